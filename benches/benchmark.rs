@@ -8,6 +8,7 @@ mod benchmarks {
 
     use lll_rs::{l2, lll, Matrix};
 
+    #[allow(deprecated)]
     pub fn bench_big_int_reduction_lll(c: &mut Criterion) {
         type I = rug::Integer;
         // "Bad" lattice basis
@@ -0,0 +1,96 @@
+//! Lattice bases checked once at construction
+
+use super::{Coefficient, DimensionMismatch, Matrix};
+
+use std::{convert::TryFrom, error, fmt};
+
+/// Error returned when a `Matrix` is not a valid lattice basis
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidBasis {
+    /// The matrix has no basis vector, or its basis vectors have no coordinate
+    Empty,
+    /// The basis vectors do not have the same dimension
+    DimensionMismatch(DimensionMismatch),
+    /// An entry is infinite or not a number
+    NonFinite {
+        /// Index of the basis vector
+        index: usize,
+        /// Index of the coordinate in the basis vector
+        coordinate: usize,
+    },
+}
+
+impl fmt::Display for InvalidBasis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidBasis::Empty => write!(f, "the basis is empty"),
+            InvalidBasis::DimensionMismatch(error) => error.fmt(f),
+            InvalidBasis::NonFinite { index, coordinate } => write!(
+                f,
+                "the coordinate {} of the basis vector {} is not finite",
+                coordinate, index
+            ),
+        }
+    }
+}
+
+impl error::Error for InvalidBasis {}
+
+impl From<DimensionMismatch> for InvalidBasis {
+    fn from(error: DimensionMismatch) -> Self {
+        InvalidBasis::DimensionMismatch(error)
+    }
+}
+
+/// A lattice basis: a `Matrix` with at least one basis vector, whose basis vectors have the same non-zero
+/// dimension and finite entries
+///
+/// The checks are done once by [`Basis::try_new`], so that the reductions of a `Basis` (see
+/// [`LatticeReduce`](crate::l2::LatticeReduce)) do not fail or panic on a malformed matrix. The basis vectors are
+/// only modified by the reductions, which keep these invariants.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Basis<T: Coefficient> {
+    matrix: Matrix<T>,
+}
+
+impl<T: Coefficient> Basis<T> {
+    /// Check that `matrix` is a valid lattice basis
+    pub fn try_new(matrix: Matrix<T>) -> Result<Self, InvalidBasis> {
+        matrix.check_dimensions()?;
+        let (d, _) = matrix.dimensions();
+        let n = matrix[0].dimension();
+        let zero = T::from(0);
+        for index in 0..d {
+            // `x - x` is not a number for infinite floating-point numbers, and never equal to anything for NaN
+            if let Some(coordinate) =
+                (0..n).position(|j| matrix[index][j].clone() - &matrix[index][j] != zero)
+            {
+                return Err(InvalidBasis::NonFinite { index, coordinate });
+            }
+        }
+        Ok(Self { matrix })
+    }
+
+    /// Return the basis vectors
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Return the basis vectors
+    pub fn into_matrix(self) -> Matrix<T> {
+        self.matrix
+    }
+
+    /// Return the basis vectors, to be reduced without breaking the invariants
+    pub(crate) fn matrix_mut(&mut self) -> &mut Matrix<T> {
+        &mut self.matrix
+    }
+}
+
+impl<T: Coefficient> TryFrom<Matrix<T>> for Basis<T> {
+    type Error = InvalidBasis;
+
+    fn try_from(matrix: Matrix<T>) -> Result<Self, InvalidBasis> {
+        Self::try_new(matrix)
+    }
+}
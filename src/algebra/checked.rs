@@ -0,0 +1,189 @@
+//! Machine integer backends with overflow checking
+//!
+//! The entries of the basis are `i64` or `i128`, and the Gram-Schmidt coefficients are double floating-point
+//! numbers. Instead of wrapping around, an overflowing operation is recorded and aborts the reduction.
+
+use super::scalar::{Float, FromExt, Scalar};
+
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    error, fmt,
+    iter::Sum,
+    ops::{Add, Mul, Sub, SubAssign},
+};
+
+thread_local! {
+    /// Whether an arithmetic operation of a checked backend overflowed on this thread
+    static OVERFLOW: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Records an overflow, and returns a dummy value
+pub(super) fn overflow<T: Default>() -> T {
+    OVERFLOW.with(|o| o.set(true));
+    T::default()
+}
+
+/// Whether an overflow was recorded since the beginning of the current checked computation
+pub(crate) fn overflowed() -> bool {
+    OVERFLOW.with(Cell::get)
+}
+
+/// Runs `f`, and returns an error if an arithmetic operation of a checked backend overflowed meanwhile
+pub(crate) fn with_overflow_check<T>(f: impl FnOnce() -> T) -> Result<T, Overflow> {
+    let previous = OVERFLOW.with(|o| o.replace(false));
+    let result = f();
+    if OVERFLOW.with(|o| o.replace(previous)) {
+        Err(Overflow)
+    } else {
+        Ok(result)
+    }
+}
+
+/// Error returned when an integer computation does not fit in the machine integers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "integer overflow during the lattice reduction")
+    }
+}
+
+impl error::Error for Overflow {}
+
+/// A machine integer whose arithmetic operations are checked
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Checked<T>(pub T);
+
+macro_rules! impl_checked {
+    ($int:ty, $scalar:ident, $doc:expr) => {
+        impl From<i32> for Checked<$int> {
+            fn from(n: i32) -> Self {
+                Checked(<$int>::from(n))
+            }
+        }
+
+        impl Add<&Checked<$int>> for Checked<$int> {
+            type Output = Checked<$int>;
+
+            fn add(self, other: &Checked<$int>) -> Checked<$int> {
+                self.0.checked_add(other.0).map_or_else(overflow, Checked)
+            }
+        }
+
+        impl Sub<&Checked<$int>> for Checked<$int> {
+            type Output = Checked<$int>;
+
+            fn sub(self, other: &Checked<$int>) -> Checked<$int> {
+                self.0.checked_sub(other.0).map_or_else(overflow, Checked)
+            }
+        }
+
+        impl SubAssign<&Checked<$int>> for Checked<$int> {
+            fn sub_assign(&mut self, other: &Checked<$int>) {
+                *self = *self - other;
+            }
+        }
+
+        impl Mul<&Checked<$int>> for Checked<$int> {
+            type Output = Checked<$int>;
+
+            fn mul(self, other: &Checked<$int>) -> Checked<$int> {
+                self.0.checked_mul(other.0).map_or_else(overflow, Checked)
+            }
+        }
+
+        impl Sum<Checked<$int>> for Checked<$int> {
+            fn sum<I: Iterator<Item = Checked<$int>>>(iter: I) -> Self {
+                iter.fold(Checked(0), |acc, n| acc + &n)
+            }
+        }
+
+        impl PartialEq<Checked<$int>> for f64 {
+            fn eq(&self, other: &Checked<$int>) -> bool {
+                *self == other.0 as f64
+            }
+        }
+
+        impl PartialOrd<Checked<$int>> for f64 {
+            fn partial_cmp(&self, other: &Checked<$int>) -> Option<Ordering> {
+                self.partial_cmp(&(other.0 as f64))
+            }
+        }
+
+        impl FromExt<&Checked<$int>> for f64 {
+            fn from_ext(n: &Checked<$int>) -> Self {
+                n.0 as f64
+            }
+        }
+
+        impl FromExt<(Checked<$int>, Checked<$int>)> for f64 {
+            fn from_ext((n, d): (Checked<$int>, Checked<$int>)) -> Self {
+                n.0 as f64 / d.0 as f64
+            }
+        }
+
+        #[doc = $doc]
+        pub struct $scalar;
+
+        impl Scalar for $scalar {
+            type Integer = Checked<$int>;
+            type Fraction = f64;
+
+            fn round(f: &Self::Fraction) -> Self::Integer {
+                let n = Float::round(f);
+                // The upper bound is a power of two, exactly represented as a `f64`
+                if n >= <$int>::MIN as f64 && n < -(<$int>::MIN as f64) {
+                    Checked(n as $int)
+                } else {
+                    overflow()
+                }
+            }
+
+            fn round_div(n: Self::Integer, d: Self::Integer) -> Self::Integer {
+                let (q, r) = match (n.0.checked_div(d.0), n.0.checked_rem(d.0)) {
+                    (Some(q), Some(r)) => (q, r),
+                    _ => return overflow(),
+                };
+                // Ties are rounded away from zero
+                if r.unsigned_abs() >= d.0.unsigned_abs() - r.unsigned_abs() {
+                    if (n.0 < 0) == (d.0 < 0) {
+                        Checked(q + 1)
+                    } else {
+                        Checked(q - 1)
+                    }
+                } else {
+                    Checked(q)
+                }
+            }
+
+            fn abs(f: Self::Fraction) -> Self::Fraction {
+                f.abs()
+            }
+
+            fn log2(f: &Self::Fraction) -> f64 {
+                f.log2()
+            }
+
+            fn overflowed() -> bool {
+                overflowed()
+            }
+
+            fn precision() -> u32 {
+                f64::MANTISSA_DIGITS
+            }
+        }
+    };
+}
+
+impl_checked!(
+    i64,
+    CheckedI64,
+    "`i64` integers with checked arithmetic, and double floating-point numbers"
+);
+impl_checked!(
+    i128,
+    CheckedI128,
+    "`i128` integers with checked arithmetic, and double floating-point numbers"
+);
@@ -0,0 +1,147 @@
+//! Fixed-width integer backend, relying on `crypto-bigint`
+//!
+//! The entries of the basis are `crypto_bigint::Int<LIMBS>` with checked arithmetic, and the Gram-Schmidt
+//! coefficients are double floating-point numbers. The integers are not allocated on the heap.
+
+use super::checked::{overflow, overflowed, Checked};
+use super::scalar::{Float, FromExt, Scalar};
+
+use crypto_bigint::{CheckedMul, CheckedSub, Int, Word};
+use std::{
+    cmp::Ordering,
+    iter::Sum,
+    ops::{Add, Mul, Sub, SubAssign},
+};
+
+/// Nearest double floating-point number (up to a few ulps)
+fn to_f64<const LIMBS: usize>(n: &Int<LIMBS>) -> f64 {
+    let radix = 2_f64.powi(Word::BITS as i32);
+    let magnitude = n
+        .abs()
+        .as_words()
+        .iter()
+        .rev()
+        .fold(0., |acc, &word| acc * radix + word as f64);
+    if *n < Int::ZERO {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// The integer `f`, or an overflow if it is not representable
+fn from_f64<const LIMBS: usize>(f: f64) -> Checked<Int<LIMBS>> {
+    if !f.is_finite() {
+        return overflow();
+    }
+    if f.abs() < 2_f64.powi(62) {
+        return Checked(Int::from_i64(f as i64));
+    }
+
+    // f = m 2^e with |m| < 2^53, both divisions being exact
+    let e = f.abs().log2().floor() as i32 - 52;
+    let mut n = Checked(Int::from_i64((f / 2_f64.powi(e)) as i64));
+    let word = Checked(Int::from_i64(1 << 32));
+    for _ in 0..e / 32 {
+        n = n * &word;
+    }
+    n * &Checked(Int::from_i64(1 << (e % 32)))
+}
+
+impl<const LIMBS: usize> From<i32> for Checked<Int<LIMBS>> {
+    fn from(n: i32) -> Self {
+        Checked(Int::from_i64(i64::from(n)))
+    }
+}
+
+impl<const LIMBS: usize> Add<&Checked<Int<LIMBS>>> for Checked<Int<LIMBS>> {
+    type Output = Checked<Int<LIMBS>>;
+
+    fn add(self, other: &Checked<Int<LIMBS>>) -> Checked<Int<LIMBS>> {
+        Option::from(self.0.checked_add(&other.0)).map_or_else(overflow, Checked)
+    }
+}
+
+impl<const LIMBS: usize> Sub<&Checked<Int<LIMBS>>> for Checked<Int<LIMBS>> {
+    type Output = Checked<Int<LIMBS>>;
+
+    fn sub(self, other: &Checked<Int<LIMBS>>) -> Checked<Int<LIMBS>> {
+        Option::from(self.0.checked_sub(&other.0)).map_or_else(overflow, Checked)
+    }
+}
+
+impl<const LIMBS: usize> SubAssign<&Checked<Int<LIMBS>>> for Checked<Int<LIMBS>> {
+    fn sub_assign(&mut self, other: &Checked<Int<LIMBS>>) {
+        *self = *self - other;
+    }
+}
+
+impl<const LIMBS: usize> Mul<&Checked<Int<LIMBS>>> for Checked<Int<LIMBS>> {
+    type Output = Checked<Int<LIMBS>>;
+
+    fn mul(self, other: &Checked<Int<LIMBS>>) -> Checked<Int<LIMBS>> {
+        Option::from(self.0.checked_mul(&other.0)).map_or_else(overflow, Checked)
+    }
+}
+
+impl<const LIMBS: usize> Sum<Checked<Int<LIMBS>>> for Checked<Int<LIMBS>> {
+    fn sum<I: Iterator<Item = Checked<Int<LIMBS>>>>(iter: I) -> Self {
+        iter.fold(Checked(Int::ZERO), |acc, n| acc + &n)
+    }
+}
+
+impl<const LIMBS: usize> PartialEq<Checked<Int<LIMBS>>> for f64 {
+    fn eq(&self, other: &Checked<Int<LIMBS>>) -> bool {
+        *self == to_f64(&other.0)
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd<Checked<Int<LIMBS>>> for f64 {
+    fn partial_cmp(&self, other: &Checked<Int<LIMBS>>) -> Option<Ordering> {
+        self.partial_cmp(&to_f64(&other.0))
+    }
+}
+
+impl<const LIMBS: usize> FromExt<&Checked<Int<LIMBS>>> for f64 {
+    fn from_ext(n: &Checked<Int<LIMBS>>) -> Self {
+        to_f64(&n.0)
+    }
+}
+
+impl<const LIMBS: usize> FromExt<(Checked<Int<LIMBS>>, Checked<Int<LIMBS>>)> for f64 {
+    fn from_ext((n, d): (Checked<Int<LIMBS>>, Checked<Int<LIMBS>>)) -> Self {
+        to_f64(&n.0) / to_f64(&d.0)
+    }
+}
+
+/// `crypto_bigint::Int<LIMBS>` integers with checked arithmetic, and double floating-point numbers
+pub struct FixedInt<const LIMBS: usize>;
+
+impl<const LIMBS: usize> Scalar for FixedInt<LIMBS> {
+    type Integer = Checked<Int<LIMBS>>;
+    type Fraction = f64;
+
+    fn round(f: &Self::Fraction) -> Self::Integer {
+        from_f64(Float::round(f))
+    }
+
+    fn round_div(n: Self::Integer, d: Self::Integer) -> Self::Integer {
+        from_f64(Float::round_div(to_f64(&n.0), to_f64(&d.0)))
+    }
+
+    fn abs(f: Self::Fraction) -> Self::Fraction {
+        f.abs()
+    }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        f.log2()
+    }
+
+    fn overflowed() -> bool {
+        overflowed()
+    }
+
+    fn precision() -> u32 {
+        f64::MANTISSA_DIGITS
+    }
+}
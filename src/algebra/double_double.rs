@@ -0,0 +1,236 @@
+//! Double-double backend
+//!
+//! The Gram-Schmidt coefficients are represented as the unevaluated sum of two double floating-point numbers,
+//! which gives about 106 bits of precision with the exponent range of `f64`. The error-free transformations follow
+//! [D71] and the QD library [HLB00].
+//!
+//! [D71]: T. J. Dekker. A floating-point technique for extending the available precision. Numer. Math. 1971.
+//! [HLB00]: Y. Hida, X. S. Li and D. H. Bailey. Quad-double arithmetic: algorithms, implementation, and
+//! application. 2000.
+
+use super::scalar::{BigNum, FromExt, Scalar};
+
+use rug::{Integer, Rational};
+use std::{
+    cmp::Ordering,
+    iter::Sum,
+    ops::{Add, Div, Mul, Neg, Sub, SubAssign},
+};
+
+/// A double-double number `hi + lo`, normalized so that `|lo| <= ulp(hi) / 2`
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+/// Sum of `a` and `b` with its rounding error
+fn two_sum(a: f64, b: f64) -> DoubleDouble {
+    let hi = a + b;
+    let b_virtual = hi - a;
+    let lo = (a - (hi - b_virtual)) + (b - b_virtual);
+    DoubleDouble { hi, lo }
+}
+
+/// Sum of `a` and `b` with its rounding error, assuming `|a| >= |b|`
+fn quick_two_sum(a: f64, b: f64) -> DoubleDouble {
+    let hi = a + b;
+    let lo = b - (hi - a);
+    DoubleDouble { hi, lo }
+}
+
+/// Product of `a` and `b` with its rounding error
+fn two_prod(a: f64, b: f64) -> DoubleDouble {
+    let hi = a * b;
+    let lo = a.mul_add(b, -hi);
+    DoubleDouble { hi, lo }
+}
+
+impl DoubleDouble {
+    /// The high and low parts of the number
+    pub fn parts(self) -> (f64, f64) {
+        (self.hi, self.lo)
+    }
+
+    /// Nearest double floating-point number
+    pub fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+
+    fn abs(self) -> Self {
+        if self.hi < 0. {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(hi: f64) -> Self {
+        DoubleDouble { hi, lo: 0. }
+    }
+}
+
+impl From<i32> for DoubleDouble {
+    fn from(n: i32) -> Self {
+        DoubleDouble::from(f64::from(n))
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn neg(self) -> DoubleDouble {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add<&DoubleDouble> for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn add(self, other: &DoubleDouble) -> DoubleDouble {
+        let s = two_sum(self.hi, other.hi);
+        let t = two_sum(self.lo, other.lo);
+        let s = quick_two_sum(s.hi, s.lo + t.hi);
+        quick_two_sum(s.hi, s.lo + t.lo)
+    }
+}
+
+impl Sub<&DoubleDouble> for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn sub(self, other: &DoubleDouble) -> DoubleDouble {
+        self + &-*other
+    }
+}
+
+impl SubAssign<&DoubleDouble> for DoubleDouble {
+    fn sub_assign(&mut self, other: &DoubleDouble) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<&DoubleDouble> for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn mul(self, other: &DoubleDouble) -> DoubleDouble {
+        let p = two_prod(self.hi, other.hi);
+        quick_two_sum(p.hi, p.lo + (self.hi * other.lo + self.lo * other.hi))
+    }
+}
+
+impl Div<&DoubleDouble> for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn div(self, other: &DoubleDouble) -> DoubleDouble {
+        // Long division, one double at a time
+        let q1 = self.hi / other.hi;
+        let r = self - &(*other * &DoubleDouble::from(q1));
+        let q2 = r.hi / other.hi;
+        let r = r - &(*other * &DoubleDouble::from(q2));
+        let q3 = r.hi / other.hi;
+        quick_two_sum(q1, q2) + &DoubleDouble::from(q3)
+    }
+}
+
+impl Sum<DoubleDouble> for DoubleDouble {
+    fn sum<I: Iterator<Item = DoubleDouble>>(iter: I) -> Self {
+        iter.fold(DoubleDouble::default(), |acc, f| acc + &f)
+    }
+}
+
+impl PartialEq<Integer> for DoubleDouble {
+    fn eq(&self, other: &Integer) -> bool {
+        *self == DoubleDouble::from_ext(other)
+    }
+}
+
+impl PartialOrd<Integer> for DoubleDouble {
+    fn partial_cmp(&self, other: &Integer) -> Option<Ordering> {
+        self.partial_cmp(&DoubleDouble::from_ext(other))
+    }
+}
+
+impl FromExt<&Integer> for DoubleDouble {
+    fn from_ext(n: &Integer) -> Self {
+        let hi = n.to_f64();
+        // The conversion truncates, so that the remainder is smaller than `hi`
+        let lo = Integer::from_f64(hi).map_or(0., |h| (n - h).to_f64());
+        quick_two_sum(hi, lo)
+    }
+}
+
+impl FromExt<(Integer, Integer)> for DoubleDouble {
+    fn from_ext((n, d): (Integer, Integer)) -> Self {
+        let r = Rational::from((n, d));
+        let hi = r.to_f64();
+        let lo = Rational::from_f64(hi).map_or(0., |h| (&r - h).to_f64());
+        quick_two_sum(hi, lo)
+    }
+}
+
+impl FromExt<f64> for DoubleDouble {
+    fn from_ext(f: f64) -> Self {
+        DoubleDouble::from(f)
+    }
+}
+
+impl FromExt<(i32, i32)> for DoubleDouble {
+    fn from_ext((n, d): (i32, i32)) -> Self {
+        DoubleDouble::from(f64::from(n)) / &DoubleDouble::from(f64::from(d))
+    }
+}
+
+/// Arbitrary precision integers (`rug::Integer`) and double-double floating-point numbers
+pub struct BigDoubleDouble;
+
+impl Scalar for BigDoubleDouble {
+    type Integer = Integer;
+    type Fraction = DoubleDouble;
+    const NON_FINITE: bool = true;
+
+    fn round(f: &Self::Fraction) -> Self::Integer {
+        // Both parts are truncated exactly, the remaining fractional parts sum to less than 2 in absolute value
+        let (hi, lo) = (f.hi.trunc(), f.lo.trunc());
+        let fract = two_sum(f.hi - hi, f.lo - lo);
+        let carry = fract.hi.trunc();
+        let fract = fract - &DoubleDouble::from(carry);
+        // Non-finite numbers are rounded to zero, the failure being detected by the reduction
+        let int = Integer::from_f64(hi).unwrap_or_default()
+            + Integer::from_f64(lo).unwrap_or_default()
+            + Integer::from_f64(carry).unwrap_or_default();
+        if fract.abs() > DoubleDouble::from(0.5) {
+            if fract.hi < 0. {
+                int - 1
+            } else {
+                int + 1
+            }
+        } else {
+            int
+        }
+    }
+
+    fn round_div(n: Self::Integer, d: Self::Integer) -> Self::Integer {
+        BigNum::round_div(n, d)
+    }
+
+    fn abs(f: Self::Fraction) -> Self::Fraction {
+        f.abs()
+    }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        f.to_f64().log2()
+    }
+
+    fn is_finite(f: &Self::Fraction) -> bool {
+        f.hi.is_finite() && f.lo.is_finite()
+    }
+
+    fn precision() -> u32 {
+        2 * f64::MANTISSA_DIGITS
+    }
+}
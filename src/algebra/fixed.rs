@@ -0,0 +1,159 @@
+//! Fixed-point backend
+//!
+//! The entries of the basis are `i64` integers with checked arithmetic, and the Gram-Schmidt coefficients are
+//! `i128` integers scaled by `2^FRAC`. All the operations of the reduction are integer operations, so that its
+//! result does not depend on the platform.
+
+use super::checked::{overflow, overflowed, Checked, CheckedI64};
+use super::scalar::{FromExt, Scalar};
+
+use std::{
+    cmp::Ordering,
+    convert::TryFrom,
+    iter::Sum,
+    ops::{Add, Div, Mul, Sub, SubAssign},
+};
+
+/// A fixed-point number with `FRAC` fractional bits
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Fixed<const FRAC: u32>(pub i128);
+
+impl<const FRAC: u32> Fixed<FRAC> {
+    /// The fixed-point number `n / d`, rounded towards zero
+    fn ratio(n: i128, d: i128) -> Self {
+        n.checked_mul(1 << FRAC)
+            .and_then(|n| n.checked_div(d))
+            .map_or_else(overflow, Fixed)
+    }
+}
+
+impl<const FRAC: u32> From<i32> for Fixed<FRAC> {
+    fn from(n: i32) -> Self {
+        Fixed(i128::from(n) << FRAC)
+    }
+}
+
+impl<const FRAC: u32> Add<&Fixed<FRAC>> for Fixed<FRAC> {
+    type Output = Fixed<FRAC>;
+
+    fn add(self, other: &Fixed<FRAC>) -> Fixed<FRAC> {
+        self.0.checked_add(other.0).map_or_else(overflow, Fixed)
+    }
+}
+
+impl<const FRAC: u32> Sub<&Fixed<FRAC>> for Fixed<FRAC> {
+    type Output = Fixed<FRAC>;
+
+    fn sub(self, other: &Fixed<FRAC>) -> Fixed<FRAC> {
+        self.0.checked_sub(other.0).map_or_else(overflow, Fixed)
+    }
+}
+
+impl<const FRAC: u32> SubAssign<&Fixed<FRAC>> for Fixed<FRAC> {
+    fn sub_assign(&mut self, other: &Fixed<FRAC>) {
+        *self = *self - other;
+    }
+}
+
+impl<const FRAC: u32> Mul<&Fixed<FRAC>> for Fixed<FRAC> {
+    type Output = Fixed<FRAC>;
+
+    fn mul(self, other: &Fixed<FRAC>) -> Fixed<FRAC> {
+        // Rounded to nearest
+        self.0
+            .checked_mul(other.0)
+            .and_then(|p| p.checked_add(1 << (FRAC - 1)))
+            .map_or_else(overflow, |p| Fixed(p >> FRAC))
+    }
+}
+
+impl<const FRAC: u32> Div<&Fixed<FRAC>> for Fixed<FRAC> {
+    type Output = Fixed<FRAC>;
+
+    fn div(self, other: &Fixed<FRAC>) -> Fixed<FRAC> {
+        Fixed::ratio(self.0, other.0)
+    }
+}
+
+impl<const FRAC: u32> Sum<Fixed<FRAC>> for Fixed<FRAC> {
+    fn sum<I: Iterator<Item = Fixed<FRAC>>>(iter: I) -> Self {
+        iter.fold(Fixed(0), |acc, f| acc + &f)
+    }
+}
+
+impl<const FRAC: u32> PartialEq<Checked<i64>> for Fixed<FRAC> {
+    fn eq(&self, other: &Checked<i64>) -> bool {
+        *self == Fixed::from_ext(other)
+    }
+}
+
+impl<const FRAC: u32> PartialOrd<Checked<i64>> for Fixed<FRAC> {
+    fn partial_cmp(&self, other: &Checked<i64>) -> Option<Ordering> {
+        self.partial_cmp(&Fixed::from_ext(other))
+    }
+}
+
+impl<const FRAC: u32> FromExt<&Checked<i64>> for Fixed<FRAC> {
+    fn from_ext(n: &Checked<i64>) -> Self {
+        Fixed(i128::from(n.0) << FRAC)
+    }
+}
+
+impl<const FRAC: u32> FromExt<(Checked<i64>, Checked<i64>)> for Fixed<FRAC> {
+    fn from_ext((n, d): (Checked<i64>, Checked<i64>)) -> Self {
+        Fixed::ratio(i128::from(n.0), i128::from(d.0))
+    }
+}
+
+impl<const FRAC: u32> FromExt<(i32, i32)> for Fixed<FRAC> {
+    fn from_ext((n, d): (i32, i32)) -> Self {
+        Fixed::ratio(i128::from(n), i128::from(d))
+    }
+}
+
+impl<const FRAC: u32> FromExt<f64> for Fixed<FRAC> {
+    fn from_ext(f: f64) -> Self {
+        // Only used for the parameters of the reduction; the scaling by a power of two is exact, and IEEE 754 rounding
+        // is the same on every platform
+        Fixed((f * (FRAC as f64).exp2()).round() as i128)
+    }
+}
+
+/// `i64` integers with checked arithmetic, and fixed-point numbers with `FRAC` fractional bits
+pub struct FixedPoint<const FRAC: u32>;
+
+impl<const FRAC: u32> Scalar for FixedPoint<FRAC> {
+    type Integer = Checked<i64>;
+    type Fraction = Fixed<FRAC>;
+
+    fn round(f: &Self::Fraction) -> Self::Integer {
+        let one = 1_i128 << FRAC;
+        let (trunc, fract) = (f.0 / one, f.0 % one);
+        let n = if 2 * fract.abs() > one {
+            trunc + fract.signum()
+        } else {
+            trunc
+        };
+        i64::try_from(n).map_or_else(|_| overflow(), Checked)
+    }
+
+    fn round_div(n: Self::Integer, d: Self::Integer) -> Self::Integer {
+        CheckedI64::round_div(n, d)
+    }
+
+    fn abs(f: Self::Fraction) -> Self::Fraction {
+        f.0.checked_abs().map_or_else(overflow, Fixed)
+    }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        (f.0 as f64).log2() - FRAC as f64
+    }
+
+    fn overflowed() -> bool {
+        overflowed()
+    }
+
+    fn precision() -> u32 {
+        FRAC
+    }
+}
@@ -0,0 +1,214 @@
+//! Human-readable formatting of matrices
+
+use super::{Coefficient, Matrix};
+
+use std::fmt;
+
+/// Horizontal alignment of the coordinates
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Alignment {
+    /// Entries aligned on their first character
+    Left,
+    /// Centered entries
+    Center,
+    /// Entries aligned on their last character, such as the units of integers
+    #[default]
+    Right,
+}
+
+impl Alignment {
+    /// Pad `entry` with spaces to `width` characters
+    fn pad(self, entry: &str, width: usize) -> String {
+        let padding = width.saturating_sub(entry.chars().count());
+        let left = match self {
+            Alignment::Left => 0,
+            Alignment::Center => padding / 2,
+            Alignment::Right => padding,
+        };
+        format!(
+            "{:left$}{}{:right$}",
+            "",
+            entry,
+            "",
+            left = left,
+            right = padding - left
+        )
+    }
+}
+
+/// Options of [`Matrix::to_text_with`], [`Matrix::to_latex_with`] and [`Matrix::to_markdown_with`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Maximum number of characters of an entry: longer entries are truncated around an ellipsis
+    pub max_width: Option<usize>,
+    /// Alignment of the coordinates
+    pub alignment: Alignment,
+}
+
+/// Escape the characters of `entry` which are special in LaTeX math mode
+fn escape_latex(entry: String) -> String {
+    entry
+        .chars()
+        .map(|c| match c {
+            '#' | '$' | '%' | '&' | '_' | '{' | '}' => format!("\\{}", c),
+            '\\' => "\\backslash{}".to_string(),
+            '…' => "\\ldots{}".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Escape the characters of `entry` which are special in a Markdown table
+fn escape_markdown(entry: String) -> String {
+    entry
+        .chars()
+        .map(|c| match c {
+            '|' | '\\' | '*' | '_' | '`' => format!("\\{}", c),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Shorten `entry` to `max_width` characters, keeping its first and last digits around an ellipsis
+fn truncate(entry: String, max_width: Option<usize>) -> String {
+    match max_width {
+        Some(max_width) if entry.chars().count() > max_width => {
+            let tail = max_width.saturating_sub(1) / 2;
+            let head = max_width.saturating_sub(1) - tail;
+            let chars: Vec<char> = entry.chars().collect();
+            chars[..head]
+                .iter()
+                .chain(&['…'])
+                .chain(&chars[chars.len() - tail..])
+                .collect()
+        }
+        _ => entry,
+    }
+}
+
+/// Formatted entries of the basis vectors, with `precision` if any, truncated then escaped, and width of each
+/// coordinate
+fn entries<T: Coefficient + fmt::Display>(
+    matrix: &Matrix<T>,
+    precision: Option<usize>,
+    max_width: Option<usize>,
+    escape: impl Fn(String) -> String,
+) -> (Vec<Vec<String>>, Vec<usize>) {
+    let (col_num, col_dim) = matrix.dimensions();
+    let format = |entry: &T| match precision {
+        Some(precision) => format!("{:.*}", precision, entry),
+        None => entry.to_string(),
+    };
+    let entries: Vec<Vec<String>> = (0..col_num)
+        .map(|i| {
+            (0..col_dim)
+                .map(|j| escape(truncate(format(&matrix[i][j]), max_width)))
+                .collect()
+        })
+        .collect();
+    let widths = (0..col_dim)
+        .map(|j| {
+            entries
+                .iter()
+                .map(|vector| vector[j].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    (entries, widths)
+}
+
+/// The basis vectors on separate lines, between brackets, with their coordinates aligned
+fn text(entries: &[Vec<String>], widths: &[usize], alignment: Alignment) -> String {
+    let lines: Vec<String> = entries
+        .iter()
+        .map(|vector| {
+            let cells: Vec<String> = vector
+                .iter()
+                .zip(widths)
+                .map(|(entry, &width)| alignment.pad(entry, width))
+                .collect();
+            format!("[{}]", cells.join(" "))
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// Print the basis vectors on separate lines, with their coordinates right-aligned
+///
+/// A precision, such as `{:.3}`, is passed to the entries. The huge entries are truncated by
+/// [`Matrix::to_text_with`].
+impl<T: Coefficient + fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (entries, widths) = entries(self, f.precision(), None, |entry| entry);
+        write!(f, "{}", text(&entries, &widths, Alignment::Right))
+    }
+}
+
+impl<T: Coefficient + fmt::Display> Matrix<T> {
+    /// Return the basis vectors on separate lines, as printed by `Display`
+    ///
+    /// The entries are truncated to `options.max_width` characters, which keeps large integers readable.
+    pub fn to_text_with(&self, options: &FormatOptions) -> String {
+        let (entries, widths) = entries(self, None, options.max_width, |entry| entry);
+        text(&entries, &widths, options.alignment)
+    }
+
+    /// Return the LaTeX code of the matrix, whose rows are the basis vectors, with the default options
+    pub fn to_latex(&self) -> String {
+        self.to_latex_with(&FormatOptions::default())
+    }
+
+    /// Return the LaTeX code of the matrix, whose rows are the basis vectors
+    ///
+    /// The matrix is an `array` between parentheses, to be used in math mode.
+    pub fn to_latex_with(&self, options: &FormatOptions) -> String {
+        let (entries, _) = entries(self, None, options.max_width, escape_latex);
+        let alignment = match options.alignment {
+            Alignment::Left => "l",
+            Alignment::Center => "c",
+            Alignment::Right => "r",
+        };
+        let rows: Vec<String> = entries.iter().map(|vector| vector.join(" & ")).collect();
+        format!(
+            "\\left(\\begin{{array}}{{{}}}\n{}\n\\end{{array}}\\right)",
+            alignment.repeat(self.dimensions().1),
+            rows.join(" \\\\\n")
+        )
+    }
+
+    /// Return the Markdown table of the matrix, whose rows are the basis vectors, with the default options
+    pub fn to_markdown(&self) -> String {
+        self.to_markdown_with(&FormatOptions::default())
+    }
+
+    /// Return the Markdown table of the matrix, whose rows are the basis vectors
+    ///
+    /// The header of the table is empty, as Markdown tables require one.
+    pub fn to_markdown_with(&self, options: &FormatOptions) -> String {
+        let (entries, widths) = entries(self, None, options.max_width, escape_markdown);
+        // The delimiter row needs three characters for the centered alignment
+        let widths: Vec<usize> = widths.into_iter().map(|width| width.max(3)).collect();
+        let row = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+
+        let mut lines = vec![
+            row(widths.iter().map(|&width| " ".repeat(width)).collect()),
+            row(widths
+                .iter()
+                .map(|&width| match options.alignment {
+                    Alignment::Left => format!(":{}", "-".repeat(width - 1)),
+                    Alignment::Center => format!(":{}:", "-".repeat(width - 2)),
+                    Alignment::Right => format!("{}:", "-".repeat(width - 1)),
+                })
+                .collect()),
+        ];
+        for vector in entries {
+            lines.push(row(vector
+                .iter()
+                .zip(&widths)
+                .map(|(entry, &width)| options.alignment.pad(entry, width))
+                .collect()));
+        }
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,147 @@
+//! Generic backend for the integer types implementing the `num-traits` traits
+//!
+//! The entries of the basis are wrapped in [`Num`], which provides the glue required by the reductions from the
+//! standard `num-traits` traits, and the Gram-Schmidt coefficients are double floating-point numbers. The integers
+//! which a double floating-point number cannot represent are converted to NaN, which aborts the reduction.
+
+use super::scalar::{Float, FromExt, Scalar};
+
+use num_traits::{FromPrimitive, Signed, ToPrimitive};
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    iter::Sum,
+    marker::PhantomData,
+    ops::{Add, Mul, Sub, SubAssign},
+};
+
+/// Requirements on the integer types of the generic backend
+pub trait NumInteger: Signed + FromPrimitive + ToPrimitive + Clone + Debug + PartialOrd {}
+
+impl<T> NumInteger for T where T: Signed + FromPrimitive + ToPrimitive + Clone + Debug + PartialOrd {}
+
+/// An integer of the generic backend
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct Num<T>(pub T);
+
+impl<T: NumInteger> Num<T> {
+    fn to_f64(&self) -> f64 {
+        self.0.to_f64().unwrap_or(f64::NAN)
+    }
+}
+
+impl<T: NumInteger> Default for Num<T> {
+    fn default() -> Self {
+        Num(T::zero())
+    }
+}
+
+impl<T: NumInteger> From<i32> for Num<T> {
+    fn from(n: i32) -> Self {
+        Num(T::from_i64(i64::from(n)).expect("Small integers must be representable"))
+    }
+}
+
+impl<T: NumInteger> Add<&Num<T>> for Num<T> {
+    type Output = Num<T>;
+
+    fn add(self, other: &Num<T>) -> Num<T> {
+        Num(self.0 + other.0.clone())
+    }
+}
+
+impl<T: NumInteger> Sub<&Num<T>> for Num<T> {
+    type Output = Num<T>;
+
+    fn sub(self, other: &Num<T>) -> Num<T> {
+        Num(self.0 - other.0.clone())
+    }
+}
+
+impl<T: NumInteger> SubAssign<&Num<T>> for Num<T> {
+    fn sub_assign(&mut self, other: &Num<T>) {
+        *self = self.clone() - other;
+    }
+}
+
+impl<T: NumInteger> Mul<&Num<T>> for Num<T> {
+    type Output = Num<T>;
+
+    fn mul(self, other: &Num<T>) -> Num<T> {
+        Num(self.0 * other.0.clone())
+    }
+}
+
+impl<T: NumInteger> Sum<Num<T>> for Num<T> {
+    fn sum<I: Iterator<Item = Num<T>>>(iter: I) -> Self {
+        iter.fold(Num::default(), |acc, n| acc + &n)
+    }
+}
+
+impl<T: NumInteger> PartialEq<Num<T>> for f64 {
+    fn eq(&self, other: &Num<T>) -> bool {
+        *self == other.to_f64()
+    }
+}
+
+impl<T: NumInteger> PartialOrd<Num<T>> for f64 {
+    fn partial_cmp(&self, other: &Num<T>) -> Option<Ordering> {
+        self.partial_cmp(&other.to_f64())
+    }
+}
+
+impl<T: NumInteger> FromExt<&Num<T>> for f64 {
+    fn from_ext(n: &Num<T>) -> Self {
+        n.to_f64()
+    }
+}
+
+impl<T: NumInteger> FromExt<(Num<T>, Num<T>)> for f64 {
+    fn from_ext((n, d): (Num<T>, Num<T>)) -> Self {
+        n.to_f64() / d.to_f64()
+    }
+}
+
+/// Any integer type implementing the `num-traits` traits (see [`NumInteger`]), and double floating-point numbers
+pub struct NumTraits<T>(PhantomData<T>);
+
+impl<T: NumInteger> Scalar for NumTraits<T> {
+    type Integer = Num<T>;
+    type Fraction = f64;
+    const NON_FINITE: bool = true;
+
+    fn round(f: &Self::Fraction) -> Self::Integer {
+        // Non-finite numbers are rounded to zero
+        Num(T::from_f64(Float::round(f)).unwrap_or_else(T::zero))
+    }
+
+    fn round_div(n: Self::Integer, d: Self::Integer) -> Self::Integer {
+        let (q, r) = (n.0.clone() / d.0.clone(), n.0.clone() % d.0.clone());
+        // Ties are rounded away from zero
+        if r.abs() + r.abs() >= d.0.abs() && !r.is_zero() {
+            if n.0.is_negative() == d.0.is_negative() {
+                Num(q + T::one())
+            } else {
+                Num(q - T::one())
+            }
+        } else {
+            Num(q)
+        }
+    }
+
+    fn abs(f: Self::Fraction) -> Self::Fraction {
+        f.abs()
+    }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        f.log2()
+    }
+
+    fn is_finite(f: &Self::Fraction) -> bool {
+        f.is_finite()
+    }
+
+    fn precision() -> u32 {
+        f64::MANTISSA_DIGITS
+    }
+}
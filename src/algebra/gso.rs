@@ -0,0 +1,29 @@
+//! Gram-Schmidt orthogonalisation of a lattice basis
+
+use super::{FromExt, Matrix, Scalar, Vector};
+
+/// Gram-Schmidt data of a basis `(b_0, ..., b_{d-1})`
+///
+/// The orthogonalised vectors are `b*_i = b_i - sum_{j<i} mu_ij b*_j`.
+/// Linearly dependent vectors get `r_i = 0` and contribute no `mu_ij` coefficients
+/// to the vectors that follow.
+pub struct Gso<S: Scalar> {
+    /// Gram coefficient matrix (lower triangular): `mu[i][j] = <b_i, b*_j> / r_j`
+    pub mu: Matrix<S::Fraction>,
+
+    /// Squared norms of the orthogonalised vectors: `r[i] = ||b*_i||²`
+    pub r: Vector<S::Fraction>,
+}
+
+impl<S: Scalar> Gso<S> {
+    /// Compute the Gram-Schmidt orthogonalisation of `basis`
+    pub fn compute(basis: &Matrix<S::Integer>) -> Self {
+        let (mu, r) = basis.gram().map(|x| S::Fraction::from_ext(x)).ldl();
+        Self { mu, r }
+    }
+
+    /// Number of vectors in the underlying basis
+    pub fn dimension(&self) -> usize {
+        self.r.dimension()
+    }
+}
@@ -0,0 +1,351 @@
+//! Interval arithmetic backend with exact fallback
+//!
+//! Each number is an interval of double floating-point numbers which is guaranteed to contain its exact value,
+//! along with the expression computing this value. Comparisons and roundings are decided on the intervals when
+//! they are conclusive, and on the exact values (evaluated with `rug::Rational`) otherwise: all the decisions of
+//! the reduction are thus certified.
+
+use super::scalar::{BigNum, Float, FromExt, Scalar};
+
+use rug::{Integer, Rational};
+use std::{
+    cell::OnceCell,
+    cmp::Ordering,
+    fmt,
+    iter::Sum,
+    ops::{Add, Div, Mul, Sub, SubAssign},
+    rc::Rc,
+};
+
+/// Operation computing the exact value of a number
+enum Expr {
+    Leaf,
+    Add(Rc<Node>, Rc<Node>),
+    Sub(Rc<Node>, Rc<Node>),
+    Mul(Rc<Node>, Rc<Node>),
+    Div(Rc<Node>, Rc<Node>),
+    Abs(Rc<Node>),
+}
+
+/// Node of the expression graph, with its exact value once it has been evaluated
+struct Node {
+    expr: Expr,
+    value: OnceCell<Rational>,
+}
+
+impl Node {
+    fn leaf(value: Rational) -> Rc<Node> {
+        Rc::new(Node {
+            expr: Expr::Leaf,
+            value: OnceCell::from(value),
+        })
+    }
+
+    fn new(expr: Expr) -> Rc<Node> {
+        Rc::new(Node {
+            expr,
+            value: OnceCell::new(),
+        })
+    }
+
+    fn operands(&self) -> Vec<&Rc<Node>> {
+        match &self.expr {
+            Expr::Leaf => vec![],
+            Expr::Abs(a) => vec![a],
+            Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) => vec![a, b],
+        }
+    }
+
+    /// Exact value of the node
+    ///
+    /// The graph is walked iteratively since expressions can be deep, and each node is evaluated at most once.
+    fn exact(self: &Rc<Node>) -> &Rational {
+        let mut stack = vec![Rc::clone(self)];
+        while let Some(node) = stack.last().cloned() {
+            if node.value.get().is_some() {
+                stack.pop();
+                continue;
+            }
+            let pending: Vec<Rc<Node>> = node
+                .operands()
+                .into_iter()
+                .filter(|operand| operand.value.get().is_none())
+                .cloned()
+                .collect();
+            if !pending.is_empty() {
+                stack.extend(pending);
+                continue;
+            }
+
+            fn value(operand: &Rc<Node>) -> &Rational {
+                operand.value.get().unwrap()
+            }
+            let exact = match &node.expr {
+                Expr::Leaf => unreachable!(),
+                Expr::Add(a, b) => Rational::from(value(a) + value(b)),
+                Expr::Sub(a, b) => Rational::from(value(a) - value(b)),
+                Expr::Mul(a, b) => Rational::from(value(a) * value(b)),
+                Expr::Div(a, b) => Rational::from(value(a) / value(b)),
+                Expr::Abs(a) => value(a).clone().abs(),
+            };
+            let _ = node.value.set(exact);
+            stack.pop();
+        }
+        self.value.get().unwrap()
+    }
+}
+
+/// Lower bound of a floating-point result, correctly rounded to nearest
+fn down(x: f64) -> f64 {
+    if x.is_nan() {
+        f64::NEG_INFINITY
+    } else {
+        x.next_down()
+    }
+}
+
+/// Upper bound of a floating-point result, correctly rounded to nearest
+fn up(x: f64) -> f64 {
+    if x.is_nan() {
+        f64::INFINITY
+    } else {
+        x.next_up()
+    }
+}
+
+/// Number of the interval backend: an interval containing the exact value, and the expression of this value
+#[derive(Clone)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+    node: Rc<Node>,
+}
+
+impl Interval {
+    fn exact(value: Rational) -> Self {
+        let f = value.to_f64();
+        Interval {
+            lo: down(f),
+            hi: up(f),
+            node: Node::leaf(value),
+        }
+    }
+
+    /// Ordering of the exact values, decided on the intervals when they are disjoint
+    fn compare(&self, lo: f64, hi: f64, exact: impl FnOnce(&Rational) -> Ordering) -> Ordering {
+        if self.hi < lo {
+            Ordering::Less
+        } else if self.lo > hi {
+            Ordering::Greater
+        } else {
+            exact(self.node.exact())
+        }
+    }
+
+    /// Bounds of the products of the endpoints
+    fn product(&self, other: &Interval) -> (f64, f64) {
+        let products = [
+            self.lo * other.lo,
+            self.lo * other.hi,
+            self.hi * other.lo,
+            self.hi * other.hi,
+        ];
+        if products.iter().any(|p| p.is_nan()) {
+            return (f64::NEG_INFINITY, f64::INFINITY);
+        }
+        let lo = products.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi = products.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        (down(lo), up(hi))
+    }
+}
+
+impl fmt::Debug for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl Default for Interval {
+    fn default() -> Self {
+        Interval::from(0)
+    }
+}
+
+impl From<i32> for Interval {
+    fn from(n: i32) -> Self {
+        Interval {
+            lo: f64::from(n),
+            hi: f64::from(n),
+            node: Node::leaf(Rational::from(n)),
+        }
+    }
+}
+
+impl Add<&Interval> for Interval {
+    type Output = Interval;
+
+    fn add(self, other: &Interval) -> Interval {
+        Interval {
+            lo: down(self.lo + other.lo),
+            hi: up(self.hi + other.hi),
+            node: Node::new(Expr::Add(self.node, Rc::clone(&other.node))),
+        }
+    }
+}
+
+impl Sub<&Interval> for Interval {
+    type Output = Interval;
+
+    fn sub(self, other: &Interval) -> Interval {
+        Interval {
+            lo: down(self.lo - other.hi),
+            hi: up(self.hi - other.lo),
+            node: Node::new(Expr::Sub(self.node, Rc::clone(&other.node))),
+        }
+    }
+}
+
+impl SubAssign<&Interval> for Interval {
+    fn sub_assign(&mut self, other: &Interval) {
+        *self = self.clone() - other;
+    }
+}
+
+impl Mul<&Interval> for Interval {
+    type Output = Interval;
+
+    fn mul(self, other: &Interval) -> Interval {
+        let (lo, hi) = self.product(other);
+        Interval {
+            lo,
+            hi,
+            node: Node::new(Expr::Mul(self.node, Rc::clone(&other.node))),
+        }
+    }
+}
+
+impl Div<&Interval> for Interval {
+    type Output = Interval;
+
+    fn div(self, other: &Interval) -> Interval {
+        let (lo, hi) = if other.lo > 0. || other.hi < 0. {
+            let inverse = Interval {
+                lo: down(1. / other.hi),
+                hi: up(1. / other.lo),
+                node: Rc::clone(&other.node),
+            };
+            self.product(&inverse)
+        } else {
+            (f64::NEG_INFINITY, f64::INFINITY)
+        };
+        Interval {
+            lo,
+            hi,
+            node: Node::new(Expr::Div(self.node, Rc::clone(&other.node))),
+        }
+    }
+}
+
+impl Sum<Interval> for Interval {
+    fn sum<I: Iterator<Item = Interval>>(iter: I) -> Self {
+        iter.fold(Interval::from(0), |acc, f| acc + &f)
+    }
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Interval) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
+        Some(self.compare(other.lo, other.hi, |f| f.cmp(other.node.exact())))
+    }
+}
+
+impl PartialEq<Integer> for Interval {
+    fn eq(&self, other: &Integer) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd<Integer> for Interval {
+    fn partial_cmp(&self, other: &Integer) -> Option<Ordering> {
+        let f = other.to_f64();
+        Some(self.compare(down(f), up(f), |f| f.partial_cmp(other).unwrap()))
+    }
+}
+
+impl FromExt<&Integer> for Interval {
+    fn from_ext(n: &Integer) -> Self {
+        Interval::exact(Rational::from(n))
+    }
+}
+
+impl FromExt<(Integer, Integer)> for Interval {
+    fn from_ext((n, d): (Integer, Integer)) -> Self {
+        Interval::exact(Rational::from((n, d)))
+    }
+}
+
+impl FromExt<f64> for Interval {
+    fn from_ext(f: f64) -> Self {
+        Interval {
+            lo: f,
+            hi: f,
+            node: Node::leaf(Rational::from_f64(f).unwrap()),
+        }
+    }
+}
+
+impl FromExt<(i32, i32)> for Interval {
+    fn from_ext((n, d): (i32, i32)) -> Self {
+        Interval::exact(Rational::from((n, d)))
+    }
+}
+
+/// Integers (`rug::Integer`) and intervals of double floating-point numbers, with exact fallback
+pub struct Certified;
+
+impl Scalar for Certified {
+    type Integer = Integer;
+    type Fraction = Interval;
+
+    fn round(f: &Self::Fraction) -> Self::Integer {
+        // Rounding is monotonic, so that it is decided when both ends round to the same integer
+        let (lo, hi) = (Float::round(&f.lo), Float::round(&f.hi));
+        if lo == hi && lo.is_finite() {
+            Integer::from_f64(lo).unwrap()
+        } else {
+            BigNum::round(f.node.exact())
+        }
+    }
+
+    fn round_div(n: Self::Integer, d: Self::Integer) -> Self::Integer {
+        BigNum::round_div(n, d)
+    }
+
+    fn abs(f: Self::Fraction) -> Self::Fraction {
+        let (lo, hi) = if f.lo >= 0. {
+            (f.lo, f.hi)
+        } else if f.hi <= 0. {
+            (-f.hi, -f.lo)
+        } else {
+            (0., f.hi.max(-f.lo))
+        };
+        Interval {
+            lo,
+            hi,
+            node: Node::new(Expr::Abs(f.node)),
+        }
+    }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        if f.lo > 0. && f.hi.is_finite() {
+            f.lo.log2()
+        } else {
+            BigNum::log2(f.node.exact())
+        }
+    }
+}
@@ -1,14 +1,52 @@
 //! Basic matrix structure for LLL
 
-use super::{Coefficient, Vector};
+use super::{Coefficient, InvalidBasis, Vector};
 
+use rand_core::RngCore;
 use std::{
-    fmt,
-    ops::{Index, IndexMut},
+    convert::TryFrom,
+    error, fmt,
+    iter::FromIterator,
+    ops::{Index, IndexMut, Mul, Range},
 };
 
-#[derive(PartialEq)]
+/// Error returned when the basis vectors of a `Matrix` do not have the same dimension
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DimensionMismatch {
+    /// Index of the first basis vector whose dimension differs from the first one
+    pub index: usize,
+    /// Dimension of the first basis vector
+    pub expected: usize,
+    /// Dimension of the basis vector at `index`
+    pub found: usize,
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the basis vector {} has dimension {} instead of {}",
+            self.index, self.found, self.expected
+        )
+    }
+}
+
+impl error::Error for DimensionMismatch {}
+
+/// Bit lengths of the entries of a `Matrix`, the bit length of an entry being that of its absolute value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EntrySizeStats {
+    /// Largest bit length of an entry
+    pub max_bits: u64,
+    /// Average bit length of the entries, or zero for an empty matrix
+    pub mean_bits: f64,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
 /// A `Matrix` is a collection of `Vector`s
+///
+/// A `Matrix<T>` is `Send` and `Sync` when `T` is, as for the entries of every backend: bases can be moved to
+/// and shared between threads.
 pub struct Matrix<T: Coefficient> {
     /// Internal representation as a list of elements of type `T`
     columns: Vec<Vector<T>>,
@@ -28,6 +66,22 @@ impl<T: Coefficient> Matrix<T> {
         }
     }
 
+    /// Initialise the identity `Matrix` of dimension `n`
+    pub fn identity(n: usize) -> Self {
+        Self::from_columns((0..n).map(|i| Vector::basis_vector(n, i)).collect())
+    }
+
+    /// Initialise a square diagonal `Matrix` whose diagonal entries are `diagonal`
+    pub fn diagonal(diagonal: &[T]) -> Self {
+        let n = diagonal.len();
+        let mut matrix = Self::init(n, n);
+        for (i, entry) in diagonal.iter().enumerate() {
+            matrix[i][i] = entry.clone();
+        }
+        matrix
+    }
+
+    /// Create a `Matrix` from its columns, which are the basis vectors of the lattice
     pub fn from_columns(columns: Vec<Vector<T>>) -> Self {
         let dimensions = if let Some(col) = columns.first() {
             (columns.len(), col.dimension())
@@ -40,6 +94,11 @@ impl<T: Coefficient> Matrix<T> {
         }
     }
 
+    /// Create a `Matrix` from a list of basis vectors, each of them becoming a column
+    ///
+    /// The basis vectors must have the same dimension, which is not checked: see [`Matrix::try_from_matrix`]. To
+    /// make the orientation explicit, prefer [`Matrix::from_rows`] for a matrix written row by row whose rows are the
+    /// basis vectors, and [`Matrix::from_column_basis`] for one whose columns are the basis vectors.
     pub fn from_matrix(matrix: Vec<Vec<T>>) -> Self {
         Self::from_columns(
             matrix
@@ -49,6 +108,67 @@ impl<T: Coefficient> Matrix<T> {
         )
     }
 
+    /// Create a `Matrix` from a list of basis vectors, each of them becoming a column, or return an error if they
+    /// do not have the same dimension
+    pub fn try_from_matrix(matrix: Vec<Vec<T>>) -> Result<Self, DimensionMismatch> {
+        if let Some(first) = matrix.first() {
+            let expected = first.len();
+            if let Some((index, column)) = matrix
+                .iter()
+                .enumerate()
+                .find(|(_, column)| column.len() != expected)
+            {
+                return Err(DimensionMismatch {
+                    index,
+                    expected,
+                    found: column.len(),
+                });
+            }
+        }
+        Ok(Self::from_matrix(matrix))
+    }
+
+    /// Check that the matrix is not empty and that the basis vectors have the same dimension, which the matrices
+    /// built without checks (e.g. with [`Matrix::from_matrix`]) or modified through `IndexMut` may not
+    pub fn check_dimensions(&self) -> Result<(), InvalidBasis> {
+        let expected = self.columns.first().map_or(0, Vector::dimension);
+        if let Some((index, column)) = self
+            .columns
+            .iter()
+            .enumerate()
+            .find(|(_, column)| column.dimension() != expected)
+        {
+            return Err(InvalidBasis::DimensionMismatch(DimensionMismatch {
+                index,
+                expected,
+                found: column.dimension(),
+            }));
+        }
+        if expected == 0 {
+            return Err(InvalidBasis::Empty);
+        }
+        Ok(())
+    }
+
+    /// Create a `Matrix` from a row-basis, whose basis vectors are the rows `rows`
+    ///
+    /// This is the convention of most papers and of fplll: each row becomes a basis vector, that is a column of
+    /// the `Matrix`, as with [`Matrix::from_matrix`]. A matrix written row by row whose basis vectors are its
+    /// columns, as in PARI, is read with [`Matrix::from_column_basis`] instead.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        Self::from_columns(rows.into_iter().map(Vector::from_vector).collect())
+    }
+
+    /// Create a `Matrix` from a column-basis written row by row, whose basis vectors are the columns of `rows`
+    ///
+    /// This is the convention of PARI and of most textbooks on linear algebra: the `j`-th basis vector is made of
+    /// the `j`-th entries of the rows. A row-basis, as in fplll, is read with [`Matrix::from_rows`] instead.
+    pub fn from_column_basis(rows: Vec<Vec<T>>) -> Self {
+        let mut matrix = Self::from_rows(rows);
+        matrix.transpose_mut();
+        matrix
+    }
+
     /// Return the matrix dimensions
     pub fn dimensions(&self) -> (usize, usize) {
         self.dimensions
@@ -59,11 +179,763 @@ impl<T: Coefficient> Matrix<T> {
         self.columns.swap(i, j);
     }
 
+    /// Return the columns, which can be modified but not added or removed
+    pub(crate) fn columns_mut(&mut self) -> &mut [Vector<T>] {
+        &mut self.columns
+    }
+
     /// Insert the i-th column before the j-th one
     pub fn insert(&mut self, i: usize, j: usize) {
         let v = self.columns.remove(i);
         self.columns.insert(j, v)
     }
+
+    /// Sort the columns by increasing Euclidean norm, keeping the order of the columns of equal norms
+    ///
+    /// Null columns come first, as in the output of the reductions. The columns whose norm is not a number (with
+    /// floating-point entries) come last.
+    pub fn sort_by_norm(&mut self) {
+        let mut columns: Vec<_> = std::mem::take(&mut self.columns)
+            .into_iter()
+            .map(|column| (column.norm_sqr(), column))
+            .collect();
+        // Only NaN is not comparable, even to itself
+        let is_nan = |x: &T| x.partial_cmp(x).is_none();
+        columns.sort_by(|(a, _), (b, _)| {
+            a.partial_cmp(b)
+                .unwrap_or_else(|| is_nan(a).cmp(&is_nan(b)))
+        });
+        self.columns = columns.into_iter().map(|(_, column)| column).collect();
+    }
+
+    /// Append a column at the end of the matrix
+    ///
+    /// # Panics
+    /// if the matrix is not empty and `column` does not have the dimension of its columns
+    pub fn push_column(&mut self, column: Vector<T>) {
+        let len = self.dimensions.0;
+        self.insert_column(len, column)
+    }
+
+    /// Insert a column before the i-th one
+    ///
+    /// # Panics
+    /// if `i` is greater than the number of columns, or if the matrix is not empty and `column` does not have
+    /// the dimension of its columns
+    pub fn insert_column(&mut self, i: usize, column: Vector<T>) {
+        let (col_num, col_dim) = self.dimensions;
+        if col_num == 0 {
+            self.dimensions.1 = column.dimension();
+        } else {
+            assert_eq!(column.dimension(), col_dim);
+        }
+        self.columns.insert(i, column);
+        self.dimensions.0 += 1;
+    }
+
+    /// Remove and return the i-th column
+    ///
+    /// # Panics
+    /// if `i` is out of bounds
+    pub fn remove_column(&mut self, i: usize) -> Vector<T> {
+        let column = self.columns.remove(i);
+        self.dimensions.0 -= 1;
+        column
+    }
+
+    /// Return the `Matrix` made of the columns in `columns`, restricted to the coordinates in `rows`
+    ///
+    /// # Panics
+    /// if the ranges are out of the bounds of the matrix
+    pub fn submatrix(&self, columns: Range<usize>, rows: Range<usize>) -> Self {
+        let (col_num, col_dim) = self.dimensions;
+        assert!(columns.start <= columns.end && columns.end <= col_num);
+        assert!(rows.start <= rows.end && rows.end <= col_dim);
+        Self {
+            dimensions: (columns.len(), rows.len()),
+            columns: self.columns[columns]
+                .iter()
+                .map(|v| rows.clone().map(|j| v[j].clone()).collect())
+                .collect(),
+        }
+    }
+
+    /// Return the `Matrix` made of the columns in `columns`, such as a local block of the basis
+    ///
+    /// # Panics
+    /// if the range is out of the bounds of the matrix
+    pub fn block(&self, columns: Range<usize>) -> Self {
+        self.submatrix(columns, 0..self.dimensions.1)
+    }
+
+    /// Return the concatenation of the columns of `self` and `other`, whose `i`-th column is `self[i]` followed by
+    /// `other[i]`
+    ///
+    /// When the basis vectors are the rows of the matrices, as in most papers, this is the block matrix `[A | B]`.
+    ///
+    /// # Panics
+    /// if the matrices do not have the same number of columns
+    pub fn hstack(&self, other: &Self) -> Self {
+        let (col_num, col_dim) = self.dimensions;
+        assert_eq!(col_num, other.dimensions.0);
+        Self {
+            columns: (0..col_num)
+                .map(|i| {
+                    (0..col_dim)
+                        .map(|j| self[i][j].clone())
+                        .chain((0..other.dimensions.1).map(|j| other[i][j].clone()))
+                        .collect()
+                })
+                .collect(),
+            dimensions: (col_num, col_dim + other.dimensions.1),
+        }
+    }
+
+    /// Return the matrix whose `i`-th column is `self[i]` followed by `extra` coordinates equal to `fill`
+    ///
+    /// This grows the ambient dimension, e.g. before a Kannan embedding.
+    pub fn pad_columns(&self, extra: usize, fill: T) -> Self {
+        let (col_num, col_dim) = self.dimensions;
+        Self {
+            columns: self
+                .columns
+                .iter()
+                .map(|column| column.extended(extra, fill.clone()))
+                .collect(),
+            dimensions: (col_num, col_dim + extra),
+        }
+    }
+
+    /// Return the columns of `self` followed by the columns of `other`
+    ///
+    /// When the basis vectors are the rows of the matrices, as in most papers, this is the block matrix `[A; B]`.
+    ///
+    /// # Panics
+    /// if the columns of the matrices do not have the same dimension
+    pub fn vstack(&self, other: &Self) -> Self {
+        let (col_num, col_dim) = self.dimensions;
+        assert_eq!(col_dim, other.dimensions.1);
+        Self {
+            columns: self.columns.iter().chain(&other.columns).cloned().collect(),
+            dimensions: (col_num + other.dimensions.0, col_dim),
+        }
+    }
+
+    /// Return the matrix `[A | I]`, whose `i`-th column is `self[i]` followed by the `i`-th canonical vector
+    pub fn augment_identity(&self) -> Self {
+        self.hstack(&Self::identity(self.dimensions.0))
+    }
+
+    /// Return the Gram matrix of the columns: `gram[i][j] = <self[i], self[j]>`
+    ///
+    /// When the basis vectors are the rows of `B`, as in most papers, this is `B * B^T`.
+    pub fn gram(&self) -> Self {
+        let n = self.dimensions.0;
+        let mut gram = Self::init(n, n);
+        for i in 0..n {
+            for j in 0..=i {
+                gram[i][j] = self[i].dot(&self[j]);
+            }
+            for j in 0..i {
+                gram[j][i] = gram[i][j].clone();
+            }
+        }
+        gram
+    }
+
+    /// Return the `Matrix` whose entries are the images of the entries of `self` by `f`
+    pub fn map<U: Coefficient>(&self, mut f: impl FnMut(&T) -> U) -> Matrix<U> {
+        Matrix {
+            columns: self.columns.iter().map(|v| v.map(&mut f)).collect(),
+            dimensions: self.dimensions,
+        }
+    }
+
+    /// Return the `Matrix` whose entries are the images by `f` of the entries of `self` and `other` at the same
+    /// position
+    ///
+    /// # Panics
+    /// if the matrices do not have the same dimensions
+    pub fn zip_with<U: Coefficient, V: Coefficient>(
+        &self,
+        other: &Matrix<U>,
+        mut f: impl FnMut(&T, &U) -> V,
+    ) -> Matrix<V> {
+        assert_eq!(self.dimensions, other.dimensions());
+        Matrix {
+            columns: (0..self.dimensions.0)
+                .map(|i| self[i].zip_with(&other[i], &mut f))
+                .collect(),
+            dimensions: self.dimensions,
+        }
+    }
+
+    /// Return the elementwise (Hadamard) product of the matrices
+    ///
+    /// # Panics
+    /// if the matrices do not have the same dimensions
+    pub fn hadamard(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a.clone() * b)
+    }
+
+    /// Apply a permutation to the rows, that is to the coordinates of every basis vector: the `j`-th coordinate
+    /// of the result is the `permutation[j]`-th coordinate of `self`
+    ///
+    /// # Panics
+    /// if `permutation` is not a permutation of the rows
+    pub fn permute_rows(&self, permutation: &[usize]) -> Self {
+        assert_eq!(permutation.len(), self.dimensions.1);
+        Self {
+            columns: self
+                .columns
+                .iter()
+                .map(|column| column.permute(permutation))
+                .collect(),
+            dimensions: self.dimensions,
+        }
+    }
+
+    /// Return the transposed matrix, whose columns are the rows of this one
+    pub fn transpose(&self) -> Self {
+        let (col_num, col_dim) = self.dimensions;
+        Self {
+            columns: (0..col_dim)
+                .map(|j| (0..col_num).map(|i| self[i][j].clone()).collect())
+                .collect(),
+            dimensions: (col_dim, col_num),
+        }
+    }
+
+    /// Transpose the matrix in-place, without copying its entries
+    pub fn transpose_mut(&mut self) {
+        let (col_num, col_dim) = self.dimensions;
+        let mut columns = vec![Vector::<T>::init(col_num); col_dim];
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            for (j, transposed) in columns.iter_mut().enumerate() {
+                transposed[i] = std::mem::take(&mut column[j]);
+            }
+        }
+        self.columns = columns;
+        self.dimensions = (col_dim, col_num);
+    }
+}
+
+impl<T: Coefficient> Matrix<T> {
+    /// `Matrix` whose entries are successively given by `entry`
+    fn from_fn(col_num: usize, col_dim: usize, mut entry: impl FnMut() -> T) -> Self {
+        (0..col_num)
+            .map(|_| (0..col_dim).map(|_| entry()).collect())
+            .collect()
+    }
+
+    /// Statistics on the entry sizes, given the bit length of an entry
+    fn size_stats(&self, bits: impl Fn(&T) -> u64) -> EntrySizeStats {
+        let (col_num, col_dim) = self.dimensions;
+        let bits = &bits;
+        let (max_bits, total_bits) = self
+            .columns
+            .iter()
+            .flat_map(|column| (0..col_dim).map(move |j| bits(&column[j])))
+            .fold((0, 0), |(max, total), bits| (max.max(bits), total + bits));
+        let entries = col_num * col_dim;
+        EntrySizeStats {
+            max_bits,
+            mean_bits: if entries == 0 {
+                0.
+            } else {
+                total_bits as f64 / entries as f64
+            },
+        }
+    }
+
+    /// Determinant of a square matrix by fraction-free Gaussian elimination [Bar68]
+    ///
+    /// Every intermediate entry is a minor of the matrix, so that `divide_exact` is only called on exact quotients
+    /// and the entries do not grow more than the minors.
+    ///
+    /// [Bar68]: E. H. Bareiss. Sylvester's identity and multistep integer-preserving Gaussian elimination.
+    /// Math. Comp. 1968.
+    #[cfg(any(feature = "rug", feature = "num"))]
+    fn bareiss(&self, divide_exact: impl Fn(T, &T) -> T) -> T {
+        let (n, dim) = self.dimensions;
+        assert_eq!(n, dim, "The matrix must be square");
+
+        let zero = T::from(0);
+        let mut a = self.columns.clone();
+        let mut previous = T::from(1);
+        let mut negate = false;
+        for k in 0..n {
+            match (k..n).find(|&i| a[i][k] != zero) {
+                None => return zero,
+                Some(i) if i != k => {
+                    a.swap(i, k);
+                    negate = !negate;
+                }
+                Some(_) => {}
+            }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let entry = a[i][j].clone() * &a[k][k] - &(a[i][k].clone() * &a[k][j]);
+                    a[i][j] = divide_exact(entry, &previous);
+                }
+            }
+            previous = a[k][k].clone();
+        }
+
+        if negate {
+            zero - &previous
+        } else {
+            previous
+        }
+    }
+
+    /// Rank of a matrix by fraction-free Gaussian elimination [Bar68], as [`bareiss`](Matrix::bareiss)
+    ///
+    /// The columns without pivot are skipped: every intermediate entry is still a minor of the matrix.
+    #[cfg(any(feature = "rug", feature = "num"))]
+    fn bareiss_rank(&self, divide_exact: impl Fn(T, &T) -> T) -> usize {
+        let (n, dim) = self.dimensions;
+        let zero = T::from(0);
+        let mut a = self.columns.clone();
+        let mut previous = T::from(1);
+        let mut rank = 0;
+        for j in 0..dim {
+            let pivot = match (rank..n).find(|&i| a[i][j] != zero) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            a.swap(rank, pivot);
+            for i in rank + 1..n {
+                for l in j + 1..dim {
+                    let entry = a[i][l].clone() * &a[rank][j] - &(a[i][j].clone() * &a[rank][l]);
+                    a[i][l] = divide_exact(entry, &previous);
+                }
+            }
+            previous = a[rank][j].clone();
+            rank += 1;
+        }
+        rank
+    }
+
+    /// Return the `LDL^T` decomposition of a symmetric positive semi-definite matrix, such as a Gram matrix
+    ///
+    /// Returns the unit lower triangular matrix `L` and the diagonal `D` with `self = L * D * L^T`. For the Gram
+    /// matrix of a basis, these are the Gram-Schmidt coefficients `mu` and the squared norms `r` of the
+    /// orthogonalised vectors. A zero `D[j]` (linearly dependent vector) gives zero coefficients `L[i][j]`.
+    ///
+    /// Only the lower triangle of the matrix is read.
+    ///
+    /// # Panics
+    /// if the matrix is not square
+    pub fn ldl(&self) -> (Self, Vector<T>)
+    where
+        T: for<'a> std::ops::Div<&'a T, Output = T>,
+    {
+        let (n, dim) = self.dimensions;
+        assert_eq!(n, dim, "The matrix must be square");
+
+        let zero = T::from(0);
+        let mut l = Self::init(n, n);
+        let mut d: Vector<T> = Vector::init(n);
+        // `L[i][j] D[j]` for the current row
+        let mut r: Vector<T> = Vector::init(n);
+        for i in 0..n {
+            for j in 0..=i {
+                let mut r_ij = self[i][j].clone();
+                for k in 0..j {
+                    r_ij -= &(l[j][k].clone() * &r[k]);
+                }
+                r[j] = r_ij;
+
+                if j < i && d[j] != zero {
+                    l[i][j] = r[j].clone() / &d[j];
+                }
+            }
+            d[i] = r[i].clone();
+            l[i][i] = T::from(1);
+        }
+        (l, d)
+    }
+
+    /// Reduced row echelon form by Gauss-Jordan elimination, whose rows are the basis vectors, and the indices of
+    /// its pivot columns
+    ///
+    /// The entries must be in a field. The pivot of each column is the entry of largest `magnitude`, for the sake
+    /// of numerical stability with floating-point entries, and entries which are `negligible` are taken as zero.
+    pub(crate) fn gauss_jordan_rref(
+        &self,
+        magnitude: impl Fn(&T) -> T,
+        negligible: impl Fn(&T) -> bool,
+    ) -> (Self, Vec<usize>)
+    where
+        T: for<'a> std::ops::Div<&'a T, Output = T>,
+    {
+        let (n, dim) = self.dimensions;
+        let mut a = self.clone();
+        let mut pivots = Vec::new();
+        for j in 0..dim {
+            let k = pivots.len();
+            let pivot =
+                (k..n)
+                    .filter(|&i| !negligible(&a[i][j]))
+                    .fold(None, |best: Option<usize>, i| match best {
+                        Some(best) if magnitude(&a[best][j]) >= magnitude(&a[i][j]) => Some(best),
+                        _ => Some(i),
+                    });
+            let pivot = match pivot {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            a.swap(k, pivot);
+
+            let pivot_value = a[k][j].clone();
+            a[k] = a[k].map(|x| x.clone() / &pivot_value);
+            for i in (0..n).filter(|&i| i != k) {
+                let factor = a[i][j].clone();
+                if !negligible(&factor) {
+                    a[i] = a[i].sub(&a[k].mulf(factor));
+                }
+            }
+            pivots.push(j);
+        }
+        (a, pivots)
+    }
+
+    /// Inverse of a square matrix with entries in a field, if it is invertible, from the reduced row echelon form
+    /// of `[self | I]` computed by [`gauss_jordan_rref`](Matrix::gauss_jordan_rref)
+    ///
+    /// # Panics
+    /// if the matrix is not square
+    pub(crate) fn gauss_jordan(
+        &self,
+        magnitude: impl Fn(&T) -> T,
+        negligible: impl Fn(&T) -> bool,
+    ) -> Option<Self>
+    where
+        T: for<'a> std::ops::Div<&'a T, Output = T>,
+    {
+        let (n, dim) = self.dimensions;
+        assert_eq!(n, dim, "The matrix must be square");
+
+        let augmented: Self = (0..n)
+            .map(|i| {
+                (0..2 * n)
+                    .map(|j| match j.checked_sub(n) {
+                        None => self[i][j].clone(),
+                        Some(j) => T::from(i32::from(i == j)),
+                    })
+                    .collect()
+            })
+            .collect();
+        let (rref, pivots) = augmented.gauss_jordan_rref(magnitude, negligible);
+        // The pivots of the identity block only appear when the matrix is singular
+        if pivots.iter().any(|&j| j >= n) {
+            return None;
+        }
+        Some(
+            (0..n)
+                .map(|i| (n..2 * n).map(|j| rref[i][j].clone()).collect())
+                .collect(),
+        )
+    }
+}
+
+/// Random machine integer of at most `bits` bits, with a random sign
+fn random_i64<R: RngCore>(bits: u32, rng: &mut R) -> i64 {
+    assert!(bits < 64);
+    let sample = rng.next_u64();
+    let magnitude = ((sample >> 1) >> (63 - bits)) as i64;
+    if sample & 1 == 1 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+#[cfg(feature = "rug")]
+impl Matrix<rug::Integer> {
+    /// Initialise a `Matrix` whose entries are uniformly random integers of at most `bit_size` bits, with
+    /// random signs
+    ///      - `col_num`: number of columns
+    ///      - `col_dim`: number of rows
+    pub fn random<R: RngCore>(col_num: usize, col_dim: usize, bit_size: u32, rng: &mut R) -> Self {
+        Self::from_fn(col_num, col_dim, || {
+            let n = crate::generator::random_bits(bit_size + 1, rng);
+            // The lowest bit is the sign
+            let negative = n.is_odd();
+            let magnitude: rug::Integer = n >> 1;
+            if negative {
+                -magnitude
+            } else {
+                magnitude
+            }
+        })
+    }
+
+    /// Return the determinant of the matrix, computed exactly by fraction-free Gaussian elimination
+    ///
+    /// # Panics
+    /// if the matrix is not square
+    pub fn determinant(&self) -> rug::Integer {
+        self.bareiss(|n, d| n.div_exact(d))
+    }
+
+    /// Return the rank of the matrix, computed exactly by fraction-free Gaussian elimination
+    pub fn rank(&self) -> usize {
+        self.bareiss_rank(|n, d| n.div_exact(d))
+    }
+
+    /// Return the exact inverse of the matrix over the rationals, or `None` if it is singular
+    ///
+    /// The inverse is such that `self * inverse` is the identity, the product being taken on the entries.
+    ///
+    /// # Panics
+    /// if the matrix is not square
+    pub fn inverse(&self) -> Option<Matrix<rug::Rational>> {
+        self.map(|n| rug::Rational::from(n)).inverse()
+    }
+
+    /// Return the largest bit length of the absolute values of the entries
+    pub fn max_bits(&self) -> u64 {
+        self.entry_size_stats().max_bits
+    }
+
+    /// Return the largest and average bit lengths of the absolute values of the entries
+    pub fn entry_size_stats(&self) -> EntrySizeStats {
+        self.size_stats(|x| u64::from(x.significant_bits()))
+    }
+
+    /// Return the matrix whose entries are reduced modulo `q`, into the centred range `(-q/2, q/2]`
+    ///
+    /// # Panics
+    /// if `q` is not positive
+    pub fn mod_q(&self, q: &rug::Integer) -> Self {
+        assert!(*q > 0);
+        self.map(|x| centre(x.clone(), q))
+    }
+}
+
+/// Representative of `x mod q` in `(-q/2, q/2]`
+#[cfg(feature = "rug")]
+pub(crate) fn centre(x: rug::Integer, q: &rug::Integer) -> rug::Integer {
+    use rug::ops::RemRounding;
+
+    let x = x.rem_euc(q);
+    if rug::Integer::from(&x * 2) > *q {
+        x - q
+    } else {
+        x
+    }
+}
+
+#[cfg(feature = "rug")]
+impl Matrix<rug::Rational> {
+    /// Return the exact inverse of the matrix, or `None` if it is singular
+    ///
+    /// The inverse is such that `self * inverse` is the identity, the product being taken on the entries.
+    ///
+    /// # Panics
+    /// if the matrix is not square
+    pub fn inverse(&self) -> Option<Self> {
+        self.gauss_jordan(|x| x.clone().abs(), |x| *x == 0)
+    }
+
+    /// Return the reduced row echelon form of the matrix, whose rows are the basis vectors, and the indices of its
+    /// pivot columns
+    ///
+    /// The non-zero vectors of the echelon form, which come first, span the same vector space as the basis
+    /// vectors: there is one per pivot, and their number is the rank of the matrix. The `k`-th of them has a
+    /// leading `1` at the `k`-th pivot, and the other vectors have a zero coordinate there.
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        self.gauss_jordan_rref(|x| x.clone().abs(), |x| *x == 0)
+    }
+
+    /// Return the rank of the matrix, computed by [`rref`](Matrix::rref)
+    pub fn rank(&self) -> usize {
+        self.rref().1.len()
+    }
+}
+
+#[cfg(feature = "num")]
+impl Matrix<num_bigint::BigInt> {
+    /// Return the determinant of the matrix, computed exactly by fraction-free Gaussian elimination
+    ///
+    /// # Panics
+    /// if the matrix is not square
+    pub fn determinant(&self) -> num_bigint::BigInt {
+        self.bareiss(|n, d| n / d)
+    }
+
+    /// Return the rank of the matrix, computed exactly by fraction-free Gaussian elimination
+    pub fn rank(&self) -> usize {
+        self.bareiss_rank(|n, d| n / d)
+    }
+
+    /// Return the largest bit length of the absolute values of the entries
+    pub fn max_bits(&self) -> u64 {
+        self.entry_size_stats().max_bits
+    }
+
+    /// Return the largest and average bit lengths of the absolute values of the entries
+    pub fn entry_size_stats(&self) -> EntrySizeStats {
+        self.size_stats(|x| x.bits())
+    }
+}
+
+impl Matrix<i64> {
+    /// Initialise a `Matrix` whose entries are uniformly random integers of at most `bit_size` bits, with
+    /// random signs
+    ///      - `col_num`: number of columns
+    ///      - `col_dim`: number of rows
+    ///
+    /// # Panics
+    /// if `bit_size > 63`
+    pub fn random<R: RngCore>(col_num: usize, col_dim: usize, bit_size: u32, rng: &mut R) -> Self {
+        Self::from_fn(col_num, col_dim, || random_i64(bit_size, rng))
+    }
+
+    /// Return the largest bit length of the absolute values of the entries
+    pub fn max_bits(&self) -> u64 {
+        self.entry_size_stats().max_bits
+    }
+
+    /// Return the largest and average bit lengths of the absolute values of the entries
+    pub fn entry_size_stats(&self) -> EntrySizeStats {
+        self.size_stats(|x| u64::from(64 - x.unsigned_abs().leading_zeros()))
+    }
+
+    /// Return the matrix whose entries are reduced modulo `q`, into the centred range `(-q/2, q/2]`
+    ///
+    /// # Panics
+    /// if `q` is not positive
+    pub fn mod_q(&self, q: i64) -> Self {
+        assert!(q > 0);
+        self.map(|&x| {
+            let x = x.rem_euclid(q);
+            // `x - q` does not overflow as `x` is non-negative
+            if x > q - x {
+                x - q
+            } else {
+                x
+            }
+        })
+    }
+}
+
+impl Matrix<f64> {
+    /// Whether the matrices have the same dimensions, and entries which differ by at most `tolerance`
+    ///
+    /// Unlike `==`, this does not depend on the rounding errors of the platform.
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.dimensions == other.dimensions
+            && self
+                .columns
+                .iter()
+                .zip(&other.columns)
+                .all(|(u, v)| (0..u.dimension()).all(|j| (u[j] - v[j]).abs() <= tolerance))
+    }
+
+    /// Return the Cholesky factor of a symmetric positive semi-definite matrix, such as a Gram matrix
+    ///
+    /// The factor is the lower triangular matrix `C` with `self = C * C^T`, that is `C[i][j] = L[i][j] sqrt(D[j])`
+    /// with the [`ldl`](Matrix::ldl) decomposition. Its entries are not a number if the matrix is not positive
+    /// semi-definite.
+    ///
+    /// # Panics
+    /// if the matrix is not square
+    pub fn cholesky(&self) -> Self {
+        let (l, d) = self.ldl();
+        let n = self.dimensions.0;
+        let mut c = l;
+        for i in 0..n {
+            for j in 0..=i {
+                c[i][j] *= d[j].sqrt();
+            }
+        }
+        c
+    }
+
+    /// Initialise a `Matrix` whose entries are uniformly random integers of at most `bit_size` bits, with
+    /// random signs, so that they are represented exactly
+    ///      - `col_num`: number of columns
+    ///      - `col_dim`: number of rows
+    ///
+    /// # Panics
+    /// if `bit_size > 53`
+    pub fn random<R: RngCore>(col_num: usize, col_dim: usize, bit_size: u32, rng: &mut R) -> Self {
+        assert!(bit_size <= f64::MANTISSA_DIGITS);
+        Self::from_fn(col_num, col_dim, || random_i64(bit_size, rng) as f64)
+    }
+
+    /// Return the determinant of the matrix, computed by LU decomposition with partial pivoting
+    ///
+    /// # Panics
+    /// if the matrix is not square
+    pub fn determinant(&self) -> f64 {
+        let (n, dim) = self.dimensions;
+        assert_eq!(n, dim, "The matrix must be square");
+
+        let mut a = self.columns.clone();
+        let mut determinant = 1.;
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&i, &j| a[i][k].abs().total_cmp(&a[j][k].abs()))
+                .unwrap_or(k);
+            if a[pivot][k] == 0. {
+                return 0.;
+            }
+            if pivot != k {
+                a.swap(pivot, k);
+                determinant = -determinant;
+            }
+            determinant *= a[k][k];
+
+            let (top, bottom) = a.split_at_mut(k + 1);
+            let row = &top[k];
+            for other in bottom {
+                let factor = other[k] / row[k];
+                for j in k + 1..n {
+                    other[j] -= factor * row[j];
+                }
+            }
+        }
+        determinant
+    }
+
+    /// Return the reduced row echelon form of the matrix, whose rows are the basis vectors, and the indices of its
+    /// pivot columns, computed by Gauss-Jordan elimination with partial pivoting
+    ///
+    /// An entry is taken as zero when its absolute value is at most `tolerance` times the largest absolute value of
+    /// the entries, so that the rounding errors on (nearly) dependent vectors do not produce pivots. The echelon
+    /// form is otherwise as for rational entries.
+    pub fn rref(&self, tolerance: f64) -> (Self, Vec<usize>) {
+        let (n, dim) = self.dimensions;
+        let largest = (0..n)
+            .flat_map(|i| (0..dim).map(move |j| (i, j)))
+            .fold(0., |largest: f64, (i, j)| largest.max(self[i][j].abs()));
+        let threshold = tolerance * largest;
+        self.gauss_jordan_rref(|x| x.abs(), |x| x.abs() <= threshold)
+    }
+
+    /// Return the rank of the matrix, computed by [`rref`](Matrix::rref) with `tolerance`
+    pub fn rank(&self, tolerance: f64) -> usize {
+        self.rref(tolerance).1.len()
+    }
+}
+
+/// Collect basis vectors, which must have the same dimension, into the columns of a `Matrix`
+impl<T: Coefficient> FromIterator<Vector<T>> for Matrix<T> {
+    fn from_iter<I: IntoIterator<Item = Vector<T>>>(iter: I) -> Self {
+        Self::from_columns(iter.into_iter().collect())
+    }
+}
+
+impl<T: Coefficient> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = DimensionMismatch;
+
+    fn try_from(matrix: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Self::try_from_matrix(matrix)
+    }
 }
 
 /// Direct access to a column
@@ -82,8 +954,100 @@ impl<T: Coefficient> IndexMut<usize> for Matrix<T> {
     }
 }
 
+/// Matrix product, on the entries: `(A * B)[i][j] = sum_k A[i][k] * B[k][j]`
+///
+/// When the basis vectors are the rows of the matrices, as in most papers, `U * B` is the matrix whose vectors
+/// are the combinations of the vectors of `B` given by the vectors of `U`.
+///
+/// # Panics
+/// if the vectors of `A` do not have one coefficient per vector of `B`
+impl<T: Coefficient> Mul<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        Matrix::from_columns(self.columns.iter().map(|v| v * other).collect())
+    }
+}
+
+/// Product of a matrix and a vector, on the entries: `(A * v)[i] = sum_k A[i][k] * v[k]`
+///
+/// # Panics
+/// if the vectors of `A` do not have the dimension of `v`
+impl<T: Coefficient> Mul<&Vector<T>> for &Matrix<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, other: &Vector<T>) -> Vector<T> {
+        self.columns
+            .iter()
+            .for_each(|v| assert_eq!(v.dimension(), other.dimension()));
+        self.columns.iter().map(|v| v.dot(other)).collect()
+    }
+}
+
+/// Product of a vector and a matrix, on the entries: `(v * A)[j] = sum_k v[k] * A[k][j]`
+///
+/// This is the combination of the vectors of `A` with the coefficients of `v`.
+///
+/// # Panics
+/// if `v` does not have one coefficient per vector of `A`
+impl<T: Coefficient> Mul<&Matrix<T>> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Vector<T> {
+        let (n, dimension) = other.dimensions;
+        assert_eq!(self.dimension(), n);
+        (0..n).fold(Vector::zero(dimension), |sum, k| {
+            sum.add(&other[k].mulf(self[k].clone()))
+        })
+    }
+}
+
+/// Direct access to an entry: `matrix[(i, j)]` is the `j`-th coordinate of the `i`-th column
+impl<T: Coefficient> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.columns[i][j]
+    }
+}
+
+/// Direct access to an entry (mutable)
+impl<T: Coefficient> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.columns[i][j]
+    }
+}
+
 impl<T: Coefficient> fmt::Debug for Matrix<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "{:?}", self.columns)
     }
 }
+
+/// Conversions of matrices of machine integers, whose inner vectors are the basis vectors as in
+/// `Matrix::from_matrix`
+macro_rules! impl_from_primitive {
+    ($to_type:ty, $code:expr, $($from_type:ty),*) => {$(
+        impl From<Vec<Vec<$from_type>>> for Matrix<$to_type> {
+            fn from(matrix: Vec<Vec<$from_type>>) -> Self {
+                let columns: Vec<&[$from_type]> = matrix.iter().map(|column| &column[..]).collect();
+                Self::from(&columns[..])
+            }
+        }
+
+        impl From<&[&[$from_type]]> for Matrix<$to_type> {
+            fn from(matrix: &[&[$from_type]]) -> Self {
+                matrix
+                    .iter()
+                    .map(|column| column.iter().map(|&n| $code(n)).collect())
+                    .collect()
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "rug")]
+impl_from_primitive!(rug::Integer, rug::Integer::from, i32, i64, u32, u64);
+impl_from_primitive!(f64, f64::from, i32, u32);
+// Integers beyond 2^53 are rounded to the nearest f64
+impl_from_primitive!(f64, |n: i64| n as f64, i64);
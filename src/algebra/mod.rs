@@ -1,7 +1,50 @@
+mod basis;
+mod checked;
+#[cfg(feature = "crypto-bigint")]
+mod crypto_backend;
+#[cfg(feature = "rug")]
+mod double_double;
+mod fixed;
+mod format;
+#[cfg(feature = "num-traits")]
+mod generic;
+mod gso;
+#[cfg(feature = "rug")]
+mod interval;
 mod matrix;
+#[cfg(feature = "num")]
+mod num_backend;
 mod scalar;
+mod smatrix;
+mod transform;
 mod vector;
 
-pub use matrix::Matrix;
-pub use scalar::{BigNum, Coefficient, Float, FromExt, Scalar};
-pub use vector::Vector;
+pub use basis::{Basis, InvalidBasis};
+pub(crate) use checked::with_overflow_check;
+pub use checked::{Checked, CheckedI128, CheckedI64, Overflow};
+#[cfg(feature = "crypto-bigint")]
+pub use crypto_backend::FixedInt;
+#[cfg(feature = "rug")]
+pub use double_double::{BigDoubleDouble, DoubleDouble};
+pub use fixed::{Fixed, FixedPoint};
+pub use format::{Alignment, FormatOptions};
+#[cfg(feature = "num-traits")]
+pub use generic::{Num, NumInteger, NumTraits};
+pub use gso::Gso;
+#[cfg(feature = "rug")]
+pub use interval::Certified;
+#[cfg(feature = "rug")]
+pub(crate) use matrix::centre;
+pub use matrix::{DimensionMismatch, EntrySizeStats, Matrix};
+#[cfg(feature = "num")]
+pub use num_backend::NumBigInt;
+#[cfg(feature = "rug")]
+pub(crate) use scalar::with_precision;
+#[cfg(feature = "rug")]
+pub use scalar::{BigDouble, BigFloat, BigNum};
+pub use scalar::{Coefficient, Float, Float32, FromExt, HouseholderScalar, Scalar, Single};
+pub use smatrix::SMatrix;
+pub use transform::Transform;
+#[cfg(feature = "rug")]
+pub use vector::BigVector;
+pub use vector::{Vector, VectorF};
@@ -0,0 +1,153 @@
+//! Pure-Rust arbitrary precision backend, relying on `num-bigint` and `num-rational`
+
+use super::scalar::{FromExt, Scalar};
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::{
+    cmp::Ordering,
+    iter::Sum,
+    ops::{Add, Div, Mul, Sub, SubAssign},
+};
+
+/// Rational numbers of the `num-bigint` backend
+///
+/// This wraps `num_rational::BigRational` so that it can be compared with `BigInt` and built from `i32`.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct Fraction(pub BigRational);
+
+impl Default for Fraction {
+    fn default() -> Self {
+        Fraction(BigRational::zero())
+    }
+}
+
+impl From<i32> for Fraction {
+    fn from(n: i32) -> Self {
+        Fraction(BigRational::from_integer(BigInt::from(n)))
+    }
+}
+
+impl Add<&Fraction> for Fraction {
+    type Output = Fraction;
+
+    fn add(self, other: &Fraction) -> Fraction {
+        Fraction(self.0 + &other.0)
+    }
+}
+
+impl Sub<&Fraction> for Fraction {
+    type Output = Fraction;
+
+    fn sub(self, other: &Fraction) -> Fraction {
+        Fraction(self.0 - &other.0)
+    }
+}
+
+impl SubAssign<&Fraction> for Fraction {
+    fn sub_assign(&mut self, other: &Fraction) {
+        self.0 = &self.0 - &other.0;
+    }
+}
+
+impl Mul<&Fraction> for Fraction {
+    type Output = Fraction;
+
+    fn mul(self, other: &Fraction) -> Fraction {
+        Fraction(self.0 * &other.0)
+    }
+}
+
+impl Div<&Fraction> for Fraction {
+    type Output = Fraction;
+
+    fn div(self, other: &Fraction) -> Fraction {
+        Fraction(self.0 / &other.0)
+    }
+}
+
+impl Sum<Fraction> for Fraction {
+    fn sum<I: Iterator<Item = Fraction>>(iter: I) -> Self {
+        iter.fold(Fraction::default(), |acc, f| acc + &f)
+    }
+}
+
+impl PartialEq<BigInt> for Fraction {
+    fn eq(&self, other: &BigInt) -> bool {
+        self.0.is_integer() && self.0.numer() == other
+    }
+}
+
+impl PartialOrd<BigInt> for Fraction {
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        // The denominator is positive
+        self.0.numer().partial_cmp(&(other * self.0.denom()))
+    }
+}
+
+impl FromExt<f64> for Fraction {
+    fn from_ext(f: f64) -> Self {
+        Fraction(BigRational::from_float(f).unwrap())
+    }
+}
+
+impl FromExt<(BigInt, BigInt)> for Fraction {
+    fn from_ext((n, d): (BigInt, BigInt)) -> Self {
+        Fraction(BigRational::new(n, d))
+    }
+}
+
+impl FromExt<(i32, i32)> for Fraction {
+    fn from_ext((n, d): (i32, i32)) -> Self {
+        Fraction(BigRational::new(BigInt::from(n), BigInt::from(d)))
+    }
+}
+
+impl FromExt<&BigInt> for Fraction {
+    fn from_ext(n: &BigInt) -> Self {
+        Fraction(BigRational::from_integer(n.clone()))
+    }
+}
+
+/// `|n|` as a mantissa in `[0.5, 1)` rounded towards zero and an exponent, as `rug::Integer::to_f64_exp`
+fn to_f64_exp(n: &BigInt) -> (f64, f64) {
+    let bits = n.bits();
+    let shift = bits.saturating_sub(u64::from(f64::MANTISSA_DIGITS));
+    // At most 53 bits, which a double represents exactly
+    let top = (n.abs() >> shift).to_f64().unwrap();
+    (top / 2_f64.powi((bits - shift) as i32), bits as f64)
+}
+
+pub struct NumBigInt;
+
+impl Scalar for NumBigInt {
+    type Integer = BigInt;
+    type Fraction = Fraction;
+
+    fn round(f: &Self::Fraction) -> Self::Integer {
+        let (trunc, fract) = (f.0.trunc(), f.0.fract());
+        let half = BigRational::new(BigInt::one(), BigInt::from(2));
+        if fract.abs() > half {
+            (trunc + f.0.signum()).to_integer()
+        } else {
+            trunc.to_integer()
+        }
+    }
+
+    fn round_div(n: Self::Integer, d: Self::Integer) -> Self::Integer {
+        // Ties are rounded away from zero
+        BigRational::new(n, d).round().to_integer()
+    }
+
+    fn abs(f: Self::Fraction) -> Self::Fraction {
+        Fraction(f.0.abs())
+    }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        // Go through the exponents as the rug backend, so that both give the same logarithms
+        let (numer_mantissa, numer_exp) = to_f64_exp(f.0.numer());
+        let (denom_mantissa, denom_exp) = to_f64_exp(f.0.denom());
+        numer_mantissa.log2() - denom_mantissa.log2() + numer_exp - denom_exp
+    }
+}
@@ -1,4 +1,7 @@
+#[cfg(feature = "rug")]
 use rug::{Integer, Rational};
+#[cfg(feature = "rug")]
+use std::{cell::Cell, cmp::Ordering};
 use std::{
     cmp::PartialOrd,
     fmt::Debug,
@@ -63,6 +66,31 @@ pub trait Scalar {
     fn round(n: &Self::Fraction) -> Self::Integer;
     fn round_div(n: Self::Integer, d: Self::Integer) -> Self::Integer;
     fn abs(f: Self::Fraction) -> Self::Fraction;
+    fn log2(f: &Self::Fraction) -> f64;
+
+    /// Whether an arithmetic operation overflowed, in which case the computation is aborted
+    fn overflowed() -> bool {
+        false
+    }
+
+    /// Whether the numbers can be non-finite, i.e. whether `is_finite` is overridden
+    const NON_FINITE: bool = false;
+
+    /// Whether a number is finite, a non-finite Gram-Schmidt coefficient aborting the computation
+    fn is_finite(_: &Self::Fraction) -> bool {
+        true
+    }
+
+    /// Precision in bits of the Gram-Schmidt coefficients, `u32::MAX` if they are exact
+    fn precision() -> u32 {
+        u32::MAX
+    }
+}
+
+/// A floating-point backend, which supports the Householder orthogonalisation
+pub trait HouseholderScalar: Scalar {
+    /// Square root of a non-negative number
+    fn sqrt(f: &Self::Fraction) -> Self::Fraction;
 }
 
 impl_from_ext!(&f64, f64, |f: &f64| *f);
@@ -75,6 +103,7 @@ pub struct Float;
 impl Scalar for Float {
     type Integer = f64;
     type Fraction = f64;
+    const NON_FINITE: bool = true;
 
     fn round(f: &Self::Fraction) -> Self::Integer {
         let (int, fract) = (f.trunc(), f.fract());
@@ -92,24 +121,149 @@ impl Scalar for Float {
     fn abs(f: Self::Fraction) -> Self::Fraction {
         f.abs()
     }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        f.log2()
+    }
+
+    fn is_finite(f: &Self::Fraction) -> bool {
+        f.is_finite()
+    }
+
+    fn precision() -> u32 {
+        f64::MANTISSA_DIGITS
+    }
+}
+
+impl HouseholderScalar for Float {
+    fn sqrt(f: &Self::Fraction) -> Self::Fraction {
+        f.sqrt()
+    }
+}
+
+/// A single precision floating-point number
+///
+/// This wraps `f32`, which cannot be built from `i32` without loss, so that it can be used as a coefficient.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Single(pub f32);
+
+impl From<i32> for Single {
+    fn from(n: i32) -> Self {
+        Single(n as f32)
+    }
+}
+
+impl Add<&Single> for Single {
+    type Output = Single;
+
+    fn add(self, other: &Single) -> Single {
+        Single(self.0 + other.0)
+    }
+}
+
+impl Sub<&Single> for Single {
+    type Output = Single;
+
+    fn sub(self, other: &Single) -> Single {
+        Single(self.0 - other.0)
+    }
+}
+
+impl SubAssign<&Single> for Single {
+    fn sub_assign(&mut self, other: &Single) {
+        self.0 -= other.0;
+    }
+}
+
+impl Mul<&Single> for Single {
+    type Output = Single;
+
+    fn mul(self, other: &Single) -> Single {
+        Single(self.0 * other.0)
+    }
+}
+
+impl Div<&Single> for Single {
+    type Output = Single;
+
+    fn div(self, other: &Single) -> Single {
+        Single(self.0 / other.0)
+    }
 }
 
+impl Sum<Single> for Single {
+    fn sum<I: Iterator<Item = Single>>(iter: I) -> Self {
+        Single(iter.map(|f| f.0).sum())
+    }
+}
+
+impl_from_ext!(&Single, Single, |f: &Single| *f);
+impl_from_ext!((Single, Single), Single, |(n, d): (Single, Single)| {
+    Single(n.0 / d.0)
+});
+impl_from_ext!(f64, Single, |f| Single(f as f32));
+impl_from_ext!((i32, i32), Single, |(n, d)| Single(n as f32 / d as f32));
+
+/// Single precision floating-point numbers, for low-memory reductions of small lattices
+pub struct Float32;
+
+impl Scalar for Float32 {
+    type Integer = Single;
+    type Fraction = Single;
+    const NON_FINITE: bool = true;
+
+    fn round(f: &Self::Fraction) -> Self::Integer {
+        let (int, fract) = (f.0.trunc(), f.0.fract());
+        if fract.abs() > 0.5 {
+            Single(f.0.signum() * (int.abs() + 1.))
+        } else {
+            Single(int)
+        }
+    }
+
+    fn round_div(n: Self::Integer, d: Self::Integer) -> Self::Integer {
+        Single((n.0 / d.0).round())
+    }
+
+    fn abs(f: Self::Fraction) -> Self::Fraction {
+        Single(f.0.abs())
+    }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        f64::from(f.0.log2())
+    }
+
+    fn is_finite(f: &Self::Fraction) -> bool {
+        f.0.is_finite()
+    }
+
+    fn precision() -> u32 {
+        f32::MANTISSA_DIGITS
+    }
+}
+
+#[cfg(feature = "rug")]
 impl_from_ext!(&Integer, Rational, |f: &Integer| Rational::from(f));
+#[cfg(feature = "rug")]
 impl_from_ext!((Integer, Integer), Rational, |(n, d)| Rational::from((
     n, d
 )));
+#[cfg(feature = "rug")]
 impl_from_ext!(f64, Rational, |f: f64| Rational::from_f64(f).unwrap());
+#[cfg(feature = "rug")]
 impl_from_ext!((i32, i32), Rational, |(n, d)| Rational::from((n, d)));
 
+#[cfg(feature = "rug")]
 pub struct BigNum;
 
+#[cfg(feature = "rug")]
 impl Scalar for BigNum {
     type Integer = rug::Integer;
     type Fraction = rug::Rational;
 
     fn round(f: &Self::Fraction) -> Self::Integer {
         let (fract, trunc) = f.clone().fract_trunc(Integer::new());
-        if fract.abs() > (1_u16, 2_u16) {
+        if fract.abs() > Rational::from((1, 2)) {
             f.clone().signum().numer() * (trunc.abs() + Integer::from(1))
         } else {
             trunc
@@ -124,4 +278,230 @@ impl Scalar for BigNum {
     fn abs(f: Self::Fraction) -> Self::Fraction {
         f.abs()
     }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        // Go through the exponents so that huge values do not overflow an f64
+        let (numer_mantissa, numer_exp) = f.numer().to_f64_exp();
+        let (denom_mantissa, denom_exp) = f.denom().to_f64_exp();
+        numer_mantissa.log2() - denom_mantissa.log2() + f64::from(numer_exp) - f64::from(denom_exp)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl_from_ext!(&Integer, f64, |f: &Integer| f.to_f64());
+#[cfg(feature = "rug")]
+impl_from_ext!((Integer, Integer), f64, |(n, d)| Rational::from((n, d))
+    .to_f64());
+
+/// Arbitrary precision integers (`rug::Integer`) and double floating-point numbers
+#[cfg(feature = "rug")]
+pub struct BigDouble;
+
+#[cfg(feature = "rug")]
+impl Scalar for BigDouble {
+    type Integer = Integer;
+    type Fraction = f64;
+    const NON_FINITE: bool = true;
+
+    fn round(f: &Self::Fraction) -> Self::Integer {
+        // Non-finite numbers are rounded to zero, the failure being detected by the reduction
+        Integer::from_f64(Float::round(f)).unwrap_or_default()
+    }
+
+    fn round_div(n: Self::Integer, d: Self::Integer) -> Self::Integer {
+        BigNum::round_div(n, d)
+    }
+
+    fn abs(f: Self::Fraction) -> Self::Fraction {
+        f.abs()
+    }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        f.log2()
+    }
+
+    fn is_finite(f: &Self::Fraction) -> bool {
+        f.is_finite()
+    }
+
+    fn precision() -> u32 {
+        f64::MANTISSA_DIGITS
+    }
+}
+
+#[cfg(feature = "rug")]
+thread_local! {
+    /// Precision in bits of the numbers created by the `BigFloat` backend on this thread
+    static PRECISION: Cell<u32> = const { Cell::new(53) };
+}
+
+/// Restores the previous precision of the `BigFloat` backend when dropped
+#[cfg(feature = "rug")]
+struct PrecisionGuard(u32);
+
+#[cfg(feature = "rug")]
+impl Drop for PrecisionGuard {
+    fn drop(&mut self) {
+        PRECISION.with(|p| p.set(self.0));
+    }
+}
+
+/// Runs `f` with the precision of the `BigFloat` backend set to `precision` bits
+#[cfg(feature = "rug")]
+pub(crate) fn with_precision<T>(precision: u32, f: impl FnOnce() -> T) -> T {
+    let _guard = PrecisionGuard(PRECISION.with(|p| p.replace(precision)));
+    f()
+}
+
+/// A `rug::Float` number created at the precision of the `BigFloat` backend
+#[cfg(feature = "rug")]
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct MpFloat(pub rug::Float);
+
+#[cfg(feature = "rug")]
+impl MpFloat {
+    fn with_val<T>(value: T) -> Self
+    where
+        rug::Float: rug::Assign<T>,
+    {
+        MpFloat(rug::Float::with_val(PRECISION.with(Cell::get), value))
+    }
+}
+
+#[cfg(feature = "rug")]
+impl Default for MpFloat {
+    fn default() -> Self {
+        MpFloat::with_val(0)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl From<i32> for MpFloat {
+    fn from(n: i32) -> Self {
+        MpFloat::with_val(n)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl Add<&MpFloat> for MpFloat {
+    type Output = MpFloat;
+
+    fn add(self, other: &MpFloat) -> MpFloat {
+        MpFloat(self.0 + &other.0)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl Sub<&MpFloat> for MpFloat {
+    type Output = MpFloat;
+
+    fn sub(self, other: &MpFloat) -> MpFloat {
+        MpFloat(self.0 - &other.0)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl SubAssign<&MpFloat> for MpFloat {
+    fn sub_assign(&mut self, other: &MpFloat) {
+        self.0 -= &other.0;
+    }
+}
+
+#[cfg(feature = "rug")]
+impl Mul<&MpFloat> for MpFloat {
+    type Output = MpFloat;
+
+    fn mul(self, other: &MpFloat) -> MpFloat {
+        MpFloat(self.0 * &other.0)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl Div<&MpFloat> for MpFloat {
+    type Output = MpFloat;
+
+    fn div(self, other: &MpFloat) -> MpFloat {
+        MpFloat(self.0 / &other.0)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl Sum<MpFloat> for MpFloat {
+    fn sum<I: Iterator<Item = MpFloat>>(iter: I) -> Self {
+        iter.fold(MpFloat::from(0), |acc, f| acc + &f)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl PartialEq<Integer> for MpFloat {
+    fn eq(&self, other: &Integer) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(feature = "rug")]
+impl PartialOrd<Integer> for MpFloat {
+    fn partial_cmp(&self, other: &Integer) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl_from_ext!(&Integer, MpFloat, |f: &Integer| MpFloat::with_val(f));
+#[cfg(feature = "rug")]
+impl_from_ext!((Integer, Integer), MpFloat, |(n, d)| MpFloat::with_val(
+    Rational::from((n, d))
+));
+#[cfg(feature = "rug")]
+impl_from_ext!(f64, MpFloat, |f: f64| MpFloat::with_val(f));
+#[cfg(feature = "rug")]
+impl_from_ext!((i32, i32), MpFloat, |(n, d)| MpFloat::with_val(
+    Rational::from((n, d))
+));
+
+/// Arbitrary precision floating-point numbers (`rug::Float`), with a precision chosen at runtime
+#[cfg(feature = "rug")]
+pub struct BigFloat;
+
+#[cfg(feature = "rug")]
+impl Scalar for BigFloat {
+    type Integer = rug::Integer;
+    type Fraction = MpFloat;
+    const NON_FINITE: bool = true;
+
+    fn round(f: &Self::Fraction) -> Self::Integer {
+        let (trunc, fract) = f.0.clone().trunc_fract(rug::Float::new(f.0.prec()));
+        // Non-finite numbers are rounded to zero, the failure being detected by the reduction
+        let trunc = trunc.to_integer().unwrap_or_default();
+        if fract.clone().abs() > 0.5 {
+            if fract.is_sign_negative() {
+                trunc - 1
+            } else {
+                trunc + 1
+            }
+        } else {
+            trunc
+        }
+    }
+
+    fn round_div(mut n: Self::Integer, mut d: Self::Integer) -> Self::Integer {
+        n.div_rem_round_mut(&mut d);
+        n
+    }
+
+    fn abs(f: Self::Fraction) -> Self::Fraction {
+        MpFloat(f.0.abs())
+    }
+
+    fn log2(f: &Self::Fraction) -> f64 {
+        rug::Float::with_val(53, f.0.log2_ref()).to_f64()
+    }
+
+    fn is_finite(f: &Self::Fraction) -> bool {
+        f.0.is_finite()
+    }
+
+    fn precision() -> u32 {
+        PRECISION.with(Cell::get)
+    }
 }
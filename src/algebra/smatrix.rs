@@ -0,0 +1,73 @@
+//! Matrices of small dimensions known at compile time, stored on the stack
+
+use super::{Coefficient, Matrix, Vector};
+
+use std::ops::{Index, IndexMut};
+
+/// A `SMatrix` is a basis of `R` vectors of dimension `C`, stored on the stack
+///
+/// As with `Matrix`, `smatrix[i]` is the `i`-th basis vector: the basis is the `R × C` matrix whose rows are the
+/// basis vectors, as written in most papers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SMatrix<T, const R: usize, const C: usize> {
+    /// Basis vectors
+    pub(crate) vectors: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /// Create a `SMatrix` from its basis vectors
+    pub fn new(vectors: [[T; C]; R]) -> Self {
+        Self { vectors }
+    }
+
+    /// Return the matrix dimensions
+    pub fn dimensions(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    /// Return the basis vectors
+    pub fn into_inner(self) -> [[T; C]; R] {
+        self.vectors
+    }
+
+    /// Return the `SMatrix` whose entries are the images of the entries of `self` by `f`
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> SMatrix<U, R, C> {
+        SMatrix {
+            vectors: std::array::from_fn(|i| std::array::from_fn(|j| f(&self.vectors[i][j]))),
+        }
+    }
+}
+
+impl<T: Coefficient, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /// Return the `Matrix` with the same basis vectors, stored on the heap
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_columns(
+            self.vectors
+                .iter()
+                .map(|vector| Vector::from_vector(vector.to_vec()))
+                .collect(),
+        )
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for SMatrix<T, R, C> {
+    fn from(vectors: [[T; C]; R]) -> Self {
+        Self::new(vectors)
+    }
+}
+
+/// Direct access to a basis vector
+impl<T, const R: usize, const C: usize> Index<usize> for SMatrix<T, R, C> {
+    type Output = [T; C];
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.vectors[index]
+    }
+}
+
+/// Direct access to a basis vector (mutable)
+impl<T, const R: usize, const C: usize> IndexMut<usize> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.vectors[index]
+    }
+}
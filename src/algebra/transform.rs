@@ -0,0 +1,194 @@
+//! Transformations of lattice bases, such as the unimodular matrices of reductions
+
+use super::{Coefficient, Matrix, Vector};
+
+/// A transformation of a basis given by the matrix `U` of the combinations: the `i`-th vector of the transformed
+/// basis `U * B` is the combination of the vectors of `B` with the coefficients of `U[i]`
+///
+/// The transformation of a reduction is unimodular, so that the reduced basis spans the same lattice. A
+/// transformation created by [`identity_with_inverse`](Transform::identity_with_inverse) also keeps track of
+/// `U^-1`, which maps the coordinates in the transformed basis back to the initial one.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Transform<T: Coefficient> {
+    /// The matrix `U`, with one vector per transformed vector and one coefficient per initial vector
+    matrix: Matrix<T>,
+    /// The inverse of `U`, if it is tracked
+    inverse: Option<Matrix<T>>,
+}
+
+impl<T: Coefficient> Transform<T> {
+    /// Create the transformation whose combinations are the vectors of `matrix`, without tracking its inverse
+    pub fn new(matrix: Matrix<T>) -> Self {
+        Self {
+            matrix,
+            inverse: None,
+        }
+    }
+
+    /// The transformation leaving a basis of `n` vectors unchanged
+    pub fn identity(n: usize) -> Self {
+        Self::new((0..n).map(|i| Vector::basis_vector(n, i)).collect())
+    }
+
+    /// The transformation leaving a basis of `n` vectors unchanged, whose inverse is updated along with it by the
+    /// elementary operations and the reductions
+    pub fn identity_with_inverse(n: usize) -> Self {
+        let identity = Self::identity(n);
+        Self {
+            inverse: Some(identity.matrix.clone()),
+            ..identity
+        }
+    }
+
+    /// Return the matrix `U` of the combinations
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Return the matrix `U` of the combinations
+    pub fn into_matrix(self) -> Matrix<T> {
+        self.matrix
+    }
+
+    /// Return the inverse of `U`, if it is tracked
+    ///
+    /// The vectors of the initial basis are the combinations of the transformed vectors with the coefficients of
+    /// `U^-1`: `B = U^-1 * (U * B)`.
+    pub fn inverse(&self) -> Option<&Matrix<T>> {
+        self.inverse.as_ref()
+    }
+
+    /// Return the transformation applying `self`, then `next`, whose matrix is `next * self`
+    ///
+    /// The inverse is tracked if it is tracked by both transformations.
+    ///
+    /// # Panics
+    /// if `next` does not transform the vectors produced by `self`
+    pub fn compose(&self, next: &Self) -> Self {
+        assert_eq!(next.matrix.dimensions().1, self.matrix.dimensions().0);
+        Self {
+            matrix: &next.matrix * &self.matrix,
+            inverse: match (&self.inverse, &next.inverse) {
+                (Some(inverse), Some(next_inverse)) => Some(inverse * next_inverse),
+                _ => None,
+            },
+        }
+    }
+
+    /// Number of transformed vectors
+    pub(crate) fn len(&self) -> usize {
+        self.matrix.dimensions().0
+    }
+
+    /// Record the addition of `factor` times the `source`-th vector to the `target`-th one
+    pub(crate) fn add_multiple(&mut self, target: usize, source: usize, factor: &T) {
+        let multiple = &self.matrix[source] * factor;
+        self.matrix[target] += &multiple;
+        // U^-1 is multiplied on the right by the inverse operation
+        if let Some(inverse) = &mut self.inverse {
+            for i in 0..inverse.dimensions().0 {
+                let multiple = inverse[i][target].clone() * factor;
+                inverse[i][source] -= &multiple;
+            }
+        }
+    }
+
+    /// Record the negation of the `i`-th vector
+    pub(crate) fn negate(&mut self, i: usize) {
+        self.matrix[i] = -&self.matrix[i];
+        if let Some(inverse) = &mut self.inverse {
+            for k in 0..inverse.dimensions().0 {
+                inverse[k][i] = T::from(0) - &inverse[k][i];
+            }
+        }
+    }
+
+    /// Record the swap of two vectors
+    pub(crate) fn swap(&mut self, i: usize, j: usize) {
+        self.matrix.swap(i, j);
+        if let Some(inverse) = &mut self.inverse {
+            for k in 0..inverse.dimensions().0 {
+                let coefficient = inverse[k][i].clone();
+                inverse[k][i] = inverse[k][j].clone();
+                inverse[k][j] = coefficient;
+            }
+        }
+    }
+
+    /// Record the insertion of the `i`-th vector before the `j`-th one, as `Matrix::insert`
+    pub(crate) fn insert(&mut self, i: usize, j: usize) {
+        self.matrix.insert(i, j);
+        if let Some(inverse) = &mut self.inverse {
+            let mut permutation: Vec<usize> = (0..self.matrix.dimensions().0).collect();
+            let moved = permutation.remove(i);
+            permutation.insert(j, moved);
+            for k in 0..inverse.dimensions().0 {
+                inverse[k] = inverse[k].permute(&permutation);
+            }
+        }
+    }
+}
+
+impl<T: Coefficient> From<Matrix<T>> for Transform<T> {
+    fn from(matrix: Matrix<T>) -> Self {
+        Self::new(matrix)
+    }
+}
+
+impl<T: Coefficient> Matrix<T> {
+    /// Apply `transform` to the vectors of `self`, returning `U * self`
+    ///
+    /// The vectors need not be the basis the transform was recorded on: any data with one vector per basis
+    /// vector, such as the extra coordinates of an embedding, follows the same combinations.
+    ///
+    /// # Panics
+    /// if `self` does not have one vector per coefficient of the combinations
+    pub fn apply_transform(&self, transform: &Transform<T>) -> Self {
+        assert_eq!(transform.matrix.dimensions().1, self.dimensions().0);
+        transform.matrix() * self
+    }
+
+    /// Add `factor` times the `source`-th column to the `target`-th one, and record the operation in `transform`
+    ///
+    /// # Panics
+    /// if `target` and `source` are equal, or if `transform` does not have one vector per column
+    pub fn column_add_multiple(
+        &mut self,
+        target: usize,
+        source: usize,
+        factor: &T,
+        transform: Option<&mut Transform<T>>,
+    ) {
+        assert_ne!(target, source);
+        if let Some(transform) = transform {
+            assert_eq!(transform.len(), self.dimensions().0);
+            transform.add_multiple(target, source, factor);
+        }
+        let multiple = &self[source] * factor;
+        self[target] += &multiple;
+    }
+
+    /// Negate the `i`-th column, and record the operation in `transform`
+    ///
+    /// # Panics
+    /// if `transform` does not have one vector per column
+    pub fn column_negate(&mut self, i: usize, transform: Option<&mut Transform<T>>) {
+        if let Some(transform) = transform {
+            assert_eq!(transform.len(), self.dimensions().0);
+            transform.negate(i);
+        }
+        self[i] = -&self[i];
+    }
+
+    /// Swap two columns, and record the operation in `transform`
+    ///
+    /// # Panics
+    /// if `transform` does not have one vector per column
+    pub fn column_swap(&mut self, i: usize, j: usize, transform: Option<&mut Transform<T>>) {
+        if let Some(transform) = transform {
+            assert_eq!(transform.len(), self.dimensions().0);
+            transform.swap(i, j);
+        }
+        self.swap(i, j);
+    }
+}
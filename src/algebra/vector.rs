@@ -1,16 +1,27 @@
 //! Basic vector structures for LLL
-use super::Coefficient;
+use super::{Coefficient, DimensionMismatch};
 
 use std::{
     fmt,
-    ops::{Index, IndexMut},
+    iter::{self, FromIterator},
+    mem,
+    ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
+/// Storage of the coefficients: with the `smallvec` feature, the coefficients of vectors of dimension up to 8 are
+/// stored inline instead of on the heap
+#[cfg(feature = "smallvec")]
+type Coefficients<T> = smallvec::SmallVec<[T; 8]>;
+#[cfg(not(feature = "smallvec"))]
+type Coefficients<T> = Vec<T>;
+
 /// Implementation of a vector without generic coefficients
-#[derive(Clone, PartialEq)]
+///
+/// A `Vector<T>` is `Send` and `Sync` when `T` is.
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Vector<T: Coefficient> {
     /// Internal representation as a list of coefficients
-    coefficients: Vec<T>,
+    coefficients: Coefficients<T>,
 }
 
 impl<T: Coefficient> Vector<T> {
@@ -32,7 +43,7 @@ impl<T: Coefficient> Vector<T> {
 
     pub fn init(dimension: usize) -> Self {
         Self {
-            coefficients: vec![Default::default(); dimension],
+            coefficients: iter::repeat_with(T::default).take(dimension).collect(),
         }
     }
 
@@ -40,16 +51,24 @@ impl<T: Coefficient> Vector<T> {
         self.coefficients.len()
     }
 
+    /// Return the coefficients
+    pub(crate) fn as_slice(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Return the coefficients (mutable)
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.coefficients
+    }
+
     pub fn add(&self, other: &Self) -> Self {
         let n = self.dimension();
 
         assert_eq!(n, other.dimension());
 
-        Self::from_vector(
-            (0..n)
-                .map(|i| self.coefficients[i].clone() + &other.coefficients[i])
-                .collect(),
-        )
+        (0..n)
+            .map(|i| self.coefficients[i].clone() + &other.coefficients[i])
+            .collect()
     }
 
     pub fn sub(&self, other: &Self) -> Self {
@@ -57,31 +76,109 @@ impl<T: Coefficient> Vector<T> {
 
         assert_eq!(n, other.dimension());
 
-        Self::from_vector(
-            (0..n)
-                .map(|i| self.coefficients[i].clone() - &other.coefficients[i])
-                .collect(),
-        )
+        (0..n)
+            .map(|i| self.coefficients[i].clone() - &other.coefficients[i])
+            .collect()
+    }
+
+    /// Sum of `self` and `other`, or an error if they do not have the same dimension
+    pub fn try_add(&self, other: &Self) -> Result<Self, DimensionMismatch> {
+        self.check_dimension(other)?;
+        Ok(self.add(other))
+    }
+
+    /// Difference of `self` and `other`, or an error if they do not have the same dimension
+    pub fn try_sub(&self, other: &Self) -> Result<Self, DimensionMismatch> {
+        self.check_dimension(other)?;
+        Ok(self.sub(other))
+    }
+
+    /// Inner product of `self` and `other`, or an error if they do not have the same dimension
+    pub fn try_dot(&self, other: &Self) -> Result<T, DimensionMismatch> {
+        self.check_dimension(other)?;
+        Ok(self.dot(other))
+    }
+
+    /// Error reporting `other` as the vector 1 if its dimension differs from the one of `self`
+    fn check_dimension(&self, other: &Self) -> Result<(), DimensionMismatch> {
+        if self.dimension() == other.dimension() {
+            Ok(())
+        } else {
+            Err(DimensionMismatch {
+                index: 1,
+                expected: self.dimension(),
+                found: other.dimension(),
+            })
+        }
+    }
+
+    /// Opposite vector
+    pub fn neg(&self) -> Self {
+        self.map(|a| T::from(0) - a)
     }
 
     /// Create an instance from a `Vec`
     pub fn from_vector(coefficients: Vec<T>) -> Self {
-        Self { coefficients }
+        // With `smallvec`, this is `SmallVec::from_vec`, which keeps the allocation of a large vector
+        Self {
+            coefficients: Coefficients::from(coefficients),
+        }
     }
 
     /// Multiplication by a scalar
     pub fn mulf(&self, other: T) -> Self {
         let n = self.dimension();
 
-        Self::from_vector(
-            (0..n)
-                .map(|i| self.coefficients[i].clone() * &other)
-                .collect(),
-        )
+        (0..n)
+            .map(|i| self.coefficients[i].clone() * &other)
+            .collect()
+    }
+    /// Apply `f` to each coefficient
+    pub fn map<U: Coefficient>(&self, f: impl FnMut(&T) -> U) -> Vector<U> {
+        self.coefficients.iter().map(f).collect()
+    }
+
+    /// Apply `f` to the pairs of coefficients of `self` and `other` with the same index
+    ///
+    /// # Panics
+    /// if the vectors do not have the same dimension
+    pub fn zip_with<U: Coefficient, V: Coefficient>(
+        &self,
+        other: &Vector<U>,
+        mut f: impl FnMut(&T, &U) -> V,
+    ) -> Vector<V> {
+        assert_eq!(self.dimension(), other.dimension());
+
+        (0..self.dimension())
+            .map(|i| f(&self.coefficients[i], &other[i]))
+            .collect()
     }
+
+    /// Apply a permutation to the coordinates: the `i`-th coordinate of the result is the `permutation[i]`-th
+    /// coordinate of `self`
+    ///
+    /// # Panics
+    /// if `permutation` is not a permutation of `0..self.dimension()`
+    pub fn permute(&self, permutation: &[usize]) -> Self {
+        assert!(is_permutation(permutation, self.dimension()));
+        permutation
+            .iter()
+            .map(|&j| self.coefficients[j].clone())
+            .collect()
+    }
+
+    /// Return `self` followed by `extra` coordinates equal to `fill`
+    pub fn extended(&self, extra: usize, fill: T) -> Self {
+        self.coefficients
+            .iter()
+            .cloned()
+            .chain(iter::repeat_n(fill, extra))
+            .collect()
+    }
+
     pub fn zero(dimension: usize) -> Self {
         Self {
-            coefficients: vec![Default::default(); dimension],
+            coefficients: iter::repeat_with(T::default).take(dimension).collect(),
         }
     }
 
@@ -89,13 +186,138 @@ impl<T: Coefficient> Vector<T> {
         self == &Vector::zero(self.dimension())
     }
 
+    /// Inner product of `self` and `other` (see [`Vector::try_dot`])
+    ///
+    /// # Panics
+    /// if the vectors do not have the same dimension
     pub fn dot(&self, other: &Self) -> T {
+        assert_eq!(self.dimension(), other.dimension());
+
         self.coefficients
             .iter()
             .zip(&other.coefficients)
             .map(|(coeff_r, coeff_l)| coeff_r.clone() * coeff_l)
             .sum()
     }
+
+    /// Orthogonal projection of `self` onto the line spanned by `other`, or zero if `other` is zero
+    pub fn project_onto(&self, other: &Self) -> Self
+    where
+        T: for<'a> Div<&'a T, Output = T>,
+    {
+        let norm = other.norm_sqr();
+        if norm == T::from(0) {
+            return Self::zero(other.dimension());
+        }
+        other * &(self.dot(other) / &norm)
+    }
+
+    /// Component of `self` orthogonal to the span of `vectors`, by Gram-Schmidt orthogonalisation
+    ///
+    /// The vectors need not be orthogonal nor linearly independent.
+    pub fn orthogonalize_against(&self, vectors: &[Self]) -> Self
+    where
+        T: for<'a> Div<&'a T, Output = T>,
+    {
+        let mut orthogonal: Vec<Self> = Vec::with_capacity(vectors.len());
+        for v in vectors {
+            let v = orthogonal.iter().fold(v.clone(), |v, b| {
+                let projection = v.project_onto(b);
+                v - &projection
+            });
+            if !v.is_zero() {
+                orthogonal.push(v);
+            }
+        }
+        orthogonal.iter().fold(self.clone(), |v, b| {
+            let projection = v.project_onto(b);
+            v - &projection
+        })
+    }
+
+    /// Squared Euclidean norm
+    pub fn norm_sqr(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Sum of the absolute values of the coefficients
+    pub fn l1_norm(&self) -> T {
+        self.coefficients.iter().map(abs).sum()
+    }
+
+    /// Largest absolute value of the coefficients, or zero for an empty vector
+    pub fn max_norm(&self) -> T {
+        self.coefficients
+            .iter()
+            .map(abs)
+            .fold(T::from(0), |max, x| if x > max { x } else { max })
+    }
+}
+
+#[cfg(feature = "rug")]
+impl Vector<rug::Integer> {
+    /// Division by `divisor`, or `None` if a coefficient is not divisible by it
+    ///
+    /// # Panics
+    /// if `divisor` is zero
+    pub fn divf_exact(&self, divisor: &rug::Integer) -> Option<Self> {
+        assert!(*divisor != 0);
+        self.coefficients
+            .iter()
+            .map(|a| {
+                if a.is_divisible(divisor) {
+                    Some(a.clone().div_exact(divisor))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Greatest common divisor of the coefficients, which is non-negative, and zero for the zero vector
+    pub fn content(&self) -> rug::Integer {
+        self.coefficients
+            .iter()
+            .fold(rug::Integer::new(), |gcd, a| gcd.gcd(a))
+    }
+
+    /// Primitive vector of the line spanned by `self`, whose coefficients are divided by their greatest common
+    /// divisor
+    ///
+    /// The zero vector is left unchanged.
+    pub fn primitive(&self) -> Self {
+        let content = self.content();
+        if content == 0 {
+            return self.clone();
+        }
+        self.map(|a| a.clone().div_exact(&content))
+    }
+}
+
+impl Vector<f64> {
+    /// Division by `divisor`
+    pub fn divf(&self, divisor: f64) -> Self {
+        self.map(|a| a / divisor)
+    }
+}
+
+/// Whether `permutation` is a permutation of `0..n`
+fn is_permutation(permutation: &[usize], n: usize) -> bool {
+    let mut seen = vec![false; n];
+    permutation.len() == n
+        && permutation
+            .iter()
+            .all(|&j| j < n && !mem::replace(&mut seen[j], true))
+}
+
+/// Absolute value of a coefficient
+fn abs<T: Coefficient>(x: &T) -> T {
+    let zero = T::from(0);
+    if *x < zero {
+        zero - x
+    } else {
+        x.clone()
+    }
 }
 
 impl<T: Coefficient> Index<usize> for Vector<T> {
@@ -112,11 +334,125 @@ impl<T: Coefficient> IndexMut<usize> for Vector<T> {
     }
 }
 
+impl<T: Coefficient> AsRef<[T]> for Vector<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.coefficients
+    }
+}
+
+impl<T: Coefficient> Add<&Vector<T>> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, other: &Vector<T>) -> Vector<T> {
+        self.zip_with(other, |a, b| a.clone() + b)
+    }
+}
+
+impl<T: Coefficient> Add<&Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(mut self, other: &Vector<T>) -> Vector<T> {
+        self += other;
+        self
+    }
+}
+
+impl<T: Coefficient> AddAssign<&Vector<T>> for Vector<T> {
+    fn add_assign(&mut self, other: &Vector<T>) {
+        assert_eq!(self.dimension(), other.dimension());
+        for (a, b) in self.coefficients.iter_mut().zip(&other.coefficients) {
+            *a = mem::take(a) + b;
+        }
+    }
+}
+
+impl<T: Coefficient> Sub<&Vector<T>> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: &Vector<T>) -> Vector<T> {
+        self.zip_with(other, |a, b| a.clone() - b)
+    }
+}
+
+impl<T: Coefficient> Sub<&Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(mut self, other: &Vector<T>) -> Vector<T> {
+        self -= other;
+        self
+    }
+}
+
+impl<T: Coefficient> SubAssign<&Vector<T>> for Vector<T> {
+    fn sub_assign(&mut self, other: &Vector<T>) {
+        assert_eq!(self.dimension(), other.dimension());
+        for (a, b) in self.coefficients.iter_mut().zip(&other.coefficients) {
+            *a -= b;
+        }
+    }
+}
+
+impl<T: Coefficient> Neg for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Vector<T> {
+        Vector::neg(self)
+    }
+}
+
+impl<T: Coefficient> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Vector<T> {
+        -&self
+    }
+}
+
+/// Multiplication by a scalar
+impl<T: Coefficient> Mul<&T> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, other: &T) -> Vector<T> {
+        self.map(|a| a.clone() * other)
+    }
+}
+
+/// Multiplication by a scalar
+impl<T: Coefficient> Mul<&T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(mut self, other: &T) -> Vector<T> {
+        self *= other;
+        self
+    }
+}
+
+/// Multiplication by a scalar
+impl<T: Coefficient> MulAssign<&T> for Vector<T> {
+    fn mul_assign(&mut self, other: &T) {
+        for a in self.coefficients.iter_mut() {
+            *a = mem::take(a) * other;
+        }
+    }
+}
+
+impl<T: Coefficient> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            coefficients: iter.into_iter().collect(),
+        }
+    }
+}
+
 impl<T: Coefficient> fmt::Debug for Vector<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "{:?}", self.coefficients)
     }
 }
-/*
-pub type VectorF = Vector<Float>;
-pub type BigVector = Vector<BigNum>;*/
+
+/// Vector of double floating-point numbers
+pub type VectorF = Vector<f64>;
+
+/// Vector of arbitrary precision integers
+#[cfg(feature = "rug")]
+pub type BigVector = Vector<rug::Integer>;
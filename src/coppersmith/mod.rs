@@ -0,0 +1,541 @@
+//! Small roots of modular polynomial equations (Coppersmith's method)
+//!
+//! Given a polynomial `f` in one or several variables and a modulus `N`, the functions of this
+//! module find the small integer roots `x` (`|x_i| <= X_i`) of `f(x) = 0 mod N`, or more generally
+//! of `f(x) = 0 mod p` for an unknown divisor `p` of `N` of known size.
+//!
+//! The lattice is spanned by the coefficient vectors of shift polynomials `x^e f^k N^l`, which all
+//! vanish at the root modulo `p^(k + l)`. Which shifts are used is controlled by a [`ShiftStrategy`];
+//! [`JochemszMay`] implements the standard strategy for the modular case.
+//!
+//! After reduction, the short vectors give polynomials vanishing at the root over the integers
+//! (Howgrave-Graham's condition). The root is recovered from these polynomials by linear algebra
+//! and univariate integer root finding, without resultants or Gröbner bases.
+//!
+//! Small roots of polynomial equations over the integers are found similarly, with the strategy of
+//! [`integer_lattice`].
+
+mod polynomial;
+mod roots;
+
+pub use polynomial::{Monomial, Polynomial};
+
+use crate::{algebra::Matrix, l2};
+
+use rug::{ops::Pow, Integer};
+
+use std::collections::BTreeSet;
+
+/// A shift polynomial `x^monomial * f^polynomial_power * N^modulus_power`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shift {
+    pub monomial: Monomial,
+    pub polynomial_power: u32,
+    pub modulus_power: u32,
+}
+
+/// A strategy to choose the shift polynomials spanning the Coppersmith lattice
+///
+/// Implement this trait to experiment with custom lattice constructions. A plain list of shifts
+/// (`Vec<Shift>`) is also a strategy.
+pub trait ShiftStrategy {
+    /// Shift polynomials for the monic polynomial `polynomial`
+    fn shifts(&self, polynomial: &Polynomial) -> Vec<Shift>;
+}
+
+impl ShiftStrategy for Vec<Shift> {
+    fn shifts(&self, _: &Polynomial) -> Vec<Shift> {
+        self.clone()
+    }
+}
+
+/// The basic strategy of Jochemsz and May for modular equations [JM06]
+///
+/// With `l` the leading monomial of `f`, the monomial sets are
+/// `M_k = { x^(i + s) : x^i monomial of f^m, x^i / l^k monomial of f^(m-k), s <= extra_shifts }`
+/// and the shifts are `x^i / l^k * f^k * N^(m-k)` for `x^i` in `M_k \ M_(k+1)`.
+/// For a univariate polynomial this is the construction of Howgrave-Graham.
+///
+/// The same parameters define the basic strategy for equations over the integers, see
+/// [`integer_lattice`].
+///
+/// [JM06]: E. Jochemsz and A. May. A Strategy for Finding Roots of Multivariate Polynomials
+/// with New Applications in Attacking RSA Variants. ASIACRYPT 2006.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JochemszMay {
+    /// Maximal power of `f` in the shifts
+    pub m: u32,
+
+    /// Extra shifts for each variable (missing entries are 0)
+    pub extra_shifts: Vec<u32>,
+}
+
+impl JochemszMay {
+    /// Strategy with parameter `m` and no extra shifts
+    pub fn new(m: u32) -> Self {
+        Self {
+            m,
+            extra_shifts: vec![],
+        }
+    }
+
+    /// Strategy with parameter `m` and extra shifts `x_i^extra_shifts[i]`
+    pub fn with_extra_shifts(m: u32, extra_shifts: Vec<u32>) -> Self {
+        Self { m, extra_shifts }
+    }
+
+    /// All the extra shifts `x^s` with `s <= extra_shifts`, in `n` variables
+    fn extra_monomials(&self, n: usize) -> Vec<Monomial> {
+        let mut extras: Vec<Monomial> = vec![vec![0; n]];
+        for i in 0..n {
+            let bound = self.extra_shifts.get(i).copied().unwrap_or(0);
+            extras = extras
+                .into_iter()
+                .flat_map(|s| {
+                    (0..=bound).map(move |e| {
+                        let mut s = s.clone();
+                        s[i] = e;
+                        s
+                    })
+                })
+                .collect();
+        }
+        extras
+    }
+}
+
+/// Monomials of `f^k` for `k <= max`, assuming no cancellation
+fn support_powers(polynomial: &Polynomial, max: usize) -> Vec<BTreeSet<Monomial>> {
+    let support: Vec<&Monomial> = polynomial.terms().map(|(monomial, _)| monomial).collect();
+    let mut powers: Vec<BTreeSet<Monomial>> =
+        vec![std::iter::once(vec![0; polynomial.variables()]).collect()];
+    for k in 0..max {
+        let next = powers[k]
+            .iter()
+            .flat_map(|a| support.iter().map(move |b| add(a, b)))
+            .collect();
+        powers.push(next);
+    }
+    powers
+}
+
+impl ShiftStrategy for JochemszMay {
+    fn shifts(&self, polynomial: &Polynomial) -> Vec<Shift> {
+        let n = polynomial.variables();
+        let m = self.m as usize;
+        let leading = match polynomial.leading_monomial() {
+            Some(leading) => leading.clone(),
+            None => return vec![],
+        };
+        let powers = support_powers(polynomial, m);
+        let extras = self.extra_monomials(n);
+
+        let monomial_sets: Vec<BTreeSet<Monomial>> = (0..=m)
+            .map(|k| {
+                let leading_power: Monomial = leading.iter().map(|e| e * k as u32).collect();
+                powers[m]
+                    .iter()
+                    .filter(|base| {
+                        sub(base, &leading_power)
+                            .is_some_and(|quotient| powers[m - k].contains(&quotient))
+                    })
+                    .flat_map(|base| extras.iter().map(move |s| add(base, s)))
+                    .collect()
+            })
+            .collect();
+
+        let mut shifts = vec![];
+        for k in 0..=m {
+            let leading_power: Monomial = leading.iter().map(|e| e * k as u32).collect();
+            for monomial in &monomial_sets[k] {
+                if k < m && monomial_sets[k + 1].contains(monomial) {
+                    continue;
+                }
+                shifts.push(Shift {
+                    monomial: sub(monomial, &leading_power)
+                        .expect("Monomials of M_k are divisible by l^k"),
+                    polynomial_power: k as u32,
+                    modulus_power: (m - k) as u32,
+                });
+            }
+        }
+        shifts
+    }
+}
+
+/// Product of two monomials
+fn add(a: &[u32], b: &[u32]) -> Monomial {
+    a.iter().zip(b).map(|(x, y)| x + y).collect()
+}
+
+/// Quotient of two monomials, if it exists
+fn sub(a: &[u32], b: &[u32]) -> Option<Monomial> {
+    a.iter().zip(b).map(|(x, y)| x.checked_sub(*y)).collect()
+}
+
+/// A modular polynomial equation `f(x) = 0 mod p` with a small root
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModularEquation {
+    /// The polynomial `f`
+    pub polynomial: Polynomial,
+
+    /// The modulus `N`
+    pub modulus: Integer,
+
+    /// Lower bound on the divisor `p` of `N` modulo which the root vanishes
+    pub divisor_bound: Integer,
+
+    /// Bounds `X_i` on the absolute values of the coordinates of the root
+    pub root_bounds: Vec<Integer>,
+}
+
+impl ModularEquation {
+    /// The equation `f(x) = 0 mod N` with `|x_i| <= X_i`
+    pub fn new(polynomial: Polynomial, modulus: Integer, root_bounds: Vec<Integer>) -> Self {
+        Self {
+            polynomial,
+            divisor_bound: modulus.clone(),
+            modulus,
+            root_bounds,
+        }
+    }
+
+    /// The equation `f(x) = 0 mod p` with `|x_i| <= X_i`, for an unknown divisor `p >= divisor_bound` of `N`
+    pub fn with_unknown_divisor(
+        polynomial: Polynomial,
+        modulus: Integer,
+        divisor_bound: Integer,
+        root_bounds: Vec<Integer>,
+    ) -> Self {
+        Self {
+            polynomial,
+            modulus,
+            divisor_bound,
+            root_bounds,
+        }
+    }
+
+    /// Whether `x` is a root of the equation
+    pub fn is_root(&self, x: &[Integer]) -> bool {
+        x.iter()
+            .zip(&self.root_bounds)
+            .all(|(x_i, bound)| x_i.clone().abs() <= *bound)
+            && self.polynomial.evaluate(x).gcd(&self.modulus) >= self.divisor_bound
+    }
+}
+
+/// Coppersmith lattice of an equation: the coefficient vectors of the shifts of `f(X_1 x_1, ..., X_n x_n)`
+///
+/// Returns the basis and the monomials indexing its coordinates, as well as the minimal power of `p`
+/// dividing all the shifts at the root.
+///
+/// # Panics
+/// if the leading coefficient of `f` is not invertible modulo `N`
+pub fn lattice<T: ShiftStrategy + ?Sized>(
+    equation: &ModularEquation,
+    strategy: &T,
+) -> (Matrix<Integer>, Vec<Monomial>, u32) {
+    let polynomial = monic(equation);
+    let shifts = strategy.shifts(&polynomial);
+
+    let mut powers = vec![Polynomial::constant(
+        polynomial.variables(),
+        Integer::from(1),
+    )];
+    let polynomials: Vec<Polynomial> = shifts
+        .iter()
+        .map(|shift| {
+            while powers.len() <= shift.polynomial_power as usize {
+                let next = powers.last().unwrap().mul(&polynomial);
+                powers.push(next);
+            }
+            let modulus_power = Integer::from((&equation.modulus).pow(shift.modulus_power));
+            powers[shift.polynomial_power as usize]
+                .mul_monomial(&shift.monomial)
+                .mul_scalar(&modulus_power)
+                .scale_variables(&equation.root_bounds)
+        })
+        .collect();
+
+    let mut monomials: Vec<Monomial> = polynomials
+        .iter()
+        .flat_map(|p| p.terms().map(|(m, _)| m.clone()).collect::<Vec<_>>())
+        .collect();
+    monomials.sort_by(|a, b| polynomial::monomial_cmp(a, b));
+    monomials.dedup();
+
+    let mut basis: Matrix<Integer> = Matrix::init(polynomials.len(), monomials.len());
+    for (i, p) in polynomials.iter().enumerate() {
+        for (j, m) in monomials.iter().enumerate() {
+            basis[i][j] = p.coefficient(m);
+        }
+    }
+
+    let power = shifts
+        .iter()
+        .map(|shift| shift.polynomial_power + shift.modulus_power)
+        .min()
+        .unwrap_or(0);
+
+    (basis, monomials, power)
+}
+
+/// Normalises `f` so that the coefficient of its leading monomial is 1 modulo `N`
+fn monic(equation: &ModularEquation) -> Polynomial {
+    let polynomial = &equation.polynomial;
+    let leading = match polynomial.leading_monomial() {
+        Some(leading) => polynomial.coefficient(leading),
+        None => return polynomial.clone(),
+    };
+    if leading == 1 {
+        return polynomial.clone();
+    }
+
+    let inverse = leading
+        .invert(&equation.modulus)
+        .expect("The leading coefficient must be invertible modulo N");
+    polynomial.mul_scalar(&inverse).rem_euc(&equation.modulus)
+}
+
+/// Small roots of a modular polynomial equation
+///
+/// Builds the lattice given by `strategy` (see [`lattice`]), reduces it with L², and recovers the
+/// common integer roots of the polynomials given by the reduced vectors `v` that satisfy
+/// Howgrave-Graham's condition `||v||_1 < divisor_bound^m`.
+///
+/// Returns the roots that were found, all of which satisfy the equation. The search may fail
+/// (and return no root) when the bounds `X_i` are too large for the chosen strategy.
+///
+/// # Panics
+/// if the leading coefficient of `f` is not invertible modulo `N`
+pub fn small_roots<T: ShiftStrategy + ?Sized>(
+    equation: &ModularEquation,
+    strategy: &T,
+) -> Vec<Vec<Integer>> {
+    let (basis, monomials, power) = lattice(equation, strategy);
+    let threshold = Integer::from((&equation.divisor_bound).pow(power));
+    let polynomials = short_polynomials(
+        basis,
+        &monomials,
+        &threshold,
+        &equation.root_bounds,
+        equation.polynomial.variables(),
+    );
+
+    let mut roots: Vec<Vec<Integer>> = roots::common_roots(&polynomials, &equation.root_bounds)
+        .into_iter()
+        .filter(|x| equation.is_root(x))
+        .collect();
+    roots.sort();
+    roots.dedup();
+    roots
+}
+
+/// Reduces a Coppersmith lattice with L² and returns the polynomials given by the reduced vectors `v`
+/// with `||v||_1 < threshold`, which vanish at the root over the integers
+fn short_polynomials(
+    mut basis: Matrix<Integer>,
+    monomials: &[Monomial],
+    threshold: &Integer,
+    root_bounds: &[Integer],
+    variables: usize,
+) -> Vec<Polynomial> {
+    let (d, _) = basis.dimensions();
+    if d == 0 {
+        return vec![];
+    }
+    l2::lll_bignum(&mut basis, 0.51, 0.99).expect("The lattice basis is valid");
+
+    let scales: Vec<Integer> = monomials
+        .iter()
+        .map(|m| {
+            m.iter()
+                .zip(root_bounds)
+                .fold(Integer::from(1), |acc, (&e, x)| {
+                    acc * Integer::from(x.pow(e))
+                })
+        })
+        .collect();
+
+    (0..d)
+        .filter(|&i| !basis[i].is_zero())
+        .filter(|&i| {
+            let l1_norm: Integer = (0..monomials.len())
+                .map(|j| basis[i][j].clone().abs())
+                .sum();
+            l1_norm < *threshold
+        })
+        .map(|i| {
+            Polynomial::from_terms(
+                variables,
+                monomials
+                    .iter()
+                    .zip(&scales)
+                    .enumerate()
+                    .map(|(j, (m, scale))| (m.clone(), basis[i][j].clone().div_exact(scale)))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// A polynomial equation `f(x) = 0` over the integers with a small root
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntegerEquation {
+    /// The polynomial `f`
+    pub polynomial: Polynomial,
+
+    /// Bounds `X_i` on the absolute values of the coordinates of the root
+    pub root_bounds: Vec<Integer>,
+}
+
+impl IntegerEquation {
+    /// The equation `f(x) = 0` with `|x_i| <= X_i`
+    pub fn new(polynomial: Polynomial, root_bounds: Vec<Integer>) -> Self {
+        Self {
+            polynomial,
+            root_bounds,
+        }
+    }
+
+    /// Whether `x` is a root of the equation
+    pub fn is_root(&self, x: &[Integer]) -> bool {
+        x.iter()
+            .zip(&self.root_bounds)
+            .all(|(x_i, bound)| x_i.clone().abs() <= *bound)
+            && self.polynomial.evaluate(x) == 0
+    }
+}
+
+/// Coppersmith lattice of an equation over the integers, with the basic strategy of Jochemsz and May
+///
+/// With `W >= ||f(X_1 x_1, ..., X_n x_n)||_inf` coprime to `f(0)`, the sets of monomials are
+/// `S = { x^(i + s) : x^i monomial of f^(m-1), s <= extra_shifts }` and
+/// `M = { monomials of x^i f : x^i in S }`. With `l_j` the largest exponent of `x_j` in `S`,
+/// the modulus is `R = W prod X_j^l_j` and the shifts are `x^i f' prod X_j^(l_j - i_j)` for `x^i`
+/// in `S` and `x^i R` for `x^i` in `M \ S`, where `f' = f / f(0) mod R`. They all vanish at the root
+/// modulo `R`.
+///
+/// Returns the basis and the monomials indexing its coordinates, as well as the modulus `R`.
+///
+/// # Panics
+/// if `strategy.m == 0`, or if the constant coefficient of `f` is zero or not coprime to the bounds `X_j`
+pub fn integer_lattice(
+    equation: &IntegerEquation,
+    strategy: &JochemszMay,
+) -> (Matrix<Integer>, Vec<Monomial>, Integer) {
+    assert!(strategy.m >= 1);
+    let polynomial = &equation.polynomial;
+    let n = polynomial.variables();
+    let bounds = &equation.root_bounds;
+
+    let powers = support_powers(polynomial, strategy.m as usize - 1);
+    let extras = strategy.extra_monomials(n);
+    let shifted: BTreeSet<Monomial> = powers[strategy.m as usize - 1]
+        .iter()
+        .flat_map(|base| extras.iter().map(move |s| add(base, s)))
+        .collect();
+    let all: BTreeSet<Monomial> = shifted
+        .iter()
+        .flat_map(|a| polynomial.terms().map(move |(b, _)| add(a, b)))
+        .collect();
+
+    let largest: Vec<u32> = (0..n)
+        .map(|j| shifted.iter().map(|m| m[j]).max().unwrap_or(0))
+        .collect();
+    let constant = polynomial.coefficient(&vec![0; n]);
+    let bound_product = largest
+        .iter()
+        .zip(bounds)
+        .fold(Integer::from(1), |acc, (&l, x)| {
+            acc * Integer::from(x.pow(l))
+        });
+    assert!(
+        constant.clone().gcd(&bound_product) == 1,
+        "The constant coefficient must be coprime to the bounds"
+    );
+
+    // W is increased until the constant coefficient is invertible modulo R
+    let mut w = polynomial
+        .scale_variables(bounds)
+        .terms()
+        .map(|(_, c)| c.clone().abs())
+        .max()
+        .unwrap_or_default();
+    while constant.clone().gcd(&w) != 1 {
+        w += 1;
+    }
+    let modulus = w * bound_product;
+    let inverse = constant
+        .invert(&modulus)
+        .expect("The constant coefficient is coprime to R");
+    let normalised = polynomial.mul_scalar(&inverse).rem_euc(&modulus);
+
+    let scale = |monomial: &[u32]| {
+        monomial
+            .iter()
+            .zip(&largest)
+            .zip(bounds)
+            .fold(Integer::from(1), |acc, ((&i, &l), x)| {
+                acc * Integer::from(x.pow(l - i))
+            })
+    };
+    let polynomials: Vec<Polynomial> = all
+        .iter()
+        .map(|monomial| {
+            if shifted.contains(monomial) {
+                normalised
+                    .mul_monomial(monomial)
+                    .mul_scalar(&scale(monomial))
+            } else {
+                Polynomial::constant(n, modulus.clone()).mul_monomial(monomial)
+            }
+            .scale_variables(bounds)
+        })
+        .collect();
+
+    let mut monomials: Vec<Monomial> = all.into_iter().collect();
+    monomials.sort_by(|a, b| polynomial::monomial_cmp(a, b));
+
+    let mut basis: Matrix<Integer> = Matrix::init(polynomials.len(), monomials.len());
+    for (i, p) in polynomials.iter().enumerate() {
+        for (j, m) in monomials.iter().enumerate() {
+            basis[i][j] = p.coefficient(m);
+        }
+    }
+
+    (basis, monomials, modulus)
+}
+
+/// Small roots of a polynomial equation over the integers
+///
+/// Builds the lattice of [`integer_lattice`], reduces it with L², and recovers the common integer
+/// roots of `f` and of the polynomials given by the reduced vectors `v` with `||v||_1 < R`.
+///
+/// Returns the roots that were found, all of which satisfy the equation. The search may fail
+/// (and return no root) when the bounds `X_i` are too large for the chosen strategy.
+///
+/// # Panics
+/// if `strategy.m == 0`, or if the constant coefficient of `f` is zero or not coprime to the bounds `X_j`
+pub fn integer_small_roots(
+    equation: &IntegerEquation,
+    strategy: &JochemszMay,
+) -> Vec<Vec<Integer>> {
+    let (basis, monomials, modulus) = integer_lattice(equation, strategy);
+    let mut polynomials = short_polynomials(
+        basis,
+        &monomials,
+        &modulus,
+        &equation.root_bounds,
+        equation.polynomial.variables(),
+    );
+    polynomials.push(equation.polynomial.clone());
+
+    let mut roots: Vec<Vec<Integer>> = roots::common_roots(&polynomials, &equation.root_bounds)
+        .into_iter()
+        .filter(|x| equation.is_root(x))
+        .collect();
+    roots.sort();
+    roots.dedup();
+    roots
+}
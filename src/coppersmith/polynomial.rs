@@ -0,0 +1,245 @@
+//! Multivariate polynomials with integer coefficients
+
+use rug::{ops::Pow, Integer};
+
+use std::{cmp::Ordering, collections::BTreeMap, fmt};
+
+/// Exponents of a monomial `x_0^e_0 * ... * x_{n-1}^e_{n-1}`
+pub type Monomial = Vec<u32>;
+
+/// Monomial order used throughout the module: graded, then lexicographic
+pub(crate) fn monomial_cmp(a: &[u32], b: &[u32]) -> Ordering {
+    let degree = |m: &[u32]| m.iter().map(|&e| u64::from(e)).sum::<u64>();
+    degree(a).cmp(&degree(b)).then_with(|| a.cmp(b))
+}
+
+/// A polynomial in `variables` variables with `rug::Integer` coefficients
+#[derive(Clone, PartialEq)]
+pub struct Polynomial {
+    /// Number of variables
+    variables: usize,
+
+    /// Non-zero coefficients, indexed by monomial
+    terms: BTreeMap<Monomial, Integer>,
+}
+
+impl Polynomial {
+    /// The zero polynomial in `variables` variables
+    pub fn zero(variables: usize) -> Self {
+        Self {
+            variables,
+            terms: BTreeMap::new(),
+        }
+    }
+
+    /// A constant polynomial
+    pub fn constant(variables: usize, c: Integer) -> Self {
+        Self::from_terms(variables, vec![(vec![0; variables], c)])
+    }
+
+    /// The polynomial `x_i`
+    pub fn variable(variables: usize, i: usize) -> Self {
+        assert!(i < variables);
+        let mut monomial = vec![0; variables];
+        monomial[i] = 1;
+        Self::from_terms(variables, vec![(monomial, Integer::from(1))])
+    }
+
+    /// Create a polynomial from a list of `(monomial, coefficient)` pairs
+    ///
+    /// Coefficients of repeated monomials are added together.
+    pub fn from_terms(variables: usize, terms: Vec<(Monomial, Integer)>) -> Self {
+        let mut polynomial = Self::zero(variables);
+        for (monomial, c) in terms {
+            polynomial.add_term(monomial, c);
+        }
+        polynomial
+    }
+
+    /// Create a univariate polynomial from its coefficients, from the constant one upwards
+    pub fn univariate(coefficients: Vec<Integer>) -> Self {
+        Self::from_terms(
+            1,
+            coefficients
+                .into_iter()
+                .enumerate()
+                .map(|(i, c)| (vec![i as u32], c))
+                .collect(),
+        )
+    }
+
+    /// Number of variables
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    /// Whether this is the zero polynomial
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Total degree (0 for the zero polynomial)
+    pub fn degree(&self) -> u32 {
+        self.terms
+            .keys()
+            .map(|m| m.iter().sum::<u32>())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Coefficient of `monomial`
+    pub fn coefficient(&self, monomial: &[u32]) -> Integer {
+        self.terms.get(monomial).cloned().unwrap_or_default()
+    }
+
+    /// Non-zero terms of the polynomial
+    pub fn terms(&self) -> impl Iterator<Item = (&Monomial, &Integer)> {
+        self.terms.iter()
+    }
+
+    /// Leading monomial for the graded lexicographic order, `None` for the zero polynomial
+    pub fn leading_monomial(&self) -> Option<&Monomial> {
+        self.terms.keys().max_by(|a, b| monomial_cmp(a, b))
+    }
+
+    /// Adds `c * monomial` to the polynomial
+    pub fn add_term(&mut self, monomial: Monomial, c: Integer) {
+        assert_eq!(monomial.len(), self.variables);
+        let coefficient = self.terms.entry(monomial.clone()).or_default();
+        *coefficient += c;
+        if *coefficient == 0 {
+            self.terms.remove(&monomial);
+        }
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        assert_eq!(self.variables, other.variables);
+        let mut sum = self.clone();
+        for (monomial, c) in other.terms() {
+            sum.add_term(monomial.clone(), c.clone());
+        }
+        sum
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.mul_scalar(&Integer::from(-1)))
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        assert_eq!(self.variables, other.variables);
+        let mut product = Self::zero(self.variables);
+        for (m1, c1) in self.terms() {
+            for (m2, c2) in other.terms() {
+                let monomial = m1.iter().zip(m2).map(|(e1, e2)| e1 + e2).collect();
+                product.add_term(monomial, Integer::from(c1 * c2));
+            }
+        }
+        product
+    }
+
+    /// Multiplication by a scalar
+    pub fn mul_scalar(&self, c: &Integer) -> Self {
+        if *c == 0 {
+            return Self::zero(self.variables);
+        }
+        Self {
+            variables: self.variables,
+            terms: self
+                .terms()
+                .map(|(m, coeff)| (m.clone(), Integer::from(coeff * c)))
+                .collect(),
+        }
+    }
+
+    /// Multiplication by a monomial
+    pub fn mul_monomial(&self, monomial: &[u32]) -> Self {
+        assert_eq!(monomial.len(), self.variables);
+        Self {
+            variables: self.variables,
+            terms: self
+                .terms()
+                .map(|(m, c)| {
+                    (
+                        m.iter().zip(monomial).map(|(e1, e2)| e1 + e2).collect(),
+                        c.clone(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn pow(&self, k: u32) -> Self {
+        (0..k).fold(
+            Self::constant(self.variables, Integer::from(1)),
+            |acc, _| acc.mul(self),
+        )
+    }
+
+    /// Reduces all the coefficients modulo `modulus`, in `[0, modulus)`
+    pub fn rem_euc(&self, modulus: &Integer) -> Self {
+        Self::from_terms(
+            self.variables,
+            self.terms()
+                .map(|(m, c)| {
+                    let mut c = Integer::from(c % modulus);
+                    if c < 0 {
+                        c += modulus;
+                    }
+                    (m.clone(), c)
+                })
+                .collect(),
+        )
+    }
+
+    /// Evaluates the polynomial at `point`
+    pub fn evaluate(&self, point: &[Integer]) -> Integer {
+        assert_eq!(point.len(), self.variables);
+        self.terms()
+            .map(|(m, c)| {
+                m.iter()
+                    .zip(point)
+                    .fold(c.clone(), |acc, (&e, x)| acc * Integer::from(x.pow(e)))
+            })
+            .sum()
+    }
+
+    /// Substitutes `x_i -> scale_i * x_i` for all the variables
+    pub fn scale_variables(&self, scales: &[Integer]) -> Self {
+        assert_eq!(scales.len(), self.variables);
+        Self::from_terms(
+            self.variables,
+            self.terms()
+                .map(|(m, c)| {
+                    let scale = m
+                        .iter()
+                        .zip(scales)
+                        .fold(c.clone(), |acc, (&e, x)| acc * Integer::from(x.pow(e)));
+                    (m.clone(), scale)
+                })
+                .collect(),
+        )
+    }
+
+    /// Substitutes the value `value` to the variable `x_i`
+    ///
+    /// The result is a polynomial in the remaining `variables - 1` variables.
+    pub fn substitute(&self, i: usize, value: &Integer) -> Self {
+        assert!(i < self.variables);
+        Self::from_terms(
+            self.variables - 1,
+            self.terms()
+                .map(|(m, c)| {
+                    let mut monomial = m.clone();
+                    let e = monomial.remove(i);
+                    (monomial, c * Integer::from(value.pow(e)))
+                })
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Debug for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:?}", self.terms)
+    }
+}
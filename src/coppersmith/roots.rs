@@ -0,0 +1,227 @@
+//! Integer roots of systems of polynomials
+//!
+//! The roots are recovered with linear algebra only: the polynomials are put in reduced
+//! row echelon form with the monomials of the last variable ordered last, which exposes
+//! univariate polynomials whose integer roots are found by bisection. Their roots are then
+//! substituted back and the process is repeated on the remaining variables.
+
+use super::polynomial::{monomial_cmp, Monomial, Polynomial};
+
+use rug::{Integer, Rational};
+
+/// Evaluates the univariate polynomial `coefficients` (constant first) at `x`
+fn evaluate(coefficients: &[Integer], x: &Integer) -> Integer {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Integer::new(), |acc, c| acc * x + c)
+}
+
+/// Derivative of the univariate polynomial `coefficients`
+fn derivative(coefficients: &[Integer]) -> Vec<Integer> {
+    coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| Integer::from(c * i as u64))
+        .collect()
+}
+
+/// Finds `t` in `[a, b)` such that the polynomial vanishes or changes sign between `t` and `t + 1`,
+/// given that its values at `a` and `b` have different signs or vanish.
+fn bisect(coefficients: &[Integer], mut a: Integer, mut b: Integer) -> Integer {
+    let sign_a = evaluate(coefficients, &a).cmp0();
+    if sign_a == std::cmp::Ordering::Equal {
+        return a;
+    }
+    while Integer::from(&b - &a) > 1 {
+        let middle = Integer::from(&a + &b) >> 1;
+        if evaluate(coefficients, &middle).cmp0() == sign_a {
+            a = middle;
+        } else {
+            b = middle;
+        }
+    }
+    a
+}
+
+/// Integers `t` in `[low, high]` such that all the real roots of the polynomial in `[low, high]`
+/// lie in some interval `[t, t + 1]`
+fn root_floors(coefficients: &[Integer], low: &Integer, high: &Integer) -> Vec<Integer> {
+    if coefficients.len() <= 1 {
+        return vec![];
+    }
+
+    // The polynomial is monotone between the critical points
+    let mut breakpoints = vec![low.clone()];
+    for t in root_floors(&derivative(coefficients), low, high) {
+        breakpoints.push(t.clone());
+        breakpoints.push(t + 1);
+    }
+    breakpoints.push(high.clone());
+    breakpoints.retain(|t| t >= low && t <= high);
+    breakpoints.sort();
+    breakpoints.dedup();
+
+    let mut floors = vec![];
+    for window in breakpoints.windows(2) {
+        let (a, b) = (&window[0], &window[1]);
+        let sign_a = evaluate(coefficients, a).cmp0();
+        let sign_b = evaluate(coefficients, b).cmp0();
+        if sign_a != sign_b || sign_a == std::cmp::Ordering::Equal {
+            floors.push(bisect(coefficients, a.clone(), b.clone()));
+        }
+        // The critical intervals themselves may hide roots
+        if Integer::from(b - a) == 1 {
+            floors.push(a.clone());
+        }
+    }
+    if evaluate(coefficients, high).cmp0() == std::cmp::Ordering::Equal {
+        floors.push(high.clone());
+    }
+    floors.sort();
+    floors.dedup();
+    floors
+}
+
+/// Integer roots `x` of a non-zero univariate polynomial with `|x| <= bound`
+pub(crate) fn integer_roots(coefficients: &[Integer], bound: &Integer) -> Vec<Integer> {
+    let mut coefficients = coefficients.to_vec();
+    while coefficients.last().is_some_and(|c| *c == 0) {
+        coefficients.pop();
+    }
+
+    let low = Integer::from(-bound);
+    let mut roots: Vec<Integer> = root_floors(&coefficients, &low, bound)
+        .into_iter()
+        .flat_map(|t| vec![t.clone(), t + 1])
+        .filter(|t| t <= bound && evaluate(&coefficients, t) == 0)
+        .collect();
+    roots.sort();
+    roots.dedup();
+    roots
+}
+
+/// Reduced row echelon form of the coefficient matrix of `polynomials` over the monomials `columns`
+fn row_echelon(polynomials: &[Polynomial], columns: &[Monomial]) -> Vec<Vec<Rational>> {
+    let mut rows: Vec<Vec<Rational>> = polynomials
+        .iter()
+        .map(|p| {
+            columns
+                .iter()
+                .map(|m| Rational::from(p.coefficient(m)))
+                .collect()
+        })
+        .collect();
+
+    let mut rank = 0;
+    for col in 0..columns.len() {
+        let pivot = match (rank..rows.len()).find(|&i| rows[i][col] != 0) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        rows.swap(rank, pivot);
+
+        let pivot_value = rows[rank][col].clone();
+        for entry in rows[rank].iter_mut() {
+            *entry /= &pivot_value;
+        }
+
+        let pivot_row = rows[rank].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != rank && row[col] != 0 {
+                let factor = row[col].clone();
+                for (entry, pivot_entry) in row.iter_mut().zip(&pivot_row) {
+                    *entry -= Rational::from(pivot_entry * &factor);
+                }
+            }
+        }
+        rank += 1;
+    }
+
+    rows.truncate(rank);
+    rows
+}
+
+/// Common integer roots `x` of `polynomials` with `|x_i| <= bounds[i]`
+///
+/// Returns an empty list if the polynomials do not carry enough information to recover the roots,
+/// e.g. if no univariate polynomial can be obtained by linear combinations.
+pub(crate) fn common_roots(polynomials: &[Polynomial], bounds: &[Integer]) -> Vec<Vec<Integer>> {
+    let n = bounds.len();
+    let polynomials: Vec<Polynomial> = polynomials
+        .iter()
+        .filter(|p| !p.is_zero())
+        .cloned()
+        .collect();
+
+    if n == 0 {
+        // Non-zero constants do not vanish
+        return if polynomials.is_empty() {
+            vec![vec![]]
+        } else {
+            vec![]
+        };
+    }
+    if polynomials.is_empty() {
+        return vec![];
+    }
+
+    // Monomials involving other variables than the last one come first
+    let is_pure = |m: &Monomial| m[..n - 1].iter().all(|&e| e == 0);
+    let mut columns: Vec<Monomial> = polynomials
+        .iter()
+        .flat_map(|p| p.terms().map(|(m, _)| m.clone()).collect::<Vec<_>>())
+        .collect();
+    columns.sort_by(|a, b| is_pure(a).cmp(&is_pure(b)).then_with(|| monomial_cmp(b, a)));
+    columns.dedup();
+
+    // The rows with a pivot in the last block are univariate in the last variable
+    let univariates: Vec<Vec<Integer>> = row_echelon(&polynomials, &columns)
+        .into_iter()
+        .filter(|row| {
+            row.iter()
+                .zip(&columns)
+                .all(|(entry, m)| *entry == 0 || is_pure(m))
+        })
+        .map(|row| {
+            let degree = columns.iter().map(|m| m[n - 1]).max().unwrap_or(0) as usize;
+            let mut coefficients = vec![Rational::new(); degree + 1];
+            for (entry, m) in row.into_iter().zip(&columns).filter(|(_, m)| is_pure(m)) {
+                coefficients[m[n - 1] as usize] = entry;
+            }
+            let denominator = coefficients
+                .iter()
+                .fold(Integer::from(1), |acc, c| acc.lcm(c.denom()));
+            coefficients
+                .into_iter()
+                .map(|c| (c * &denominator).numer().clone())
+                .collect()
+        })
+        .collect();
+
+    let first = match univariates.first() {
+        Some(first) => first,
+        None => return vec![],
+    };
+    if first.iter().skip(1).all(|c| *c == 0) {
+        // A non-zero constant: the system has no solution
+        return vec![];
+    }
+
+    let mut roots = vec![];
+    for value in integer_roots(first, &bounds[n - 1]) {
+        if univariates.iter().any(|u| evaluate(u, &value) != 0) {
+            continue;
+        }
+        let substituted: Vec<Polynomial> = polynomials
+            .iter()
+            .map(|p| p.substitute(n - 1, &value))
+            .collect();
+        for mut root in common_roots(&substituted, &bounds[..n - 1]) {
+            root.push(value.clone());
+            roots.push(root);
+        }
+    }
+    roots
+}
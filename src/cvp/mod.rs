@@ -0,0 +1,311 @@
+//! Approximate closest vector problem (CVP)
+//!
+//! All the functions of this module work on a [`Preprocessed`] lattice, which bundles the
+//! data they need (a reduced basis, its Gram-Schmidt orthogonalisation and its dual basis)
+//! so that it is computed once and reused across many targets.
+
+use crate::{
+    algebra::{Float, FromExt, Gso, Matrix, Scalar, Vector},
+    l2,
+};
+
+#[cfg(feature = "rug")]
+use crate::algebra::BigNum;
+#[cfg(feature = "num")]
+use crate::algebra::NumBigInt;
+
+use rand_core::RngCore;
+
+/// A lattice prepared for closest vector queries
+pub struct Preprocessed<S: Scalar> {
+    /// L²-reduced basis, without zero vectors
+    basis: Matrix<S::Integer>,
+
+    /// Gram-Schmidt orthogonalisation of `basis`
+    gso: Gso<S>,
+
+    /// Dual basis: `<dual[i], basis[j]> = 1` if `i == j`, 0 otherwise
+    dual: Matrix<S::Fraction>,
+}
+
+impl<S: Scalar> Preprocessed<S> {
+    /// Reduce `basis` and compute the data needed by the CVP algorithms
+    ///
+    /// # Panics
+    /// if the parameters `eta` and `delta` are invalid (see [`l2`])
+    fn new(basis: &Matrix<S::Integer>, eta: f64, delta: f64) -> Self {
+        let (d, _) = basis.dimensions();
+        let mut basis = basis.clone();
+        l2::reduction::<S>(&mut basis, eta, delta);
+
+        // Zero vectors are put first by the reduction
+        let basis = Matrix::from_columns(
+            (0..d)
+                .filter(|&i| !basis[i].is_zero())
+                .map(|i| basis[i].clone())
+                .collect(),
+        );
+
+        let gso = Gso::<S>::compute(&basis);
+        let dual = dual_basis::<S>(&basis);
+
+        Self { basis, gso, dual }
+    }
+
+    /// The reduced basis of the lattice
+    pub fn basis(&self) -> &Matrix<S::Integer> {
+        &self.basis
+    }
+
+    /// The dual basis of the lattice, in the same order as the reduced basis
+    pub fn dual(&self) -> &Matrix<S::Fraction> {
+        &self.dual
+    }
+
+    /// Rank of the lattice
+    pub fn rank(&self) -> usize {
+        self.basis.dimensions().0
+    }
+
+    /// Lattice vector `sum c_i b_i`
+    fn lattice_vector(&self, coefficients: &[S::Integer]) -> Vector<S::Integer> {
+        let (_, n) = self.basis.dimensions();
+        coefficients
+            .iter()
+            .enumerate()
+            .fold(Vector::init(n), |acc, (i, c)| {
+                acc.add(&self.basis[i].mulf(c.clone()))
+            })
+    }
+
+    /// Coordinates `<t, b*_i> / r_i` of the projections of `target` on the Gram-Schmidt vectors
+    fn gso_coordinates(&self, target: &Vector<S::Integer>) -> Vec<S::Fraction> {
+        let (d, n) = self.basis.dimensions();
+        assert_eq!(n, target.dimension());
+
+        let zero = S::Fraction::from(0);
+
+        // <t, b*_i> = <t, b_i> - sum_{j<i} mu_ij <t, b*_j>
+        let mut products: Vec<S::Fraction> = Vec::with_capacity(d);
+        for i in 0..d {
+            let mut product = S::Fraction::from_ext(&target.dot(&self.basis[i]));
+            for (j, product_j) in products.iter().enumerate() {
+                product -= &(self.gso.mu[i][j].clone() * product_j);
+            }
+            products.push(product);
+        }
+
+        products
+            .into_iter()
+            .enumerate()
+            .map(|(i, product)| {
+                if self.gso.r[i] == zero {
+                    zero.clone()
+                } else {
+                    product / &self.gso.r[i]
+                }
+            })
+            .collect()
+    }
+}
+
+/// Dual basis of a basis with linearly independent vectors
+///
+/// The dual vectors are `d_i = sum_j (G^-1)_ij b_j` where `G` is the Gram matrix of the basis.
+///
+/// # Panics
+/// if the basis vectors are linearly dependent
+fn dual_basis<S: Scalar>(basis: &Matrix<S::Integer>) -> Matrix<S::Fraction> {
+    let (d, n) = basis.dimensions();
+    let zero = S::Fraction::from(0);
+
+    let gram: Matrix<S::Fraction> = basis.gram().map(|x| S::Fraction::from_ext(x));
+    let inverse = gram
+        .gauss_jordan(|x| S::abs(x.clone()), |x| *x == zero)
+        .expect("Basis vectors must be linearly independent");
+
+    let mut dual: Matrix<S::Fraction> = Matrix::init(d, n);
+    for i in 0..d {
+        for j in 0..d {
+            for k in 0..n {
+                let entry = inverse[i][j].clone() * &S::Fraction::from_ext(&basis[j][k]);
+                dual[i][k] = dual[i][k].clone() + &entry;
+            }
+        }
+    }
+    dual
+}
+
+/// Reduce `basis` and prepare it for closest vector queries
+///
+/// This implementation uses `rug::Integer` and `rug::Rational` for the underlying arithmetic operations.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1
+/// if eta <= 1/2 or eta > sqrt(delta)
+#[cfg(feature = "rug")]
+pub fn preprocess_bignum(
+    basis: &Matrix<rug::Integer>,
+    eta: f64,
+    delta: f64,
+) -> Preprocessed<BigNum> {
+    Preprocessed::new(basis, eta, delta)
+}
+
+/// Reduce `basis` and prepare it for closest vector queries
+///
+/// This implementation uses `num_bigint::BigInt` and `num_rational::BigRational` for the underlying arithmetic operations.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1
+/// if eta <= 1/2 or eta > sqrt(delta)
+#[cfg(feature = "num")]
+pub fn preprocess_num_bigint(
+    basis: &Matrix<num_bigint::BigInt>,
+    eta: f64,
+    delta: f64,
+) -> Preprocessed<NumBigInt> {
+    Preprocessed::new(basis, eta, delta)
+}
+
+/// Reduce `basis` and prepare it for closest vector queries
+///
+/// This implementation uses platform double floating-point numbers (IEEE 754)
+/// for the underlying arithmetic operations.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1
+/// if eta <= 1/2 or eta > sqrt(delta)
+pub fn preprocess_float(basis: &Matrix<f64>, eta: f64, delta: f64) -> Preprocessed<Float> {
+    Preprocessed::new(basis, eta, delta)
+}
+
+/// Nearest plane descent, with a custom rounding of the coordinate at each level
+fn descent<S: Scalar, F>(
+    lattice: &Preprocessed<S>,
+    target: &Vector<S::Integer>,
+    mut round: F,
+) -> Vector<S::Integer>
+where
+    F: FnMut(&S::Fraction) -> S::Integer,
+{
+    let d = lattice.rank();
+    let mut coordinates = lattice.gso_coordinates(target);
+    let mut coefficients = vec![S::Integer::from(0); d];
+
+    for i in (0..d).rev() {
+        let c = round(&coordinates[i]);
+        let c_fraction = S::Fraction::from_ext(&c);
+        for (j, coordinate) in coordinates.iter_mut().enumerate().take(i) {
+            *coordinate -= &(lattice.gso.mu[i][j].clone() * &c_fraction);
+        }
+        coefficients[i] = c;
+    }
+
+    lattice.lattice_vector(&coefficients)
+}
+
+/// Babai's nearest plane algorithm
+///
+/// Returns a lattice vector `v` close to `target`. If the target is in the span of the lattice,
+/// `||v - target||` is at most `sqrt(sum ||b*_i||²) / 2`.
+///
+/// # Panics
+/// if `target` does not have the dimension of the lattice vectors
+pub fn nearest_plane<S: Scalar>(
+    lattice: &Preprocessed<S>,
+    target: &Vector<S::Integer>,
+) -> Vector<S::Integer> {
+    descent(lattice, target, S::round)
+}
+
+/// Randomized variant of Babai's nearest plane algorithm
+///
+/// Runs `trials` nearest plane descents where each coordinate `x` is randomly rounded
+/// to `floor(x) + 1` with probability `x - floor(x)` and to `floor(x)` otherwise,
+/// and returns the closest candidate to `target`. The deterministic descent of [`nearest_plane`]
+/// is always one of the candidates, so the result is never worse.
+///
+/// # Panics
+/// if `target` does not have the dimension of the lattice vectors
+pub fn randomized_nearest_plane<S: Scalar, R: RngCore>(
+    lattice: &Preprocessed<S>,
+    target: &Vector<S::Integer>,
+    trials: usize,
+    rng: &mut R,
+) -> Vector<S::Integer> {
+    let one = S::Integer::from(1);
+    let distance = |v: &Vector<S::Integer>| {
+        let difference = v.sub(target);
+        difference.norm_sqr()
+    };
+
+    let mut best = nearest_plane(lattice, target);
+    let mut best_distance = distance(&best);
+
+    for _ in 0..trials {
+        let candidate = descent(lattice, target, |x| {
+            let mut floor = S::round(x);
+            if S::Fraction::from_ext(&floor) > *x {
+                floor -= &one;
+            }
+
+            // Uniform sample in [0, 1) with 53 bits of precision
+            let uniform = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+            if S::Fraction::from_ext(uniform) < x.clone() - &S::Fraction::from_ext(&floor) {
+                floor + &one
+            } else {
+                floor
+            }
+        });
+
+        let candidate_distance = distance(&candidate);
+        if candidate_distance < best_distance {
+            best = candidate;
+            best_distance = candidate_distance;
+        }
+    }
+
+    best
+}
+
+/// Babai's rounding algorithm
+///
+/// Returns the lattice vector whose coordinates are the rounded coordinates of `target`
+/// in the reduced basis. It is faster but generally less accurate than [`nearest_plane`].
+///
+/// # Panics
+/// if `target` does not have the dimension of the lattice vectors
+pub fn rounding<S: Scalar>(
+    lattice: &Preprocessed<S>,
+    target: &Vector<S::Integer>,
+) -> Vector<S::Integer> {
+    let (d, n) = lattice.basis.dimensions();
+    assert_eq!(n, target.dimension());
+
+    let coefficients: Vec<S::Integer> = (0..d)
+        .map(|i| {
+            let coordinate = (0..n)
+                .map(|k| lattice.dual[i][k].clone() * &S::Fraction::from_ext(&target[k]))
+                .sum();
+            S::round(&coordinate)
+        })
+        .collect();
+
+    lattice.lattice_vector(&coefficients)
+}
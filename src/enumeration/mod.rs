@@ -0,0 +1,242 @@
+//! Enumeration of lattice points in a ball (Fincke-Pohst)
+//!
+//! The enumeration walks the tree of coefficient vectors level by level using the
+//! Gram-Schmidt orthogonalisation of the basis, pruning every branch whose partial
+//! norm already exceeds the bound. Its running time is exponential in the dimension
+//! and highly dependent on the quality of the basis: reduce the basis first.
+
+use crate::algebra::{Float, FromExt, Gso, Matrix, Scalar, Vector};
+
+#[cfg(feature = "rug")]
+use crate::algebra::BigNum;
+#[cfg(feature = "num")]
+use crate::algebra::NumBigInt;
+
+/// Enumeration state shared across the levels of the search tree
+struct Enumerator<'a, S: Scalar> {
+    basis: &'a Matrix<S::Integer>,
+    gso: Gso<S>,
+    bound: S::Fraction,
+    coefficients: Vec<S::Integer>,
+}
+
+impl<'a, S: Scalar> Enumerator<'a, S> {
+    /// Enumerate all the coefficients of level `k` compatible with the coefficients of the
+    /// upper levels, given the squared norm `partial` of their projection.
+    fn enumerate_level<F>(&mut self, k: usize, partial: &S::Fraction, callback: &mut F)
+    where
+        F: FnMut(&[S::Integer], &Vector<S::Integer>),
+    {
+        let (d, _) = self.basis.dimensions();
+
+        // Center of the projection of the partial vector on b*_k
+        let mut center = S::Fraction::from(0);
+        for j in (k + 1)..d {
+            center -= &(self.gso.mu[j][k].clone() * &S::Fraction::from_ext(&self.coefficients[j]));
+        }
+
+        // Walk away from the center in both directions, the contribution grows monotonically
+        let start = S::round(&center);
+        for &step in &[1, -1] {
+            let step = S::Integer::from(step);
+            let mut x = if step > S::Integer::from(0) {
+                start.clone()
+            } else {
+                start.clone() + &step
+            };
+
+            loop {
+                let offset = S::Fraction::from_ext(&x) - &center;
+                let norm = offset.clone() * &offset * &self.gso.r[k] + partial;
+                if norm > self.bound {
+                    break;
+                }
+
+                self.coefficients[k] = x.clone();
+                if k == 0 {
+                    let vector = self.lattice_vector();
+                    callback(&self.coefficients, &vector);
+                } else {
+                    self.enumerate_level(k - 1, &norm, callback);
+                }
+
+                x = x + &step;
+            }
+        }
+        self.coefficients[k] = S::Integer::from(0);
+    }
+
+    /// Lattice vector with the current coefficients
+    fn lattice_vector(&self) -> Vector<S::Integer> {
+        let (d, n) = self.basis.dimensions();
+        (0..d).fold(Vector::init(n), |acc, i| {
+            acc.add(&self.basis[i].mulf(self.coefficients[i].clone()))
+        })
+    }
+}
+
+/// Enumerate the lattice points of squared norm at most `bound` using generic Scalars
+///
+/// # Panics
+/// if the non-zero basis vectors are linearly dependent
+fn enumerate<S: Scalar, F>(basis: &Matrix<S::Integer>, bound: &S::Integer, mut callback: F)
+where
+    F: FnMut(&[S::Integer], &Vector<S::Integer>),
+{
+    let (d, _) = basis.dimensions();
+
+    // Zero vectors do not contribute to the lattice
+    let basis = Matrix::from_columns(
+        (0..d)
+            .filter(|&i| !basis[i].is_zero())
+            .map(|i| basis[i].clone())
+            .collect(),
+    );
+    let (d, _) = basis.dimensions();
+
+    let gso = Gso::<S>::compute(&basis);
+    let zero = S::Fraction::from(0);
+    assert!(
+        (0..d).all(|i| gso.r[i] > zero),
+        "Enumeration requires linearly independent basis vectors"
+    );
+
+    if d == 0 {
+        return;
+    }
+
+    let mut enumerator = Enumerator {
+        basis: &basis,
+        gso,
+        bound: S::Fraction::from_ext(bound),
+        coefficients: vec![S::Integer::from(0); d],
+    };
+    enumerator.enumerate_level(d - 1, &zero, &mut callback);
+}
+
+/// Count the lattice points of each squared norm at most `bound` using generic Scalars
+fn theta_series<S: Scalar>(
+    basis: &Matrix<S::Integer>,
+    bound: &S::Integer,
+) -> Vec<(S::Integer, usize)> {
+    let mut norms = vec![];
+    enumerate::<S, _>(basis, bound, |_, v| norms.push(v.dot(v)));
+    norms.sort_by(|a, b| a.partial_cmp(b).expect("Squared norms are comparable"));
+
+    let mut series: Vec<(S::Integer, usize)> = vec![];
+    for norm in norms {
+        match series.last_mut() {
+            Some((last, count)) if *last == norm => *count += 1,
+            _ => series.push((norm, 1)),
+        }
+    }
+    series
+}
+
+/// Enumerate all the lattice points of squared norm at most `bound`
+///
+/// The `callback` is called once per lattice point `v = sum x_i b_i` with the coefficients
+/// `x_i` and the vector `v` itself. The zero vector is included, and so is `-v` for every `v`.
+/// The coefficients are given with respect to the non-zero vectors of `basis`.
+///
+/// This implementation uses `rug::Integer` and `rug::Rational` for the underlying arithmetic operations.
+///
+/// # Panics
+/// if the non-zero basis vectors are linearly dependent
+#[cfg(feature = "rug")]
+pub fn enumerate_bignum<F>(basis: &Matrix<rug::Integer>, bound: &rug::Integer, callback: F)
+where
+    F: FnMut(&[rug::Integer], &Vector<rug::Integer>),
+{
+    enumerate::<BigNum, F>(basis, bound, callback)
+}
+
+/// Enumerate all the lattice points of squared norm at most `bound`
+///
+/// The `callback` is called once per lattice point `v = sum x_i b_i` with the coefficients
+/// `x_i` and the vector `v` itself. The zero vector is included, and so is `-v` for every `v`.
+/// The coefficients are given with respect to the non-zero vectors of `basis`.
+///
+/// This implementation uses `num_bigint::BigInt` and `num_rational::BigRational` for the underlying arithmetic operations.
+///
+/// # Panics
+/// if the non-zero basis vectors are linearly dependent
+#[cfg(feature = "num")]
+pub fn enumerate_num_bigint<F>(
+    basis: &Matrix<num_bigint::BigInt>,
+    bound: &num_bigint::BigInt,
+    callback: F,
+) where
+    F: FnMut(&[num_bigint::BigInt], &Vector<num_bigint::BigInt>),
+{
+    enumerate::<NumBigInt, F>(basis, bound, callback)
+}
+
+/// Enumerate all the lattice points of squared norm at most `bound`
+///
+/// The `callback` is called once per lattice point `v = sum x_i b_i` with the coefficients
+/// `x_i` and the vector `v` itself. The zero vector is included, and so is `-v` for every `v`.
+/// The coefficients are given with respect to the non-zero vectors of `basis`.
+///
+/// This implementation uses platform double floating-point numbers (IEEE 754)
+/// for the underlying arithmetic operations. Points lying (numerically) on the boundary may be missed.
+///
+/// # Panics
+/// if the non-zero basis vectors are linearly dependent
+pub fn enumerate_float<F>(basis: &Matrix<f64>, bound: f64, callback: F)
+where
+    F: FnMut(&[f64], &Vector<f64>),
+{
+    enumerate::<Float, F>(basis, &bound, callback)
+}
+
+/// Number of lattice points of each squared norm up to `bound`
+///
+/// Returns the pairs `(squared norm, number of points)` in increasing order of norm, for the norms
+/// that are actually reached. These are the first coefficients of the theta series of the lattice
+/// (the zero vector is counted).
+///
+/// This implementation uses `rug::Integer` and `rug::Rational` for the underlying arithmetic operations.
+///
+/// # Panics
+/// if the non-zero basis vectors are linearly dependent
+#[cfg(feature = "rug")]
+pub fn theta_series_bignum(
+    basis: &Matrix<rug::Integer>,
+    bound: &rug::Integer,
+) -> Vec<(rug::Integer, usize)> {
+    theta_series::<BigNum>(basis, bound)
+}
+
+/// Number of lattice points of each squared norm up to `bound`
+///
+/// Returns the pairs `(squared norm, number of points)` in increasing order of norm, for the norms
+/// that are actually reached. These are the first coefficients of the theta series of the lattice
+/// (the zero vector is counted).
+///
+/// This implementation uses `num_bigint::BigInt` and `num_rational::BigRational` for the underlying arithmetic operations.
+///
+/// # Panics
+/// if the non-zero basis vectors are linearly dependent
+#[cfg(feature = "num")]
+pub fn theta_series_num_bigint(
+    basis: &Matrix<num_bigint::BigInt>,
+    bound: &num_bigint::BigInt,
+) -> Vec<(num_bigint::BigInt, usize)> {
+    theta_series::<NumBigInt>(basis, bound)
+}
+
+/// Number of lattice points of each squared norm up to `bound`
+///
+/// Returns the pairs `(squared norm, number of points)` in increasing order of norm, for the norms
+/// that are actually reached. These are the first coefficients of the theta series of the lattice
+/// (the zero vector is counted).
+///
+/// This implementation uses platform double floating-point numbers (IEEE 754)
+/// for the underlying arithmetic operations. Points lying (numerically) on the boundary may be missed.
+///
+/// # Panics
+/// if the non-zero basis vectors are linearly dependent
+pub fn theta_series_float(basis: &Matrix<f64>, bound: f64) -> Vec<(f64, usize)> {
+    theta_series::<Float>(basis, &bound)
+}
@@ -0,0 +1,153 @@
+//! Cost estimates of lattice attacks
+//!
+//! These helpers predict the BKZ block size needed to solve a problem from the dimension and the
+//! volume of the lattice only, so that the feasibility of an attack can be assessed before running
+//! it. They rely on the usual heuristics:
+//!
+//! - the Geometric Series Assumption (GSA): the Gram-Schmidt norms of a BKZ-`β` reduced basis
+//!   decrease geometrically, `log ||b*_i|| = log(vol(L)) / d + (d - 1 - 2i) log(δ_β)`, where
+//!   `δ_β` is the root Hermite factor of BKZ-`β` [Che13];
+//! - the success condition of [ADPS16] for unique-SVP: the projection of the short vector on the
+//!   last `β` Gram-Schmidt vectors, of norm about `sqrt(β / d)` times its norm, must be shorter
+//!   than `||b*_(d-β)||`;
+//! - the core-SVP cost model: one call to an SVP oracle in dimension `β`, costing `2^(0.292 β)`
+//!   classically and `2^(0.265 β)` quantumly with sieving.
+//!
+//! The estimates are rough: they are meant for large dimensions and ignore the number of BKZ tours.
+//! All the logarithms are in base 2, as in [`BasisQuality`](crate::quality::BasisQuality).
+//!
+//! [Che13]: Y. Chen. Réduction de réseau et sécurité concrète du chiffrement complètement homomorphe.
+//! PhD thesis, 2013.
+//!
+//! [ADPS16]: E. Alkim, L. Ducas, T. Pöppelmann and P. Schwabe. Post-quantum key exchange - a new hope.
+//! USENIX Security 2016.
+
+use std::f64::consts::{E, PI};
+
+/// Experimental root Hermite factors of BKZ for small block sizes
+const SMALL_BLOCK_SIZES: [(usize, f64); 8] = [
+    (2, 1.02190),
+    (5, 1.01862),
+    (10, 1.01616),
+    (15, 1.01485),
+    (20, 1.01420),
+    (25, 1.01342),
+    (28, 1.01331),
+    (40, 1.01295),
+];
+
+/// Root Hermite factor `δ_β` of BKZ with block size `block_size`
+///
+/// Uses the asymptotic formula `δ_β = (β / (2πe) (πβ)^(1/β))^(1 / (2(β - 1)))` for `β > 40`,
+/// and interpolates experimental values for smaller block sizes (`β = 2` is LLL).
+///
+/// # Panics
+/// if `block_size < 2`
+pub fn root_hermite_factor(block_size: usize) -> f64 {
+    assert!(block_size >= 2);
+    if block_size > 40 {
+        let beta = block_size as f64;
+        return (beta / (2. * PI * E) * (PI * beta).powf(1. / beta)).powf(1. / (2. * (beta - 1.)));
+    }
+    let k = SMALL_BLOCK_SIZES
+        .iter()
+        .position(|&(beta, _)| beta >= block_size)
+        .expect("The block size is at most 40");
+    let (beta_1, delta_1) = SMALL_BLOCK_SIZES[k];
+    if k == 0 || beta_1 == block_size {
+        return delta_1;
+    }
+    let (beta_0, delta_0) = SMALL_BLOCK_SIZES[k - 1];
+    let t = (block_size - beta_0) as f64 / (beta_1 - beta_0) as f64;
+    delta_0 + t * (delta_1 - delta_0)
+}
+
+/// Logarithms of the Gram-Schmidt norms `||b*_i||` of a BKZ-reduced basis under the GSA
+///
+/// # Panics
+/// if `block_size < 2`
+pub fn gsa_profile(dimension: usize, log_volume: f64, block_size: usize) -> Vec<f64> {
+    let log_delta = root_hermite_factor(block_size).log2();
+    let d = dimension as f64;
+    (0..dimension)
+        .map(|i| log_volume / d + (d - 1. - 2. * i as f64) * log_delta)
+        .collect()
+}
+
+/// Logarithm of the Gaussian heuristic `sqrt(d / (2πe)) vol(L)^(1/d)`, the expected `λ_1` of a random lattice
+///
+/// # Panics
+/// if `dimension == 0`
+pub fn gaussian_heuristic(dimension: usize, log_volume: f64) -> f64 {
+    assert!(dimension > 0);
+    let d = dimension as f64;
+    (d / (2. * PI * E)).log2() / 2. + log_volume / d
+}
+
+/// Block size and core-SVP cost of an attack
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate {
+    /// The BKZ block size `β`
+    pub block_size: usize,
+
+    /// The root Hermite factor `δ_β`
+    pub root_hermite_factor: f64,
+
+    /// Logarithm of the classical core-SVP cost, `0.292 β`
+    pub classical_cost: f64,
+
+    /// Logarithm of the quantum core-SVP cost, `0.265 β`
+    pub quantum_cost: f64,
+}
+
+impl Estimate {
+    /// The estimate for the block size `block_size`
+    ///
+    /// # Panics
+    /// if `block_size < 2`
+    pub fn new(block_size: usize) -> Self {
+        let beta = block_size as f64;
+        Self {
+            block_size,
+            root_hermite_factor: root_hermite_factor(block_size),
+            classical_cost: 0.292 * beta,
+            quantum_cost: 0.265 * beta,
+        }
+    }
+}
+
+/// Smallest block size for which BKZ finds a unique shortest vector of norm `target_norm`
+///
+/// Returns the smallest `β <= dimension` satisfying the condition of [ADPS16]
+/// `sqrt(β / d) target_norm <= ||b*_(d-β)||` under the GSA, with its cost, or `None` if there is none.
+///
+/// # Panics
+/// if `dimension < 2` or `target_norm` is not positive
+pub fn usvp(dimension: usize, log_volume: f64, target_norm: f64) -> Option<Estimate> {
+    assert!(dimension >= 2);
+    assert!(target_norm > 0.);
+    let log_target = target_norm.log2();
+    (2..=dimension)
+        .find(|&beta| {
+            let profile = gsa_profile(dimension, log_volume, beta);
+            let projected = log_target + (beta as f64 / dimension as f64).log2() / 2.;
+            projected <= profile[dimension - beta]
+        })
+        .map(Estimate::new)
+}
+
+/// Smallest block size for which the first vector of a BKZ-reduced basis has norm at most `target_norm`
+///
+/// Returns the smallest `β <= dimension` with `||b_1|| = δ_β^(d-1) vol(L)^(1/d) <= target_norm` under
+/// the GSA, with its cost, or `None` if there is none (e.g. when the target is below the Gaussian heuristic).
+///
+/// # Panics
+/// if `dimension < 2` or `target_norm` is not positive
+pub fn approx_svp(dimension: usize, log_volume: f64, target_norm: f64) -> Option<Estimate> {
+    assert!(dimension >= 2);
+    assert!(target_norm > 0.);
+    let log_target = target_norm.log2();
+    (2..=dimension)
+        .find(|&beta| gsa_profile(dimension, log_volume, beta)[0] <= log_target)
+        .map(Estimate::new)
+}
@@ -0,0 +1,313 @@
+//! Generators of lattice instances with known solutions
+//!
+//! The generators take the randomness source as an argument so that instances can be reproduced
+//! from a seeded generator.
+
+use crate::{
+    algebra::{Matrix, Vector},
+    hssp,
+    knapsack::SubsetSum,
+};
+
+use rand_core::RngCore;
+use rug::{integer::Order, ops::RemRounding, Integer};
+
+/// Uniform sample in `[0, bound)`
+pub(crate) fn uniform_usize<R: RngCore>(bound: usize, rng: &mut R) -> usize {
+    let bound = bound as u64;
+    let zone = u64::MAX - u64::MAX % bound;
+    loop {
+        let sample = rng.next_u64();
+        if sample < zone {
+            return (sample % bound) as usize;
+        }
+    }
+}
+
+/// Uniformly random permutation of `0..n`, e.g. to re-randomize a block of a basis with
+/// [`Matrix::permute_rows`] or by reordering its vectors
+pub fn permutation<R: RngCore>(n: usize, rng: &mut R) -> Vec<usize> {
+    let mut permutation: Vec<usize> = (0..n).collect();
+    for i in (1..n).rev() {
+        let j = uniform_usize(i + 1, rng);
+        permutation.swap(i, j);
+    }
+    permutation
+}
+
+/// Uniform sample of `bits` random bits
+pub(crate) fn random_bits<R: RngCore>(bits: u32, rng: &mut R) -> Integer {
+    let words: Vec<u64> = (0..bits.div_ceil(64)).map(|_| rng.next_u64()).collect();
+    let mut n = Integer::from_digits(&words, Order::Lsf);
+    n.keep_bits_mut(bits);
+    n
+}
+
+/// Uniform sample in `[0, bound)`
+pub(crate) fn random_below<R: RngCore>(bound: &Integer, rng: &mut R) -> Integer {
+    assert!(*bound > 0);
+    let bits = bound.significant_bits();
+    loop {
+        let n = random_bits(bits, rng);
+        if n < *bound {
+            return n;
+        }
+    }
+}
+
+/// Unique-SVP instance with a planted short vector
+///
+/// Returns a basis of dimension `dimension` of the lattice
+/// `L = { x : x_{n-1} = sum_{i<n-1} c_i x_i mod q }`, where `q` is a random `modulus_bits`-bit integer,
+/// together with the planted vector `s` of `L`. The secret `s` has exactly `weight` non-zero entries,
+/// all equal to ±1, so that `||s|| = sqrt(weight)`.
+///
+/// The determinant of the lattice is `q`, so that the secret is expected to be the unique shortest vector
+/// (up to sign) as long as `sqrt(weight)` is significantly smaller than `sqrt(dimension / (2πe)) * q^(1/dimension)`.
+///
+/// # Panics
+/// if `dimension < 2`, `weight == 0`, `weight > dimension` or `modulus_bits < 2`
+pub fn usvp_bignum<R: RngCore>(
+    dimension: usize,
+    modulus_bits: u32,
+    weight: usize,
+    rng: &mut R,
+) -> (Matrix<Integer>, Vector<Integer>) {
+    assert!(dimension >= 2);
+    assert!(0 < weight && weight <= dimension);
+    assert!(modulus_bits >= 2);
+
+    let n = dimension;
+    let mut q = random_bits(modulus_bits, rng);
+    q.set_bit(modulus_bits - 1, true);
+
+    // Support of the secret, with at least one coordinate before the last one
+    let mut positions: Vec<usize> = (0..n).collect();
+    let support = loop {
+        for i in 0..weight {
+            let j = i + uniform_usize(n - i, rng);
+            positions.swap(i, j);
+        }
+        let support = &positions[..weight];
+        if support.iter().any(|&i| i < n - 1) {
+            break support.to_vec();
+        }
+    };
+
+    let mut secret: Vector<Integer> = Vector::init(n);
+    for &i in &support {
+        secret[i] = if rng.next_u32() & 1 == 0 {
+            Integer::from(1)
+        } else {
+            Integer::from(-1)
+        };
+    }
+
+    // Pick the c_i at random, except the one of the pivot which makes s a lattice vector
+    let pivot = *support.iter().filter(|&&i| i < n - 1).min().unwrap();
+    let mut c: Vec<Integer> = (0..n - 1).map(|_| random_below(&q, rng)).collect();
+    let mut residue = secret[n - 1].clone();
+    for i in (0..n - 1).filter(|&i| i != pivot) {
+        residue -= &secret[i] * &c[i];
+    }
+    // s_pivot = ±1 is its own inverse
+    residue *= &secret[pivot];
+    c[pivot] = residue.rem_euc(&q);
+
+    let mut basis: Matrix<Integer> = Matrix::init(n, n);
+    for i in 0..n - 1 {
+        basis[i][i] = Integer::from(1);
+        basis[i][n - 1] = c[i].clone();
+    }
+    basis[n - 1][n - 1] = q;
+
+    (basis, secret)
+}
+
+/// Subset-sum instance with a planted solution
+///
+/// Returns an instance with `n` random weights of exactly `bits` bits, and a random solution
+/// `x` with exactly `weight` non-zero entries. The density of the instance is about `n / bits`;
+/// the corresponding lattices are given by [`knapsack::lattice`](crate::knapsack::lattice).
+///
+/// # Panics
+/// if `n == 0`, `weight > n` or `bits == 0`
+pub fn knapsack_bignum<R: RngCore>(
+    n: usize,
+    bits: u32,
+    weight: usize,
+    rng: &mut R,
+) -> (SubsetSum, Vec<bool>) {
+    assert!(n > 0 && weight <= n);
+    assert!(bits > 0);
+
+    let weights: Vec<Integer> = (0..n)
+        .map(|_| {
+            let mut a = random_bits(bits, rng);
+            a.set_bit(bits - 1, true);
+            a
+        })
+        .collect();
+
+    let mut positions: Vec<usize> = (0..n).collect();
+    let mut solution = vec![false; n];
+    for i in 0..weight {
+        let j = i + uniform_usize(n - i, rng);
+        positions.swap(i, j);
+        solution[positions[i]] = true;
+    }
+
+    let target = weights
+        .iter()
+        .zip(&solution)
+        .filter(|(_, &x_i)| x_i)
+        .map(|(a, _)| a)
+        .sum();
+    (SubsetSum::new(weights, target), solution)
+}
+
+/// Subset-sum instance of density about `density` with a planted solution
+///
+/// The weights have `ceil(n / density)` bits, see [`knapsack_bignum`].
+///
+/// # Panics
+/// if `n == 0`, `weight > n` or `density` is not positive
+pub fn knapsack_with_density_bignum<R: RngCore>(
+    n: usize,
+    density: f64,
+    weight: usize,
+    rng: &mut R,
+) -> (SubsetSum, Vec<bool>) {
+    assert!(density > 0.);
+    let bits = (n as f64 / density).ceil() as u32;
+    knapsack_bignum(n, bits, weight, rng)
+}
+
+/// Hidden subset sum instance with planted hidden weights and vectors
+///
+/// Returns an instance `h = sum α_i x_i mod q` with `n` uniform weights `α_i` modulo `q` and `n`
+/// uniform binary vectors `x_i` of dimension `m`, together with the hidden vectors and weights.
+///
+/// # Panics
+/// if `n == 0`, `m < n` or `q < 2`
+pub fn hidden_subset_sum_bignum<R: RngCore>(
+    n: usize,
+    m: usize,
+    q: &Integer,
+    rng: &mut R,
+) -> (hssp::Instance, Matrix<Integer>, Vec<Integer>) {
+    assert!(n > 0 && m >= n);
+    assert!(*q >= 2);
+
+    let weights: Vec<Integer> = (0..n).map(|_| random_below(q, rng)).collect();
+    let mut vectors: Matrix<Integer> = Matrix::init(n, m);
+    for i in 0..n {
+        for j in 0..m {
+            vectors[i][j] = Integer::from(rng.next_u32() & 1);
+        }
+    }
+    let samples: Vec<Integer> = (0..m)
+        .map(|j| {
+            let sum: Integer = (0..n)
+                .map(|i| Integer::from(&vectors[i][j] * &weights[i]))
+                .sum();
+            sum.rem_euc(q)
+        })
+        .collect();
+    (hssp::Instance::new(samples, q.clone(), n), vectors, weights)
+}
+
+/// The matrix `H = A_1^-1 A_2 mod q`, where `A = [A_1 | A_2]` has `n` rows and the columns of `a`
+///
+/// Returns `None` if the first `n` columns `A_1` are not invertible modulo `q`.
+fn systematic_form(a: &Matrix<Integer>, q: &Integer) -> Option<Vec<Vec<Integer>>> {
+    let (m, n) = a.dimensions();
+
+    // Gauss-Jordan elimination modulo q on the rows of A
+    let mut rows: Vec<Vec<Integer>> = (0..n)
+        .map(|i| (0..m).map(|j| a[j][i].clone().rem_euc(q)).collect())
+        .collect();
+    for col in 0..n {
+        let pivot = (col..n).find(|&i| rows[i][col].clone().gcd(q) == 1)?;
+        rows.swap(col, pivot);
+        let inverse = rows[col][col].clone().invert(q).ok()?;
+        for entry in rows[col].iter_mut() {
+            *entry = Integer::from(&*entry * &inverse).rem_euc(q);
+        }
+        let pivot_row = rows[col].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != col && row[col] != 0 {
+                let factor = row[col].clone();
+                for (entry, pivot_entry) in row.iter_mut().zip(&pivot_row) {
+                    *entry = Integer::from(&*entry - &factor * pivot_entry).rem_euc(q);
+                }
+            }
+        }
+    }
+    Some(rows.into_iter().map(|row| row[n..].to_vec()).collect())
+}
+
+/// Bases of the q-ary lattices of `A`, whose columns are the vectors of `a`
+///
+/// With `A` of size `n x m`, returns bases of the `m`-dimensional lattices
+/// `Λ_q(A) = { A^T s mod q }` (of determinant `q^(m-n)`) and
+/// `Λ_q^⊥(A) = { y : A y = 0 mod q }` (of determinant `q^n`), which is `q` times the dual of `Λ_q(A)`.
+/// Returns `None` if the first `n` columns of `A` are not invertible modulo `q`.
+///
+/// # Panics
+/// if `m < n` or `q < 2`
+pub fn q_ary_bases(a: &Matrix<Integer>, q: &Integer) -> Option<(Matrix<Integer>, Matrix<Integer>)> {
+    let (m, n) = a.dimensions();
+    assert!(m >= n);
+    assert!(*q >= 2);
+    let h = systematic_form(a, q)?;
+
+    // Λ_q(A): (e_i, H^T e_i) and (0, q e_j)
+    let mut primal: Matrix<Integer> = Matrix::init(m, m);
+    // Λ_q^⊥(A): (q e_i, 0) and (-H e_j mod q, e_j)
+    let mut dual: Matrix<Integer> = Matrix::init(m, m);
+    for i in 0..n {
+        primal[i][i] = Integer::from(1);
+        for j in 0..m - n {
+            primal[i][n + j] = h[i][j].clone();
+        }
+        dual[i][i] = q.clone();
+    }
+    for j in 0..m - n {
+        primal[n + j][n + j] = q.clone();
+        for i in 0..n {
+            dual[n + j][i] = Integer::from(-&h[i][j]).rem_euc(q);
+        }
+        dual[n + j][n + j] = Integer::from(1);
+    }
+    Some((primal, dual))
+}
+
+/// Random q-ary lattices
+///
+/// Samples a uniform matrix `A` of size `n x m` modulo `q` (as `m` columns of dimension `n`), with
+/// its first `n` columns invertible modulo `q`, and returns it with the bases of `Λ_q(A)` and
+/// `Λ_q^⊥(A)` (see [`q_ary_bases`]).
+///
+/// # Panics
+/// if `n == 0`, `m < n` or `q < 2`
+pub fn q_ary_bignum<R: RngCore>(
+    n: usize,
+    m: usize,
+    q: &Integer,
+    rng: &mut R,
+) -> (Matrix<Integer>, Matrix<Integer>, Matrix<Integer>) {
+    assert!(n > 0 && m >= n);
+    assert!(*q >= 2);
+    loop {
+        let mut a: Matrix<Integer> = Matrix::init(m, n);
+        for j in 0..m {
+            for i in 0..n {
+                a[j][i] = random_below(q, rng);
+            }
+        }
+        if let Some((primal, dual)) = q_ary_bases(&a, q) {
+            return (a, primal, dual);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Embedding attack on GGH-like encryption
+//!
+//! In the Goldreich-Goldwasser-Halevi cryptosystem, a message `m` is encrypted as
+//! `c = sum m_i b_i + e` for the public basis `(b_i)` and a small error `e`. Decryption is a
+//! closest vector problem, which is solved here with Kannan's embedding technique, as in
+//! Nguyen's cryptanalysis [Ngu99]: the vector `(e, M)` is a short vector of the lattice spanned by
+//! the `(b_i, 0)` and `(c, M)`, for an embedding factor `M` of the order of the error entries.
+//!
+//! [Ngu99]: P. Q. Nguyen. Cryptanalysis of the Goldreich-Goldwasser-Halevi Cryptosystem from Crypto '97.
+//! CRYPTO 1999.
+
+use crate::{algebra::Matrix, l2};
+
+use rug::{Integer, Rational};
+
+/// Embedding lattice of the ciphertext `ciphertext` for the public basis `basis`
+///
+/// # Panics
+/// if the dimensions of the basis vectors and the ciphertext differ
+pub fn embedding_lattice(
+    basis: &Matrix<Integer>,
+    ciphertext: &[Integer],
+    embedding_factor: &Integer,
+) -> Matrix<Integer> {
+    let (d, n) = basis.dimensions();
+    assert_eq!(ciphertext.len(), n);
+
+    let mut embedding: Matrix<Integer> = Matrix::init(d + 1, n + 1);
+    for i in 0..d {
+        for j in 0..n {
+            embedding[i][j] = basis[i][j].clone();
+        }
+    }
+    for (j, c_j) in ciphertext.iter().enumerate() {
+        embedding[d][j] = c_j.clone();
+    }
+    embedding[d][n] = embedding_factor.clone();
+    embedding
+}
+
+/// Coordinates `x` of the lattice vector `v = sum x_i b_i`, if `v` belongs to the lattice
+fn coordinates(basis: &Matrix<Integer>, v: &[Integer]) -> Option<Vec<Integer>> {
+    let (d, n) = basis.dimensions();
+
+    // Gauss-Jordan elimination on the rows of [b_0 ... b_{d-1} | v]
+    let mut rows: Vec<Vec<Rational>> = (0..n)
+        .map(|j| {
+            let mut row: Vec<Rational> = (0..d).map(|i| Rational::from(&basis[i][j])).collect();
+            row.push(Rational::from(&v[j]));
+            row
+        })
+        .collect();
+
+    let mut rank = 0;
+    let mut pivots = vec![];
+    for col in 0..d {
+        let pivot = match (rank..n).find(|&i| rows[i][col] != 0) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        rows.swap(rank, pivot);
+        let pivot_value = rows[rank][col].clone();
+        for entry in rows[rank].iter_mut() {
+            *entry /= &pivot_value;
+        }
+        let pivot_row = rows[rank].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != rank && row[col] != 0 {
+                let factor = row[col].clone();
+                for (entry, pivot_entry) in row.iter_mut().zip(&pivot_row) {
+                    *entry -= Rational::from(pivot_entry * &factor);
+                }
+            }
+        }
+        pivots.push(col);
+        rank += 1;
+    }
+
+    // The system must be consistent and have an integer solution
+    if rows[rank..].iter().any(|row| row[d] != 0) {
+        return None;
+    }
+    let mut x = vec![Integer::new(); d];
+    for (row, &col) in rows.iter().zip(&pivots) {
+        if *row[d].denom() != 1 {
+            return None;
+        }
+        x[col] = row[d].numer().clone();
+    }
+    Some(x)
+}
+
+/// Recovers the plaintext and the error of a GGH ciphertext with the embedding attack
+///
+/// Reduces the lattice of [`embedding_lattice`] with L² and looks for a reduced vector of the form
+/// `±(e, M)`. Returns `(m, e)` with `c = sum m_i b_i + e` if such a vector is found and `c - e`
+/// belongs to the lattice, and `None` otherwise (typically when the error is too large for the
+/// reduction to find it).
+///
+/// # Panics
+/// if the dimensions of the basis vectors and the ciphertext differ
+pub fn decrypt(
+    basis: &Matrix<Integer>,
+    ciphertext: &[Integer],
+    embedding_factor: &Integer,
+) -> Option<(Vec<Integer>, Vec<Integer>)> {
+    let (d, n) = basis.dimensions();
+    let mut embedding = embedding_lattice(basis, ciphertext, embedding_factor);
+    l2::lll_bignum(&mut embedding, 0.51, 0.99).expect("The lattice basis is valid");
+
+    (0..=d).find_map(|i| {
+        let v = &embedding[i];
+        let sign = if v[n] == *embedding_factor {
+            1
+        } else if Integer::from(-&v[n]) == *embedding_factor {
+            -1
+        } else {
+            return None;
+        };
+
+        let error: Vec<Integer> = (0..n).map(|j| Integer::from(&v[j] * sign)).collect();
+        let lattice_vector: Vec<Integer> = ciphertext
+            .iter()
+            .zip(&error)
+            .map(|(c_j, e_j)| Integer::from(c_j - e_j))
+            .collect();
+        coordinates(basis, &lattice_vector).map(|message| (message, error))
+    })
+}
@@ -0,0 +1,166 @@
+//! Hidden Number Problem
+//!
+//! Recovers a secret `x` modulo a prime `q` from samples `(t_i, u_i)` such that
+//! `k_i = t_i x + u_i mod q` is small, following Boneh and Venkatesan [BV96].
+//! The typical application is the recovery of ECDSA or DSA private keys from signatures whose
+//! nonces have known (e.g. zero) most significant bits.
+//!
+//! The secret is found as the closest vector (with Babai's nearest plane algorithm) to a target
+//! built from the `u_i` in the lattice spanned by `q e_i` and `(t_1, ..., t_n, B / q)`,
+//! where `B` bounds the `k_i`. With `l` known bits per nonce, about `log2(q) / l` samples are
+//! needed in theory, and a few more in practice.
+//!
+//! [BV96]: D. Boneh and R. Venkatesan. Hardness of Computing the Most Significant Bits of Secret Keys
+//! in Diffie-Hellman and Related Schemes. CRYPTO 1996.
+
+use crate::{
+    algebra::{Matrix, Vector},
+    cvp,
+};
+
+use rug::{ops::RemRounding, Integer};
+
+/// A sample of the Hidden Number Problem: `0 <= t x + u mod q < bound`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub t: Integer,
+    pub u: Integer,
+}
+
+/// An ECDSA (or DSA) signature `(r, s)` of the hash `hash`, with the known most significant bits of its nonce
+///
+/// The nonce is `k = s^-1 (hash + r d) mod q` for the private key `d`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    pub r: Integer,
+    pub s: Integer,
+    pub hash: Integer,
+
+    /// Value of the known most significant bits of the nonce (0 for biased nonces)
+    pub nonce_msb: Integer,
+}
+
+impl Signature {
+    /// A signature whose nonce has leading zero bits
+    pub fn new(r: Integer, s: Integer, hash: Integer) -> Self {
+        Self::with_nonce_msb(r, s, hash, Integer::new())
+    }
+
+    /// A signature whose nonce has known leading bits `nonce_msb`
+    pub fn with_nonce_msb(r: Integer, s: Integer, hash: Integer, nonce_msb: Integer) -> Self {
+        Self {
+            r,
+            s,
+            hash,
+            nonce_msb,
+        }
+    }
+}
+
+/// Hidden Number Problem samples from ECDSA signatures whose nonces have `known_bits` known leading bits
+///
+/// The nonces are seen as `log2(q)`-bit integers, so that the unknown part of each nonce is
+/// bounded by `2^(log2(q) - known_bits)` (see [`nonce_bound`]).
+///
+/// # Panics
+/// if some `s` is not invertible modulo `order`
+pub fn ecdsa_samples(order: &Integer, signatures: &[Signature], known_bits: u32) -> Vec<Sample> {
+    let unknown_bits = order.significant_bits().saturating_sub(known_bits);
+    signatures
+        .iter()
+        .map(|signature| {
+            let inverse =
+                Integer::from(signature.s.invert_ref(order).expect("s must be invertible"));
+            let t = Integer::from(&inverse * &signature.r).rem_euc(order);
+            let u = Integer::from(&inverse * &signature.hash)
+                - Integer::from(&signature.nonce_msb << unknown_bits);
+            Sample {
+                t,
+                u: u.rem_euc(order),
+            }
+        })
+        .collect()
+}
+
+/// Bound on the unknown part of nonces with `known_bits` known leading bits
+pub fn nonce_bound(order: &Integer, known_bits: u32) -> Integer {
+    Integer::from(1) << order.significant_bits().saturating_sub(known_bits)
+}
+
+/// Boneh-Venkatesan lattice and target vector of a Hidden Number Problem instance
+///
+/// Everything is scaled by `q` to obtain an integer lattice: the basis vectors are `q^2 e_i`
+/// and `(q t_1, ..., q t_n, bound)`, and the target is `(q (bound / 2 - u_i), q bound / 2)`.
+/// The lattice vector `x (q t_1, ..., q t_n, bound) mod q^2` is then within
+/// `q bound sqrt(n + 1) / 2` of the target.
+pub fn lattice(
+    order: &Integer,
+    samples: &[Sample],
+    bound: &Integer,
+) -> (Matrix<Integer>, Vector<Integer>) {
+    let n = samples.len();
+    let q_squared = Integer::from(order * order);
+    let half = Integer::from(bound >> 1);
+
+    let mut basis: Matrix<Integer> = Matrix::init(n + 1, n + 1);
+    let mut target: Vector<Integer> = Vector::init(n + 1);
+    for (i, sample) in samples.iter().enumerate() {
+        basis[i][i] = q_squared.clone();
+        basis[n][i] = Integer::from(order * &sample.t);
+        target[i] = Integer::from(&half - &sample.u) * order;
+    }
+    basis[n][n] = bound.clone();
+    target[n] = Integer::from(order * &half);
+
+    (basis, target)
+}
+
+/// Whether `x` is a solution of all the samples
+pub fn is_solution(order: &Integer, samples: &[Sample], bound: &Integer, x: &Integer) -> bool {
+    samples.iter().all(|sample| {
+        let k = Integer::from(&sample.t * x + &sample.u).rem_euc(order);
+        k < *bound
+    })
+}
+
+/// Solves a Hidden Number Problem instance
+///
+/// Reduces the lattice of [`lattice`] with L² and runs Babai's nearest plane algorithm.
+/// Returns a solution `x` in `[0, q)` of all the samples, or `None` if the closest vector found
+/// does not give one. With too few samples, the solution is not unique and need not be the secret.
+pub fn solve(order: &Integer, samples: &[Sample], bound: &Integer) -> Option<Integer> {
+    if samples.is_empty() || *bound <= 0 {
+        return None;
+    }
+    let (basis, target) = lattice(order, samples, bound);
+    let preprocessed = cvp::preprocess_bignum(&basis, 0.51, 0.99);
+    let closest = cvp::nearest_plane(&preprocessed, &target);
+
+    let n = samples.len();
+    let (x, remainder) = closest[n].clone().div_rem_euc(bound.clone());
+    if remainder != 0 {
+        return None;
+    }
+    let x = x.rem_euc(order);
+    if is_solution(order, samples, bound, &x) {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/// Recovers an ECDSA (or DSA) private key from signatures whose nonces have `known_bits` known leading bits
+///
+/// `order` is the order `q` of the base point (or subgroup). The returned key is only a candidate:
+/// it is consistent with all the signatures, and should be checked against the public key.
+///
+/// # Panics
+/// if some `s` is not invertible modulo `order`
+pub fn recover_ecdsa_key(
+    order: &Integer,
+    signatures: &[Signature],
+    known_bits: u32,
+) -> Option<Integer> {
+    let samples = ecdsa_samples(order, signatures, known_bits);
+    solve(order, &samples, &nonce_bound(order, known_bits))
+}
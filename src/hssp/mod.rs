@@ -0,0 +1,210 @@
+//! Hidden subset sum problem
+//!
+//! Given a modulus `q` and samples `h` in `Z_q^m`, the hidden subset sum problem is to find hidden
+//! weights `α_1, ..., α_n` modulo `q` and hidden vectors `x_1, ..., x_n` in `{0, 1}^m` such that
+//! `h = sum α_i x_i mod q`. It arises for instance in the cryptanalysis of generators of random
+//! pairs `(x, g^x)` based on subset sums. Nguyen and Stern's algorithm [NS99] works in two steps:
+//!
+//! - the short vectors of the lattice `{ u : <u, h> = 0 mod q }` are orthogonal to all the `x_i`,
+//!   and the orthogonal lattice of `m - n` of them is the completion `L_x` of the lattice spanned
+//!   by the `x_i`;
+//! - a reduced basis of `L_x` consists of short combinations of the `x_i`, typically with
+//!   coefficients in `{-1, 0, 1}`, from which the `x_i` are recovered, and the weights follow by
+//!   linear algebra modulo `q`.
+//!
+//! Both steps rely on the [`orthogonal`](crate::orthogonal) module. The first step needs a modulus
+//! which is large compared to the dimension, and `m` is typically about `2n`. For larger `n`,
+//! the reduction of `L_x` should be stronger than LLL (see [`solve_with`]).
+//!
+//! [NS99]: P. Q. Nguyen and J. Stern. The Hardness of the Hidden Subset Sum Problem and Its
+//! Cryptographic Implications. CRYPTO 1999.
+
+use crate::{algebra::Matrix, lwe, orthogonal};
+
+use rug::{ops::RemRounding, Integer};
+
+use std::collections::BTreeSet;
+
+/// A hidden subset sum instance `h = sum α_i x_i mod q`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instance {
+    /// The `m` samples `h_j`
+    pub samples: Vec<Integer>,
+
+    /// The modulus `q`
+    pub modulus: Integer,
+
+    /// The number `n` of hidden weights and vectors
+    pub hidden: usize,
+}
+
+impl Instance {
+    /// # Panics
+    /// if `hidden` is larger than the number of samples
+    pub fn new(samples: Vec<Integer>, modulus: Integer, hidden: usize) -> Self {
+        assert!(hidden <= samples.len());
+        Self {
+            samples,
+            modulus,
+            hidden,
+        }
+    }
+
+    /// Whether the binary vectors `vectors` and the weights `weights` are a solution of the instance
+    pub fn is_solution(&self, vectors: &Matrix<Integer>, weights: &[Integer]) -> bool {
+        let (n, m) = vectors.dimensions();
+        n == self.hidden
+            && weights.len() == n
+            && m == self.samples.len()
+            && (0..n).all(|i| (0..m).all(|j| vectors[i][j] == 0 || vectors[i][j] == 1))
+            && (0..m).all(|j| {
+                let sum: Integer = (0..n)
+                    .map(|i| Integer::from(&vectors[i][j] * &weights[i]))
+                    .sum();
+                (sum - &self.samples[j]).rem_euc(&self.modulus) == 0
+            })
+    }
+}
+
+/// L²-reduced basis of the completion `L_x` of the lattice spanned by the hidden vectors
+///
+/// Takes the `m - n` shortest vectors of a reduced basis of the lattice of the vectors orthogonal
+/// to the samples modulo `q` (see [`orthogonal::modular_kernel`]), and returns their orthogonal
+/// lattice. Its rank is `n` when the modulus is large enough for these vectors to be orthogonal
+/// to all the hidden vectors.
+pub fn hidden_lattice(instance: &Instance) -> Matrix<Integer> {
+    let m = instance.samples.len();
+    let n = instance.hidden;
+    let kernel = orthogonal::modular_kernel(&instance.samples, &instance.modulus);
+
+    let mut shortest: Vec<(Integer, usize)> = (0..m).map(|i| (kernel[i].norm_sqr(), i)).collect();
+    shortest.sort();
+    let mut orthogonal: Matrix<Integer> = Matrix::init(m - n, m);
+    for (k, (_, i)) in shortest.iter().take(m - n).enumerate() {
+        for j in 0..m {
+            orthogonal[k][j] = kernel[*i][j].clone();
+        }
+    }
+    orthogonal::orthogonal_lattice(&orthogonal)
+}
+
+/// Whether the entries of `v` are all in `{0, 1}`, and not all zero
+fn is_binary(v: &[Integer]) -> bool {
+    v.iter().all(|v_j| *v_j == 0 || *v_j == 1) && v.iter().any(|v_j| *v_j == 1)
+}
+
+/// Recovers binary vectors spanning the same space as a reduced basis of `L_x`
+///
+/// The reduced basis vectors `b_k` are typically of the form `x_i` or `x_i - x_j`. The binary
+/// vectors among the differences `±b_k` and `±b_k ± b_l` (with entries in `{-1, 0, 1}`) are
+/// collected, and the set is closed under the addition of these differences, as long as the sums
+/// are binary. The binary vectors are then selected by increasing weight, as long as they are
+/// linearly independent. Returns `None` if fewer binary vectors than the rank of the basis are found.
+pub fn recover_vectors(basis: &Matrix<Integer>) -> Option<Matrix<Integer>> {
+    let (d, m) = basis.dimensions();
+    let vectors: Vec<Vec<Integer>> = (0..d)
+        .map(|k| (0..m).map(|j| basis[k][j].clone()).collect())
+        .collect();
+    let combine = |u: &[Integer], v: &[Integer], sign: i32| -> Vec<Integer> {
+        u.iter()
+            .zip(v)
+            .map(|(u_j, v_j)| Integer::from(u_j + v_j * sign))
+            .collect()
+    };
+
+    let mut differences: BTreeSet<Vec<Integer>> = BTreeSet::new();
+    for (k, b_k) in vectors.iter().enumerate() {
+        differences.insert(b_k.clone());
+        for b_l in &vectors[k + 1..] {
+            differences.insert(combine(b_k, b_l, 1));
+            differences.insert(combine(b_k, b_l, -1));
+        }
+    }
+    differences.retain(|v| v.iter().all(|v_j| v_j.clone().abs() <= 1));
+    let differences: Vec<Vec<Integer>> = differences
+        .into_iter()
+        .flat_map(|v| {
+            let opposite = v.iter().map(|v_j| Integer::from(-v_j)).collect();
+            vec![v, opposite]
+        })
+        .collect();
+
+    let mut binary: BTreeSet<Vec<Integer>> = differences
+        .iter()
+        .filter(|v| is_binary(v))
+        .cloned()
+        .collect();
+    let mut new: Vec<Vec<Integer>> = binary.iter().cloned().collect();
+    while !new.is_empty() {
+        let sums: Vec<Vec<Integer>> = new
+            .iter()
+            .flat_map(|x| differences.iter().map(move |v| combine(x, v, 1)))
+            .filter(|x| is_binary(x))
+            .collect();
+        new = sums
+            .into_iter()
+            .filter(|x| binary.insert(x.clone()))
+            .collect();
+    }
+
+    let mut candidates: Vec<Vec<Integer>> = binary.into_iter().collect();
+    candidates.sort_by_key(|x| x.iter().filter(|x_j| **x_j == 1).count());
+    let mut selected: Vec<Vec<Integer>> = vec![];
+    for candidate in candidates {
+        if selected.len() == d {
+            break;
+        }
+        selected.push(candidate);
+        if Matrix::from_matrix(selected.clone()).rank() < selected.len() {
+            selected.pop();
+        }
+    }
+
+    if selected.len() < d {
+        return None;
+    }
+    Some(Matrix::from_matrix(selected))
+}
+
+/// Solves the instance with a custom reduction of `L_x`
+///
+/// Computes the basis of [`hidden_lattice`], reduces it further with `reduce` (e.g. with a stronger
+/// reduction than L² for larger instances), recovers the hidden vectors with [`recover_vectors`]
+/// and the weights by solving the linear system modulo `q`. Returns the hidden vectors and their
+/// weights (in `[0, q)`, in the same order), or `None` if they are not found. The order of the hidden
+/// vectors is arbitrary. When `m` is small, the instance may have other solutions (e.g. with
+/// `x_i - x_j` instead of `x_i` when it is binary), and any of them may be returned.
+pub fn solve_with<F>(instance: &Instance, mut reduce: F) -> Option<(Matrix<Integer>, Vec<Integer>)>
+where
+    F: FnMut(&mut Matrix<Integer>),
+{
+    let mut basis = hidden_lattice(instance);
+    if basis.dimensions().0 != instance.hidden {
+        return None;
+    }
+    reduce(&mut basis);
+    let vectors = recover_vectors(&basis)?;
+
+    // h_j = sum α_i x_i[j] mod q, as a system A^T α = h with the columns a_j = (x_i[j])_i
+    let (n, m) = vectors.dimensions();
+    let mut a: Matrix<Integer> = Matrix::init(m, n);
+    for j in 0..m {
+        for i in 0..n {
+            a[j][i] = vectors[i][j].clone();
+        }
+    }
+    let weights = lwe::solve_secret(&a, &instance.samples, &instance.modulus)?;
+
+    if instance.is_solution(&vectors, &weights) {
+        Some((vectors, weights))
+    } else {
+        None
+    }
+}
+
+/// Solves the instance with Nguyen and Stern's algorithm, using only L² reduction
+///
+/// See [`solve_with`].
+pub fn solve(instance: &Instance) -> Option<(Matrix<Integer>, Vec<Integer>)> {
+    solve_with(instance, |_| {})
+}
@@ -0,0 +1,149 @@
+//! Low-density subset-sum problems
+//!
+//! Given weights `a_1, ..., a_n` and a target `s`, a subset-sum solution is `x` in `{0, 1}^n`
+//! with `sum a_i x_i = s`. When the density `n / log2(max a_i)` of the instance is low, the
+//! solution is expected to be the shortest vector of a suitable lattice and is found by lattice
+//! reduction, following Lagarias and Odlyzko [LO85] or Coster et al. [CJLOSS92].
+//!
+//! [LO85]: J. C. Lagarias and A. M. Odlyzko. Solving Low-Density Subset Sum Problems. J. ACM, 1985.
+//!
+//! [CJLOSS92]: M. J. Coster, A. Joux, B. A. LaMacchia, A. M. Odlyzko, C. P. Schnorr and J. Stern.
+//! Improved Low-Density Subset Sum Algorithms. Computational Complexity, 1992.
+
+use crate::{algebra::Matrix, l2};
+
+use rug::Integer;
+
+/// A subset-sum instance
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubsetSum {
+    pub weights: Vec<Integer>,
+    pub target: Integer,
+}
+
+impl SubsetSum {
+    pub fn new(weights: Vec<Integer>, target: Integer) -> Self {
+        Self { weights, target }
+    }
+
+    /// Density `n / log2(max a_i)` of the instance
+    pub fn density(&self) -> f64 {
+        let max = match self.weights.iter().max() {
+            Some(max) if *max > 1 => max,
+            _ => return f64::INFINITY,
+        };
+        let (mantissa, exponent) = max.to_f64_exp();
+        self.weights.len() as f64 / (mantissa.log2() + f64::from(exponent))
+    }
+
+    /// Whether `x` is a solution of the instance
+    pub fn is_solution(&self, x: &[bool]) -> bool {
+        x.len() == self.weights.len()
+            && self
+                .weights
+                .iter()
+                .zip(x)
+                .filter(|(_, &x_i)| x_i)
+                .map(|(a, _)| a)
+                .sum::<Integer>()
+                == self.target
+    }
+}
+
+/// Lattice embedding of a subset-sum instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Embedding {
+    /// Basis `(e_i, N a_i)` and `(0, ..., 0, N s)`: the solution is `(x, 0)`
+    LagariasOdlyzko,
+
+    /// Basis `(2 e_i, N a_i)` and `(1, ..., 1, N s)`: the solution is `(1 - 2x, 0)`
+    Cjloss,
+}
+
+impl Embedding {
+    /// Density below which the attack provably works for almost all instances, given an SVP oracle
+    ///
+    /// In practice, lattice reduction only approximates the shortest vector and the attack succeeds
+    /// for densities somewhat below these values, especially in large dimension.
+    pub fn critical_density(self) -> f64 {
+        match self {
+            Embedding::LagariasOdlyzko => 0.6463,
+            Embedding::Cjloss => 0.9408,
+        }
+    }
+
+    /// Whether the density of `instance` is below the critical density of the embedding
+    pub fn is_expected_to_work(self, instance: &SubsetSum) -> bool {
+        instance.density() < self.critical_density()
+    }
+}
+
+/// Lattice basis of the embedding of `instance`
+///
+/// The last coordinate is scaled by `N = floor(sqrt(n)) + 1` so that the short vectors of the lattice
+/// satisfy the subset-sum equation.
+pub fn lattice(instance: &SubsetSum, embedding: Embedding) -> Matrix<Integer> {
+    let n = instance.weights.len();
+    let scale = Integer::from(n).sqrt() + 1;
+    let diagonal = match embedding {
+        Embedding::LagariasOdlyzko => 1,
+        Embedding::Cjloss => 2,
+    };
+
+    let mut basis: Matrix<Integer> = Matrix::init(n + 1, n + 1);
+    for (i, a) in instance.weights.iter().enumerate() {
+        basis[i][i] = Integer::from(diagonal);
+        basis[i][n] = Integer::from(a * &scale);
+        if embedding == Embedding::Cjloss {
+            basis[n][i] = Integer::from(1);
+        }
+    }
+    basis[n][n] = Integer::from(&instance.target * &scale);
+    basis
+}
+
+/// Solves a subset-sum instance by lattice reduction
+///
+/// Reduces the lattice of [`lattice`] with L² and scans the reduced basis for vectors encoding
+/// a solution. Returns `None` if none is found, which is likely when the density of the instance
+/// is not below the critical density of the embedding (see [`Embedding::is_expected_to_work`]).
+pub fn solve(instance: &SubsetSum, embedding: Embedding) -> Option<Vec<bool>> {
+    let n = instance.weights.len();
+    let mut basis = lattice(instance, embedding);
+    l2::lll_bignum(&mut basis, 0.51, 0.99).expect("The lattice basis is valid");
+
+    (0..=n)
+        .filter(|&i| basis[i][n] == 0)
+        .flat_map(|i| {
+            let v = &basis[i];
+            let candidates: Vec<Vec<bool>> = match embedding {
+                Embedding::LagariasOdlyzko => [1, -1]
+                    .iter()
+                    .filter(|&&one| (0..n).all(|j| v[j] == 0 || v[j] == one))
+                    .map(|&one| (0..n).map(|j| v[j] == one).collect())
+                    .collect(),
+                Embedding::Cjloss => {
+                    if (0..n).all(|j| v[j] == 1 || v[j] == -1) {
+                        vec![
+                            (0..n).map(|j| v[j] == -1).collect(),
+                            (0..n).map(|j| v[j] == 1).collect(),
+                        ]
+                    } else {
+                        vec![]
+                    }
+                }
+            };
+            candidates
+        })
+        .find(|x| instance.is_solution(x))
+}
+
+/// Recovers the plaintext bits of a Merkle-Hellman ciphertext `c = sum b_i m_i` from the public key `b`
+///
+/// Merkle-Hellman public keys are disguised superincreasing sequences, and have a density
+/// of about 0.5: the ciphertext is decrypted as a low-density subset-sum instance, with the
+/// CJLOSS embedding first and then with the Lagarias-Odlyzko one.
+pub fn merkle_hellman_plaintext(public_key: &[Integer], ciphertext: &Integer) -> Option<Vec<bool>> {
+    let instance = SubsetSum::new(public_key.to_vec(), ciphertext.clone());
+    solve(&instance, Embedding::Cjloss).or_else(|| solve(&instance, Embedding::LagariasOdlyzko))
+}
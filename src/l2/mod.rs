@@ -1,62 +1,1024 @@
-use crate::algebra::{BigNum, Float, FromExt, Matrix, Scalar, Vector};
+use crate::algebra::{
+    with_overflow_check, Basis, Checked, CheckedI128, CheckedI64, Coefficient, FixedPoint, Float,
+    Float32, FromExt, Gso, HouseholderScalar, InvalidBasis, Matrix, Overflow, Scalar, Single,
+    Transform, Vector,
+};
+
+#[cfg(feature = "crypto-bigint")]
+use crate::algebra::FixedInt;
+#[cfg(feature = "num")]
+use crate::algebra::NumBigInt;
+#[cfg(feature = "rug")]
+use crate::algebra::{with_precision, BigDouble, BigDoubleDouble, BigFloat, BigNum, Certified};
+#[cfg(feature = "num-traits")]
+use crate::algebra::{Num, NumInteger, NumTraits};
+
+use std::{
+    collections::VecDeque,
+    error, fmt, iter, mem,
+    str::FromStr,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+pub mod small;
+mod storage;
+
+use storage::Vectors;
+
+/// Numerical failure of a floating-point reduction
+enum Failure {
+    /// A non-positive `r_ii`, or a size-reduction which does not converge
+    Inconsistent,
+    /// A non-finite Gram-Schmidt coefficient of the basis vector at the given index
+    NonFinite(usize),
+    /// The deadline of the reduction has passed
+    Timeout(Progress),
+    /// The reduction has run its maximum number of iterations
+    IterationLimit(Progress),
+}
+
+/// Error returned when the Gram-Schmidt coefficients of a basis vector overflow, or are not a number
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NonFinite {
+    /// Index of the basis vector, in the order of the basis at the time of the failure
+    pub index: usize,
+}
+
+impl fmt::Display for NonFinite {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "non-finite Gram-Schmidt coefficient of the basis vector {} during the lattice reduction",
+            self.index
+        )
+    }
+}
+
+impl error::Error for NonFinite {}
+
+/// Error returned when a reduction exceeds its time limit
+///
+/// The reduction stops cleanly: the basis spans the same lattice, and its vectors before `progress.kappa` are
+/// LLL-reduced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timeout {
+    /// Progress of the reduction when it was stopped
+    pub progress: Progress,
+}
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the lattice reduction exceeded its time limit at the basis vector {} of {} (tour {})",
+            self.progress.kappa, self.progress.dimension, self.progress.tour
+        )
+    }
+}
+
+impl error::Error for Timeout {}
+
+/// Error returned when a reduction reaches its maximum number of iterations, and is incomplete
+///
+/// The reduction stops cleanly: the basis spans the same lattice, and its vectors before `progress.kappa` are
+/// LLL-reduced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IterationLimit {
+    /// Progress of the reduction when it was stopped
+    pub progress: Progress,
+}
+
+impl fmt::Display for IterationLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the lattice reduction is incomplete after {} iterations, at the basis vector {} of {} (tour {})",
+            self.progress.work, self.progress.kappa, self.progress.dimension, self.progress.tour
+        )
+    }
+}
+
+impl error::Error for IterationLimit {}
+
+/// Error of a reduction: a [`Reducer`], a checked entry point (e.g. [`lll_float`]), or a reduction of
+/// [`LatticeReduce`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReductionError {
+    /// A Gram-Schmidt coefficient is not finite: the basis is left unchanged
+    NonFinite(NonFinite),
+    /// The reduction exceeded its time limit: the basis is partially reduced
+    Timeout(Timeout),
+    /// The reduction reached its maximum number of iterations: the basis is partially reduced
+    IterationLimit(IterationLimit),
+    /// The basis is empty, or its vectors do not have the same dimension: the basis is left unchanged
+    InvalidBasis(InvalidBasis),
+    /// An intermediate integer does not fit in the machine integers: the basis is left unchanged
+    Overflow(Overflow),
+}
+
+impl fmt::Display for ReductionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReductionError::NonFinite(error) => error.fmt(f),
+            ReductionError::Timeout(error) => error.fmt(f),
+            ReductionError::IterationLimit(error) => error.fmt(f),
+            ReductionError::InvalidBasis(error) => error.fmt(f),
+            ReductionError::Overflow(error) => error.fmt(f),
+        }
+    }
+}
+
+impl error::Error for ReductionError {}
+
+impl From<NonFinite> for ReductionError {
+    fn from(error: NonFinite) -> Self {
+        ReductionError::NonFinite(error)
+    }
+}
+
+impl From<Timeout> for ReductionError {
+    fn from(error: Timeout) -> Self {
+        ReductionError::Timeout(error)
+    }
+}
+
+impl From<IterationLimit> for ReductionError {
+    fn from(error: IterationLimit) -> Self {
+        ReductionError::IterationLimit(error)
+    }
+}
+
+impl From<InvalidBasis> for ReductionError {
+    fn from(error: InvalidBasis) -> Self {
+        ReductionError::InvalidBasis(error)
+    }
+}
+
+impl From<Overflow> for ReductionError {
+    fn from(error: Overflow) -> Self {
+        ReductionError::Overflow(error)
+    }
+}
+
+/// Computation of the Gram-Schmidt coefficients by the floating-point reductions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FloatGso {
+    /// Cholesky factorisation of the exact Gram matrix, as in the original L² algorithm
+    #[default]
+    Cholesky,
+    /// Householder orthogonalisation of the basis vectors, as in fplll's "fast" variants [MSV09]. It is much
+    /// more stable on ill-conditioned bases, whose Gram-Schmidt norms are far apart, at the cost of `n` (instead of
+    /// `kappa`) operations per coefficient.
+    ///
+    /// [MSV09]: I. Morel, D. Stehlé and G. Villard. H-LLL: Using Householder inside LLL. ISSAC 2009.
+    Householder,
+}
+
+impl FloatGso {
+    /// The orthogonalisation, with the square root of the backend for the Householder one
+    fn with_sqrt<S: HouseholderScalar>(self) -> Orthogonalization<S::Fraction> {
+        match self {
+            FloatGso::Cholesky => Orthogonalization::Cholesky,
+            FloatGso::Householder => Orthogonalization::Householder(S::sqrt),
+        }
+    }
+}
+
+/// Computation of the Gram-Schmidt coefficients, as [`FloatGso`], available for every backend
+///
+/// The Householder orthogonalisation carries the square root of the backend: only the backends implementing
+/// [`HouseholderScalar`] can build it, through [`FloatGso::with_sqrt`].
+enum Orthogonalization<F> {
+    Cholesky,
+    Householder(fn(&F) -> F),
+}
+
+// Not derived, which would require `F: Copy`
+impl<F> Clone for Orthogonalization<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for Orthogonalization<F> {}
+
+/// Statistics of a reduction, reported by [`Reduction::stats`]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ReductionStats {
+    /// Number of swaps of adjacent vectors, that is of insertions of `basis[kappa]` before `basis[kappa - 1]`
+    pub swaps: usize,
+    /// Number of deep insertions of `basis[kappa]` before `basis[kappa - 2]` or an earlier vector
+    pub deep_insertions: usize,
+    /// Number of size-reduction steps, each subtracting integer multiples of the previous vectors from a vector
+    pub size_reductions: usize,
+    /// Number of computations of the whole Gram matrix, which happen after each insertion
+    pub gram_recomputations: usize,
+    /// Logarithm (base 2) of the potential `prod_i ||b*_i||^(2 (d - i))` of the `d` non-zero reduced vectors, which
+    /// decreases with every swap
+    pub log_potential: f64,
+}
+
+/// Logarithm (base 2) of the relative margin of the Lovász conditions below which a decision is borderline: `-p/2`
+/// for Gram-Schmidt coefficients of `p` bits of precision, the square root of their relative precision. The decisions
+/// on exact coefficients are never borderline.
+fn borderline_margin<S: Scalar>() -> f64 {
+    match S::precision() {
+        u32::MAX => f64::NEG_INFINITY,
+        precision => -f64::from(precision / 2),
+    }
+}
+
+/// Numerical health of a floating-point reduction, reported by [`Reduction::health`]
+///
+/// It tells whether the precision of the Gram-Schmidt coefficients sufficed: a large precision loss or borderline
+/// Lovász decisions call for a reduction with more precision, or with exact arithmetic ([`lll_bignum`]).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Health {
+    /// Smallest squared Gram-Schmidt norm `r_ii = ||b*_i||^2` of a non-zero vector encountered during the reduction
+    pub min_r: f64,
+    /// Estimated number of bits of precision lost to cancellations: the logarithm (base 2) of the largest
+    /// Gram-Schmidt coefficient `|mu_ij|` of a vector before its size-reduction, which scales the rounding errors
+    pub precision_loss: f64,
+    /// Number of Lovász conditions `delta r_(k-1)(k-1) <= s_(k-1)` decided with a relative margin below `2^-(p/2)`,
+    /// for Gram-Schmidt coefficients of `p` bits of precision (about `2^-26` for doubles)
+    pub borderline_decisions: usize,
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Self {
+            min_r: f64::INFINITY,
+            precision_loss: 0.,
+            borderline_decisions: 0,
+        }
+    }
+}
+
+/// Progress of a reduction, reported to the callback of [`Reduction::progress`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// Index of the basis vector being reduced: the previous vectors are LLL-reduced
+    pub kappa: usize,
+    /// Number of basis vectors
+    pub dimension: usize,
+    /// Index of the current tour: the pipeline runs two tours of the reduction
+    pub tour: usize,
+    /// Number of iterations of the reduction loop since the start, each size-reducing one vector
+    pub work: usize,
+}
+
+/// Diagnostic event of a reduction, passed to the sink of an [`EventSink`]
+///
+/// Its `Display` implementation is a single line of space-separated `key=value` fields, which is easy to parse
+/// back from a trace file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// A tour of the reduction starts
+    Tour { tour: usize },
+    /// Integer multiples of the previous vectors were subtracted from `basis[kappa]`
+    SizeReduction { kappa: usize },
+    /// `basis[from]` failed the Lovász condition and was inserted before `basis[to]`
+    Insertion { from: usize, to: usize },
+    /// `basis[index]` was reduced to the zero vector, and moved to the end of the basis
+    ZeroVector { index: usize },
+    /// The reduction is done, after the given numbers of operations (see [`ReductionStats`])
+    Summary {
+        swaps: usize,
+        deep_insertions: usize,
+        size_reductions: usize,
+    },
+}
+
+impl Event {
+    /// The lowest verbosity at which the event is emitted
+    pub fn level(&self) -> Verbosity {
+        match self {
+            Event::Summary { .. } => Verbosity::Summary,
+            Event::Tour { .. } => Verbosity::PerTour,
+            Event::SizeReduction { .. } | Event::Insertion { .. } | Event::ZeroVector { .. } => {
+                Verbosity::PerSwap
+            }
+        }
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Event::Tour { tour } => write!(f, "event=tour tour={}", tour),
+            Event::SizeReduction { kappa } => write!(f, "event=size_reduction kappa={}", kappa),
+            Event::Insertion { from, to } => write!(f, "event=insertion from={} to={}", from, to),
+            Event::ZeroVector { index } => write!(f, "event=zero_vector index={}", index),
+            Event::Summary {
+                swaps,
+                deep_insertions,
+                size_reductions,
+            } => write!(
+                f,
+                "event=summary swaps={} deep_insertions={} size_reductions={}",
+                swaps, deep_insertions, size_reductions
+            ),
+        }
+    }
+}
+
+/// Resumable state of a reduction, for the long reductions which are stopped and resumed later
+///
+/// A checkpoint is created from the basis to reduce, or taken from a [`Reducer`] stopped by its time limit (see
+/// [`Reducer::checkpoint`]), and resumed by a [`Reduction`] (see [`Reduction::resume`]) until it is complete. It is
+/// written and read back as text with `Display` and `FromStr`: a line `tour <tour>`, a line `kappa <kappa>`, a line
+/// `work <work>`, then one line per basis vector with its space-separated coordinates.
+///
+/// The Gram-Schmidt coefficients are not saved, but recomputed when the reduction is resumed: the tour goes on at
+/// `kappa`, and the vectors before it, which were already reduced, are not size-reduced nor inserted again.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Checkpoint<T: Coefficient> {
+    /// The basis, partially reduced
+    pub basis: Matrix<T>,
+    /// Index of the tour to resume, which is 2 once the reduction is complete
+    pub tour: usize,
+    /// Index of the basis vector at which the tour resumes (see [`Progress`])
+    pub kappa: usize,
+    /// Number of iterations of the reduction loop so far (see [`Progress`])
+    pub work: usize,
+}
+
+impl<T: Coefficient> Checkpoint<T> {
+    /// The checkpoint of a reduction of `basis` which has not started
+    pub fn new(basis: Matrix<T>) -> Self {
+        Self {
+            basis,
+            tour: 0,
+            kappa: 1,
+            work: 0,
+        }
+    }
+
+    /// Whether the reduction is complete, with the trailing null vectors put at the beginning
+    pub fn is_complete(&self) -> bool {
+        self.tour >= 2
+    }
+}
+
+impl<T: Coefficient + fmt::Display> fmt::Display for Checkpoint<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "tour {}", self.tour)?;
+        writeln!(f, "kappa {}", self.kappa)?;
+        writeln!(f, "work {}", self.work)?;
+        let (d, n) = self.basis.dimensions();
+        for i in 0..d {
+            for j in 0..n {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", self.basis[i][j])?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when a [`Checkpoint`] cannot be read back
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseCheckpointError {
+    /// Index of the invalid line, starting from 0
+    pub line: usize,
+}
+
+impl fmt::Display for ParseCheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid line {} of the reduction checkpoint", self.line)
+    }
+}
+
+impl error::Error for ParseCheckpointError {}
+
+impl<T: Coefficient + FromStr> FromStr for Checkpoint<T> {
+    type Err = ParseCheckpointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate();
+        let missing = ParseCheckpointError {
+            line: s.lines().count(),
+        };
+        let mut counter = |name: &str| {
+            let (line, text) = lines.next().ok_or(missing)?;
+            text.strip_prefix(name)
+                .and_then(|value| value.trim().parse().ok())
+                .ok_or(ParseCheckpointError { line })
+        };
+        let tour = counter("tour")?;
+        let kappa = counter("kappa")?;
+        let work = counter("work")?;
+        // The lines of the vectors, which are not consecutive around blank lines
+        let (numbers, vectors): (Vec<usize>, Vec<Vec<T>>) = lines
+            .filter(|(_, text)| !text.trim().is_empty())
+            .map(|(line, text)| {
+                let vector = text
+                    .split_whitespace()
+                    .map(|entry| entry.parse().map_err(|_| ParseCheckpointError { line }))
+                    .collect::<Result<_, _>>()?;
+                Ok((line, vector))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+        let basis = Matrix::try_from_matrix(vectors).map_err(|mismatch| ParseCheckpointError {
+            line: numbers[mismatch.index],
+        })?;
+        Ok(Self {
+            basis,
+            tour,
+            kappa,
+            work,
+        })
+    }
+}
+
+/// Amount of diagnostic events passed to the sink of a reduction, each level including the previous ones
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Verbosity {
+    /// No event
+    #[default]
+    Silent,
+    /// A summary of the reduction when it is done
+    Summary,
+    /// The start of each tour
+    PerTour,
+    /// Each insertion and size-reduction of a basis vector
+    PerSwap,
+}
+
+/// Callbacks of the operations of a reduction on `T` integers, passed to [`Reduction::tracer`]
+///
+/// Each method does nothing by default, so that a tracer only implements the operations it records. The callbacks
+/// are called after the operation, in the order of the reduction, and describe it entirely: replaying them on the
+/// initial basis gives the reduced one. The diagnostic events of [`EventSink`] are built on them.
+pub trait Tracer<T: Coefficient> {
+    /// A tour of the reduction starts
+    fn tour(&mut self, _tour: usize) {}
+
+    /// `factors[i]` times `basis[i]` was subtracted from `basis[kappa]` for each `i < kappa`, from the last one to
+    /// the first one
+    fn size_reduce(&mut self, _kappa: usize, _factors: &[T]) {}
+
+    /// `basis[kappa]` was swapped with `basis[kappa - 1]`
+    fn swap(&mut self, _kappa: usize) {}
+
+    /// `basis[from]` was inserted before `basis[to]`, with `to + 1 < from`
+    fn insertion(&mut self, _from: usize, _to: usize) {}
+
+    /// `basis[index]` was reduced to the zero vector, and moved to `basis[to]`, after the non-zero vectors
+    fn zero_vector(&mut self, _index: usize, _to: usize) {}
+
+    /// The Gram matrix of the basis was computed from scratch
+    fn gram_recompute(&mut self, _gram: &Matrix<T>) {}
+
+    /// The reduction is done, after the operations counted in `stats` (without the potential of the reduced basis),
+    /// and the trailing null vectors were put at the beginning of the basis
+    fn done(&mut self, _stats: &ReductionStats) {}
+}
+
+/// Tracer passing the diagnostic [`Event`]s of a reduction, up to a verbosity, to a sink
+///
+/// A size-reduction is one event however many vectors it subtracts, and a swap is an insertion of `basis[kappa]`
+/// before `basis[kappa - 1]`.
+pub struct EventSink<F: FnMut(Event)> {
+    verbosity: Verbosity,
+    sink: F,
+}
+
+impl<F: FnMut(Event)> EventSink<F> {
+    /// Pass the events of `verbosity` to `sink`
+    pub fn new(verbosity: Verbosity, sink: F) -> Self {
+        Self { verbosity, sink }
+    }
+
+    fn emit(&mut self, event: Event) {
+        if event.level() <= self.verbosity {
+            (self.sink)(event);
+        }
+    }
+}
+
+impl<T: Coefficient, F: FnMut(Event)> Tracer<T> for EventSink<F> {
+    fn tour(&mut self, tour: usize) {
+        self.emit(Event::Tour { tour });
+    }
+
+    fn size_reduce(&mut self, kappa: usize, _factors: &[T]) {
+        self.emit(Event::SizeReduction { kappa });
+    }
+
+    fn swap(&mut self, kappa: usize) {
+        self.emit(Event::Insertion {
+            from: kappa,
+            to: kappa - 1,
+        });
+    }
+
+    fn insertion(&mut self, from: usize, to: usize) {
+        self.emit(Event::Insertion { from, to });
+    }
+
+    fn zero_vector(&mut self, index: usize, _to: usize) {
+        self.emit(Event::ZeroVector { index });
+    }
+
+    fn done(&mut self, stats: &ReductionStats) {
+        self.emit(Event::Summary {
+            swaps: stats.swaps,
+            deep_insertions: stats.deep_insertions,
+            size_reductions: stats.size_reductions,
+        });
+    }
+}
+
+/// Tracer passing the operations of a reduction to a tracer, if any, and to another one
+struct Tee<'a, T: Coefficient> {
+    first: Option<&'a mut dyn Tracer<T>>,
+    second: &'a mut dyn Tracer<T>,
+}
+
+impl<T: Coefficient> Tracer<T> for Tee<'_, T> {
+    fn tour(&mut self, tour: usize) {
+        if let Some(first) = self.first.as_deref_mut() {
+            first.tour(tour);
+        }
+        self.second.tour(tour);
+    }
+
+    fn size_reduce(&mut self, kappa: usize, factors: &[T]) {
+        if let Some(first) = self.first.as_deref_mut() {
+            first.size_reduce(kappa, factors);
+        }
+        self.second.size_reduce(kappa, factors);
+    }
+
+    fn swap(&mut self, kappa: usize) {
+        if let Some(first) = self.first.as_deref_mut() {
+            first.swap(kappa);
+        }
+        self.second.swap(kappa);
+    }
+
+    fn insertion(&mut self, from: usize, to: usize) {
+        if let Some(first) = self.first.as_deref_mut() {
+            first.insertion(from, to);
+        }
+        self.second.insertion(from, to);
+    }
+
+    fn zero_vector(&mut self, index: usize, to: usize) {
+        if let Some(first) = self.first.as_deref_mut() {
+            first.zero_vector(index, to);
+        }
+        self.second.zero_vector(index, to);
+    }
+
+    fn gram_recompute(&mut self, gram: &Matrix<T>) {
+        if let Some(first) = self.first.as_deref_mut() {
+            first.gram_recompute(gram);
+        }
+        self.second.gram_recompute(gram);
+    }
+
+    fn done(&mut self, stats: &ReductionStats) {
+        if let Some(first) = self.first.as_deref_mut() {
+            first.done(stats);
+        }
+        self.second.done(stats);
+    }
+}
+
+/// Bookkeeping of a reduction, updated along with the basis
+struct Tracking<'a, T: Coefficient> {
+    /// The transformation updated by the operations on the basis, if any
+    transform: Option<&'a mut Transform<T>>,
+    /// The callback reporting the progress, if any
+    progress: Option<&'a mut dyn FnMut(Progress)>,
+    /// The tracer of the operations, if any
+    tracer: Option<&'a mut dyn Tracer<T>>,
+    /// The Gram-Schmidt profile at the end of the last tour, if it is requested
+    profile: Option<Vec<f64>>,
+    /// The numerical health of the reduction, if it is requested
+    health: Option<Health>,
+    /// The time after which the reduction stops, if any
+    deadline: Option<Instant>,
+    /// The progress when the deadline passed, if it did
+    timeout: Option<Progress>,
+    /// The number of iterations of the reduction loop, over all the tours, after which the current run stops, if any
+    max_iterations: Option<usize>,
+    /// The progress when the maximum number of iterations was reached, if it was
+    incomplete: Option<Progress>,
+    stats: ReductionStats,
+    tour: usize,
+    /// The position at which the next tour starts, which is only past 1 when it resumes a checkpoint
+    kappa: usize,
+    work: usize,
+}
+
+impl<T: Coefficient> Default for Tracking<'_, T> {
+    fn default() -> Self {
+        Self {
+            transform: None,
+            progress: None,
+            tracer: None,
+            profile: None,
+            health: None,
+            deadline: None,
+            timeout: None,
+            max_iterations: None,
+            incomplete: None,
+            stats: ReductionStats::default(),
+            tour: 0,
+            kappa: 1,
+            work: 0,
+        }
+    }
+}
+
+/// The instant `time_limit` from now, or no deadline if it is too far to be represented (e.g. `Duration::MAX`)
+fn deadline(time_limit: Duration) -> Option<Instant> {
+    Instant::now().checked_add(time_limit)
+}
+
+impl<T: Coefficient> Tracking<'_, T> {
+    /// Runs `f` with this tracking, whose operations are also queued in `events` as those of
+    /// [`Verbosity::PerSwap`]
+    fn queued<R>(
+        &mut self,
+        events: &mut VecDeque<Event>,
+        f: impl FnOnce(&mut Tracking<T>) -> R,
+    ) -> R {
+        let Tracking {
+            transform,
+            progress,
+            tracer,
+            profile,
+            health,
+            deadline,
+            timeout,
+            max_iterations,
+            incomplete,
+            stats,
+            tour,
+            kappa,
+            work,
+        } = self;
+        let mut sink = EventSink::new(Verbosity::PerSwap, |event| events.push_back(event));
+        let mut tee = Tee {
+            first: tracer.as_mut().map(|tracer| &mut **tracer as _),
+            second: &mut sink,
+        };
+        let mut tracking = Tracking {
+            transform: transform.as_deref_mut(),
+            progress: progress.as_mut().map(|progress| &mut **progress as _),
+            tracer: Some(&mut tee),
+            profile: profile.take(),
+            health: *health,
+            deadline: *deadline,
+            timeout: *timeout,
+            max_iterations: *max_iterations,
+            incomplete: *incomplete,
+            stats: *stats,
+            tour: *tour,
+            kappa: *kappa,
+            work: *work,
+        };
+        let result = f(&mut tracking);
+        *profile = tracking.profile;
+        *health = tracking.health;
+        *timeout = tracking.timeout;
+        *incomplete = tracking.incomplete;
+        *stats = tracking.stats;
+        *tour = tracking.tour;
+        *kappa = tracking.kappa;
+        *work = tracking.work;
+        result
+    }
+
+    /// The error of a reduction stopped by its deadline or its maximum number of iterations, if it was
+    fn interruption(&mut self) -> Option<ReductionError> {
+        if let Some(progress) = self.timeout.take() {
+            return Some(Timeout { progress }.into());
+        }
+        self.incomplete
+            .take()
+            .map(|progress| IterationLimit { progress }.into())
+    }
+
+    /// Record the squared Gram-Schmidt norm `log2(r_ii)` of a vector in the health report, if it is requested
+    fn record_r(&mut self, log_r: f64) {
+        if let Some(health) = &mut self.health {
+            if log_r.is_finite() {
+                health.min_r = health.min_r.min(log_r.exp2());
+            }
+        }
+    }
+}
+
+/// Householder vectors of the basis vectors, and their coordinates in the orthonormal Gram-Schmidt basis
+struct Householder<S: Scalar, B: Vectors<S::Integer>> {
+    /// Square root of the backend
+    sqrt: fn(&S::Fraction) -> S::Fraction,
+    /// `v[i]` is the reflection of `basis[i]`, scaled so that `<v[i], v[i]> = 2`, and zero on its `i` first coordinates
+    v: B::With<S::Fraction>,
+    /// `q[i][j]` is the `j`-th coordinate of `basis[i]`, for `j <= i`: `q[j][j]` is `± ||b*_j||`
+    q: B::Square<S::Fraction>,
+}
 
 /// Lattice reduction (L² algorithm)
 ///
-/// This implementation uses generic Scalar types for the underlying arithmetic operations.
+/// This implementation uses generic Scalar types for the underlying arithmetic operations, and a generic storage of
+/// the vectors (see [`Vectors`]).
 ///
 /// Arguments:
 ///  * basis: A generating matrix for the lattice
 ///  * eta: eta factor of the basis reduction
 ///  * delta: delta factor of the basis reduction
 ///
-/// The basis is reduced in-place.
+///  * check: whether to detect numerical failures
+///  * gso: computation of the Gram-Schmidt coefficients
+///  * tracking: the transformation and the statistics updated by the operations on the basis
+///
+/// The basis is reduced in-place. With `check`, the reduction stops at the first inconsistency of the
+/// Gram-Schmidt coefficients (a non-positive `r_ii`, or a size-reduction which does not converge), leaving
+/// the basis in an unspecified state. It always stops at the first non-finite Gram-Schmidt coefficient.
 ///
 /// # Panics
 /// if delta <= 1/4 or delta >= 1  
 /// if eta <= 1/2 or eta > sqrt(delta)
-fn lattice_reduce<S: Scalar>(basis: &mut Matrix<S::Integer>, eta: f64, delta: f64) {
-    assert!(0.25 < delta && delta < 1.);
-    assert!(0.5 < eta && eta * eta < delta);
+#[cfg(feature = "rug")]
+fn lattice_reduce<S: Scalar, B: Vectors<S::Integer>>(
+    basis: &mut B,
+    eta: f64,
+    delta: f64,
+    check: bool,
+    gso: Orthogonalization<S::Fraction>,
+    tracking: &mut Tracking<S::Integer>,
+) -> Result<(), Failure> {
+    if basis.len() == 0 {
+        return Ok(());
+    }
+    let mut lattice = Lattice::<S, B>::new(basis, eta, delta, check, gso, tracking)?;
+    while lattice.iterate(basis, tracking)? {}
+    Ok(())
+}
 
-    // Variables
-    let (d, _) = basis.dimensions();
-    let mut gram: Matrix<S::Integer> = Matrix::init(d, d); // Gram matrix (upper triangular)
-    let mut r: Matrix<S::Fraction> = Matrix::init(d, d); // r_ij matrix
-    let mut mu: Matrix<S::Fraction> = Matrix::init(d, d); // Gram coefficient matrix
-    let mut s: Vector<S::Fraction> = Vector::init(d);
-    let mut m = Vector::init(d);
+/// State of the L² algorithm between two iterations of its loop, which size-reduce a vector and insert it
+struct Lattice<S: Scalar, B: Vectors<S::Integer>> {
+    /// Gram matrix (lower triangle kept up to date)
+    gram: B::Square<S::Integer>,
+    /// r_ij matrix
+    r: B::Square<S::Fraction>,
+    /// Gram coefficient matrix
+    mu: B::Square<S::Fraction>,
+    s: B::Line<S::Fraction>,
+    m: B::Line<S::Fraction>,
+    householder: Option<Householder<S, B>>,
+    eta_minus: S::Fraction,
+    delta_plus: S::Fraction,
+    borderline: f64,
+    check: bool,
+    num_zeros: usize,
+    kappa: usize,
+}
 
-    let zero = S::Fraction::from(0);
-    let mut num_zeros = 0;
+impl<S: Scalar, B: Vectors<S::Integer>> Lattice<S, B> {
+    /// Computes the Gram-Schmidt coefficients of the first vector of a non-empty `basis`, before the reduction loop
+    ///
+    /// The leading zero vectors, which have no Gram-Schmidt coefficients, are first moved to the end of the basis,
+    /// where the reduction loop moves the zero vectors it finds. A tour resumed at `tracking.kappa` also computes
+    /// those of the vectors before it (see [`Lattice::resume`]).
+    fn new(
+        basis: &mut B,
+        eta: f64,
+        delta: f64,
+        check: bool,
+        gso: Orthogonalization<S::Fraction>,
+        tracking: &mut Tracking<S::Integer>,
+    ) -> Result<Self, Failure> {
+        assert!(0.25 < delta && delta < 1.);
+        assert!(0.5 < eta && eta * eta < delta);
 
-    // Computing Gram matrix
-    for i in 0..d {
-        for j in 0..=i {
-            gram[i][j] = basis[i].dot(&basis[j]);
+        let d = basis.len();
+        let zero = S::Integer::from(0);
+        let mut num_zeros = 0;
+        while num_zeros < d && basis.row(0).iter().all(|c| *c == zero) {
+            num_zeros += 1;
+            basis.insert(0, d - 1);
+            if let Some(transform) = tracking.transform.as_deref_mut() {
+                transform.insert(0, d - 1);
+            }
+            if let Some(tracer) = tracking.tracer.as_deref_mut() {
+                tracer.zero_vector(0, d - 1);
+            }
         }
-    }
 
-    let eta_minus = S::Fraction::from_ext((eta + 0.5) / 2.);
-    let delta_plus = S::Fraction::from_ext(0.99); //(delta + 1.) / 2.);
+        let gram = storage::gram(basis);
+        tracking.stats.gram_recomputations += 1;
+        if let Some(tracer) = tracking.tracer.as_deref_mut() {
+            tracer.gram_recompute(&gram.as_matrix());
+        }
+        let mut r: B::Square<S::Fraction> = basis.square();
+        let mut mu: B::Square<S::Fraction> = basis.square();
+        let householder = match gso {
+            Orthogonalization::Cholesky => None,
+            Orthogonalization::Householder(sqrt) => {
+                let mut householder = Householder::<S, B> {
+                    sqrt,
+                    v: basis.zeros(),
+                    q: basis.square(),
+                };
+                orthogonalize::<S, B>(0, basis, &mut householder, &mut mu, &mut r);
+                Some(householder)
+            }
+        };
+
+        r[0][0] = S::Fraction::from_ext(&gram[0][0]);
+        if !S::is_finite(&r[0][0]) {
+            return Err(Failure::NonFinite(0));
+        }
+        if tracking.health.is_some() {
+            tracking.record_r(S::log2(&r[0][0]));
+        }
 
-    r[0][0] = S::Fraction::from_ext(&gram[0][0]);
+        let mut lattice = Self {
+            gram,
+            r,
+            mu,
+            s: basis.line(),
+            m: basis.line(),
+            householder,
+            eta_minus: S::Fraction::from_ext((eta + 0.5) / 2.),
+            // Slightly stronger factors than the requested ones, so that the approximate tests still guarantee them
+            delta_plus: S::Fraction::from_ext((delta + 1.) / 2.),
+            borderline: borderline_margin::<S>(),
+            check,
+            num_zeros,
+            kappa: 1,
+        };
+        let kappa = mem::replace(&mut tracking.kappa, 1);
+        lattice.resume(basis, kappa.min(d - num_zeros), tracking)?;
+        Ok(lattice)
+    }
 
-    let mut kappa = 1;
+    /// Computes the Gram-Schmidt coefficients of the vectors before `kappa`, where a resumed tour goes on
+    ///
+    /// These vectors were reduced before the checkpoint of the reduction, so they are neither size-reduced nor
+    /// inserted again. The tour goes on before `kappa` if the Gram-Schmidt norm of a vector is not positive.
+    fn resume(
+        &mut self,
+        basis: &B,
+        kappa: usize,
+        tracking: &mut Tracking<S::Integer>,
+    ) -> Result<(), Failure> {
+        let zero = S::Fraction::from(0);
+        while self.kappa < kappa {
+            let Self {
+                gram,
+                r,
+                mu,
+                householder,
+                kappa: i,
+                ..
+            } = self;
+            let i = *i;
+            let r_ii = match householder {
+                Some(householder) => {
+                    orthogonalize::<S, B>(i, basis, householder, mu, r);
+                    householder.q[i][i].clone() * &householder.q[i][i]
+                }
+                None => {
+                    cfa::<S, B>(i, basis, gram, mu, r);
+                    let norm = S::Fraction::from_ext((gram[i][i].clone(), S::Integer::from(1)));
+                    (0..i).fold(norm, |s, j| s - &(mu[i][j].clone() * &r[i][j]))
+                }
+            };
+            if S::overflowed() {
+                return Ok(());
+            }
+            // Non-finite numbers propagate to the norm
+            if !S::is_finite(&r_ii) {
+                return Err(Failure::NonFinite(i));
+            }
+            if r_ii <= zero {
+                return Ok(());
+            }
+            r[i][i] = r_ii;
+            if tracking.health.is_some() {
+                tracking.record_r(S::log2(&r[i][i]));
+            }
+            self.kappa += 1;
+        }
+        Ok(())
+    }
 
-    while kappa < (d - num_zeros) {
-        size_reduce::<S>(basis, &mut gram, &mut mu, &mut r, &mut m, kappa, &eta_minus);
+    /// Runs one iteration of the reduction loop, and returns whether the loop goes on
+    fn iterate(
+        &mut self,
+        basis: &mut B,
+        tracking: &mut Tracking<S::Integer>,
+    ) -> Result<bool, Failure> {
+        let Self {
+            gram,
+            r,
+            mu,
+            s,
+            m,
+            householder,
+            eta_minus,
+            delta_plus,
+            borderline,
+            check,
+            num_zeros,
+            kappa,
+        } = self;
+        let d = basis.len();
+        let zero = S::Fraction::from(0);
 
-        s[0] = S::Fraction::from_ext((gram[kappa][kappa].clone(), S::Integer::from(1)));
-        for i in 0..kappa {
-            s[i + 1] = s[i].clone() - &(mu[kappa][i].clone() * &r[kappa][i]);
+        if *kappa >= d - *num_zeros {
+            if let Some(profile) = &mut tracking.profile {
+                *profile = (0..d - *num_zeros)
+                    .map(|i| S::log2(&r[i][i]) / 2.)
+                    .collect();
+            }
+            return Ok(false);
+        }
+        if S::overflowed() {
+            return Ok(false);
+        }
+        let progress = Progress {
+            kappa: *kappa,
+            dimension: d,
+            tour: tracking.tour,
+            work: tracking.work,
+        };
+        if tracking
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Err(Failure::Timeout(progress));
+        }
+        if tracking
+            .max_iterations
+            .is_some_and(|max| tracking.work >= max)
+        {
+            return Err(Failure::IterationLimit(progress));
+        }
+        if let Some(callback) = tracking.progress.as_deref_mut() {
+            callback(progress);
+        }
+        tracking.work += 1;
+        size_reduce::<S, B>(
+            basis,
+            gram,
+            mu,
+            r,
+            householder,
+            m,
+            *kappa,
+            eta_minus,
+            *check,
+            tracking,
+        )?;
+
+        if let Some(Householder { q, .. }) = householder {
+            // Squared norms of the projections of basis[kappa], from its coordinates
+            s[*kappa] = q[*kappa][*kappa].clone() * &q[*kappa][*kappa];
+            for i in (0..*kappa).rev() {
+                s[i] = s[i + 1].clone() + &(q[*kappa][i].clone() * &q[*kappa][i]);
+            }
+        } else {
+            s[0] = S::Fraction::from_ext((gram[*kappa][*kappa].clone(), S::Integer::from(1)));
+            for i in 0..*kappa {
+                s[i + 1] = s[i].clone() - &(mu[*kappa][i].clone() * &r[*kappa][i]);
+            }
+        }
+        // Non-finite numbers propagate to the last one
+        if !S::is_finite(&s[*kappa]) {
+            return Err(Failure::NonFinite(*kappa));
         }
 
-        let delta_criterion = |k| delta_plus.clone() * &r[k - 1][k - 1];
+        let delta_criterion = |k: usize| delta_plus.clone() * &r[k - 1][k - 1];
+
+        if let Some(health) = &mut tracking.health {
+            // Computed with the precision of the coefficients, whose logarithms are only doubles. Both sides are
+            // positive unless the vector is zero, which is not a borderline decision.
+            let difference = S::abs(delta_criterion(*kappa) - &s[*kappa - 1]);
+            if S::log2(&difference) - S::log2(&s[*kappa - 1]) < *borderline {
+                health.borderline_decisions += 1;
+            }
+        }
 
-        if delta_criterion(kappa) > s[kappa - 1] {
-            let kappa_prime = kappa;
+        if delta_criterion(*kappa) > s[*kappa - 1] {
+            let kappa_prime = *kappa;
 
-            let index = (1..kappa)
+            let index = (1..*kappa)
                 .rev()
                 .find(|&k| delta_criterion(k) < s[k - 1])
                 .unwrap_or(0);
@@ -64,31 +1026,59 @@ fn lattice_reduce<S: Scalar>(basis: &mut Matrix<S::Integer>, eta: f64, delta: f6
             let is_neg = s[index] <= zero;
 
             let k = if !is_neg {
-                kappa = index;
+                *kappa = index;
                 index
             } else {
-                num_zeros += 1;
-                kappa = kappa_prime;
-                d - num_zeros
+                *num_zeros += 1;
+                *kappa = kappa_prime;
+                d - *num_zeros
             };
 
             basis.insert(kappa_prime, k);
             mu.insert(kappa_prime, k);
             r.insert(kappa_prime, k);
+            if let Some(transform) = tracking.transform.as_deref_mut() {
+                transform.insert(kappa_prime, k);
+            }
+            if !is_neg && k + 1 == kappa_prime {
+                tracking.stats.swaps += 1;
+            } else if !is_neg {
+                tracking.stats.deep_insertions += 1;
+            }
+            if let Some(tracer) = tracking.tracer.as_deref_mut() {
+                if is_neg {
+                    tracer.zero_vector(kappa_prime, k);
+                } else if k + 1 == kappa_prime {
+                    tracer.swap(kappa_prime);
+                } else {
+                    tracer.insertion(kappa_prime, k);
+                }
+            }
 
             // Update Gram matrix
-            for i in 0..d {
-                for j in 0..=i {
-                    gram[i][j] = basis[i].dot(&basis[j]);
-                }
+            *gram = storage::gram(basis);
+            tracking.stats.gram_recomputations += 1;
+            if let Some(tracer) = tracking.tracer.as_deref_mut() {
+                tracer.gram_recompute(&gram.as_matrix());
             }
 
             if is_neg {
-                continue;
+                return Ok(true);
             }
+            // The reflections of the inserted vector are only valid for its previous position
+            if let Some(householder) = householder {
+                orthogonalize::<S, B>(*kappa, basis, householder, mu, r);
+            }
+        }
+        if *check && s[*kappa] <= zero {
+            return Err(Failure::Inconsistent);
         }
-        r[kappa][kappa] = s[kappa].clone();
-        kappa += 1;
+        r[*kappa][*kappa] = s[*kappa].clone();
+        if tracking.health.is_some() {
+            tracking.record_r(S::log2(&r[*kappa][*kappa]));
+        }
+        *kappa += 1;
+        Ok(true)
     }
 }
 
@@ -101,65 +1091,121 @@ fn lattice_reduce<S: Scalar>(basis: &mut Matrix<S::Integer>, eta: f64, delta: f6
 /// * `gram`: Gram matrix of `basis`  
 /// * `mu`: Gram coefficient matrix
 /// * `r`: the r_ij matrix
+/// * `householder`: the Householder orthogonalisation of `basis`, if it is used instead of `gram`
 /// * `eta`: eta factor of the basis reduction
+/// * `check`: whether to detect a size-reduction which does not converge
+/// * `tracking`: the transformation and the statistics updated by the operations on `basis`
 ///
-/// Note: both `basis` and `gram` are updated by this operation.
-fn size_reduce<S: Scalar>(
-    basis: &mut Matrix<S::Integer>,
-    gram: &mut Matrix<S::Integer>,
-    mu: &mut Matrix<S::Fraction>,
-    r: &mut Matrix<S::Fraction>,
-    m: &mut Vector<S::Fraction>,
+/// Note: both `basis` and `gram` are updated by this operation. The size-reduction stops at the first non-finite
+/// Gram-Schmidt coefficient, which would otherwise never be reduced.
+#[allow(clippy::too_many_arguments)]
+fn size_reduce<S: Scalar, B: Vectors<S::Integer>>(
+    basis: &mut B,
+    gram: &mut B::Square<S::Integer>,
+    mu: &mut B::Square<S::Fraction>,
+    r: &mut B::Square<S::Fraction>,
+    householder: &mut Option<Householder<S, B>>,
+    m: &mut B::Line<S::Fraction>,
     kappa: usize,
     eta: &S::Fraction,
-) {
+    check: bool,
+    tracking: &mut Tracking<S::Integer>,
+) -> Result<(), Failure> {
     let zero = S::Integer::from(0);
     let one = S::Integer::from(1);
+    let mut norm: Option<S::Integer> = None;
+    let mut stalled = 0;
+    // The factors of a size-reduction, only kept for the tracer
+    let mut factors = tracking.tracer.is_some().then(|| basis.line());
     loop {
-        cfa::<S>(kappa, basis, gram, mu, r);
+        match householder {
+            Some(householder) => orthogonalize::<S, B>(kappa, basis, householder, mu, r),
+            None => cfa::<S, B>(kappa, basis, gram, mu, r),
+        }
+        if S::overflowed() {
+            return Ok(());
+        }
+        if !(0..kappa).all(|i| S::is_finite(&mu[kappa][i])) {
+            return Err(Failure::NonFinite(kappa));
+        }
 
         let all_zeroes = (0..kappa)
             .rev()
             .all(|i| &S::abs(mu[kappa][i].clone()) < eta);
 
         if all_zeroes {
-            break;
+            return Ok(());
+        }
+
+        if let Some(health) = &mut tracking.health {
+            for i in 0..kappa {
+                let loss = S::log2(&S::abs(mu[kappa][i].clone()));
+                health.precision_loss = health.precision_loss.max(loss);
+            }
         }
 
         for i in 0..kappa {
             m[i] = mu[kappa][i].clone()
         }
 
+        let mut reduced = false;
         for i in (0..kappa).rev() {
             let x_i = S::round(&m[i]);
+            if let Some(factors) = &mut factors {
+                factors[i] = x_i.clone();
+            }
             if x_i != zero {
+                reduced = true;
                 for j in 0..i {
                     m[j] -=
                         &(mu[i][j].clone() * &S::Fraction::from_ext((x_i.clone(), one.clone())));
                 }
 
                 // Swap basis
-                basis[kappa] = basis[kappa].sub(&basis[i].mulf(x_i));
+                if let Some(transform) = tracking.transform.as_deref_mut() {
+                    transform.add_multiple(kappa, i, &(zero.clone() - &x_i));
+                }
+                basis.sub_multiple(kappa, i, &x_i);
+            }
+        }
+
+        if reduced {
+            tracking.stats.size_reductions += 1;
+            if let (Some(tracer), Some(factors)) = (tracking.tracer.as_deref_mut(), &factors) {
+                tracer.size_reduce(kappa, &factors.as_ref()[..kappa]);
             }
         }
 
         // Update Gram matrix
 
         for j in 0..=kappa {
-            gram[kappa][j] = basis[kappa].dot(&basis[j]);
+            gram[kappa][j] = storage::dot(basis.row(kappa), basis.row(j));
         }
-    }
-}
 
-fn cfa<S: Scalar>(
+        // The vector must change, and get shorter every few iterations
+        if check {
+            if norm.as_ref().is_none_or(|norm| gram[kappa][kappa] < *norm) {
+                norm = Some(gram[kappa][kappa].clone());
+                stalled = 0;
+            } else {
+                stalled += 1;
+            }
+            if !reduced || stalled > 3 {
+                return Err(Failure::Inconsistent);
+            }
+        }
+    }
+}
+
+fn cfa<S: Scalar, B: Vectors<S::Integer>>(
     i: usize,
-    basis: &mut Matrix<S::Integer>,
-    gram: &mut Matrix<S::Integer>,
-    mu: &mut Matrix<S::Fraction>,
-    r: &mut Matrix<S::Fraction>,
+    basis: &B,
+    gram: &mut B::Square<S::Integer>,
+    mu: &mut B::Square<S::Fraction>,
+    r: &mut B::Square<S::Fraction>,
 ) {
     for j in 0..=i {
-        gram[i][j] = basis[i].dot(&basis[j]);
+        gram[i][j] = storage::dot(basis.row(i), basis.row(j));
     }
 
     for j in 0..i {
@@ -172,23 +1218,627 @@ fn cfa<S: Scalar>(
     }
 }
 
+/// Householder orthogonalisation of `basis[kappa]`
+///
+/// Applies the reflections of the previous vectors to `basis[kappa]`, and computes its own reflection. The
+/// `kappa`-th rows of `mu` and `r` are filled as by [`cfa`], with `r[kappa][j] = q[kappa][j] q[j][j]`.
+fn orthogonalize<S: Scalar, B: Vectors<S::Integer>>(
+    kappa: usize,
+    basis: &B,
+    householder: &mut Householder<S, B>,
+    mu: &mut B::Square<S::Fraction>,
+    r: &mut B::Square<S::Fraction>,
+) {
+    let Householder { sqrt, v, q } = householder;
+    let n = basis.dimension();
+    let zero = S::Fraction::from(0);
+
+    // basis[kappa] is reflected in place of its own reflection, which is only computed at the end
+    for (k, c) in basis.row(kappa).iter().enumerate() {
+        v[kappa][k] = S::Fraction::from_ext(c);
+    }
+    for j in 0..kappa {
+        let dot: S::Fraction = (j..n).map(|k| v[j][k].clone() * &v[kappa][k]).sum();
+        for k in j..n {
+            let reflected = dot.clone() * &v[j][k];
+            v[kappa][k] -= &reflected;
+        }
+        q[kappa][j] = v[kappa][j].clone();
+        r[kappa][j] = v[kappa][j].clone() * &q[j][j];
+        mu[kappa][j] = v[kappa][j].clone() / &q[j][j];
+    }
+
+    // Reflection of x[kappa..] onto -sigma e_kappa, sigma having the sign of x[kappa] to avoid a cancellation
+    let x = &mut v[kappa];
+    let norm: S::Fraction = (kappa..n).map(|k| x[k].clone() * &x[k]).sum();
+    let mut sigma = sqrt(&norm);
+    if kappa < n && x[kappa] < zero {
+        sigma = zero.clone() - &sigma;
+    }
+    if norm > zero {
+        let scale = sqrt(&(sigma.clone() * &(sigma.clone() + &x[kappa])));
+        x[kappa] = x[kappa].clone() + &sigma;
+        for k in kappa..n {
+            x[k] = x[k].clone() / &scale;
+        }
+    } else {
+        for k in kappa..n {
+            x[k] = zero.clone();
+        }
+    }
+    for k in 0..kappa.min(n) {
+        x[k] = zero.clone();
+    }
+    q[kappa][kappa] = zero - &sigma;
+}
+
 /// Puts the trailing null columns at the beginning of the matrix
-fn zeros_first<S: Scalar>(basis: &mut Matrix<S::Integer>) {
+fn zeros_first<T: Coefficient, B: Vectors<T>>(
+    basis: &mut B,
+    mut transform: Option<&mut Transform<T>>,
+) {
+    let d = basis.len();
+    let zero = T::from(0);
+    for _ in 0..d {
+        if basis.row(d - 1).iter().any(|c| *c != zero) {
+            break;
+        }
+        basis.insert(d - 1, 0);
+        if let Some(transform) = transform.as_deref_mut() {
+            transform.insert(d - 1, 0);
+        }
+    }
+}
+
+pub(crate) fn reduction<S: Scalar>(basis: &mut Matrix<S::Integer>, eta: f64, delta: f64) {
+    // Backends which do not detect non-finite numbers do not fail
+    let _ = finite_reduction::<S, _>(
+        basis,
+        eta,
+        delta,
+        Orthogonalization::Cholesky,
+        &mut Tracking::default(),
+    );
+}
+
+/// Runs the reduction, and returns an error, leaving the basis and the transformation unchanged, if a
+/// Gram-Schmidt coefficient is not finite
+///
+/// Returns the statistics of the reduction, without the potential of the reduced basis. If the deadline passes
+/// or the maximum number of iterations is reached, the reduction stops there and `tracking.timeout` or
+/// `tracking.incomplete` is set.
+fn finite_reduction<S: Scalar, B: Vectors<S::Integer>>(
+    basis: &mut B,
+    eta: f64,
+    delta: f64,
+    gso: Orthogonalization<S::Fraction>,
+    tracking: &mut Tracking<S::Integer>,
+) -> Result<ReductionStats, NonFinite> {
+    let mut state = State::<S, B>::new(basis, eta, delta, gso, tracking);
+    while state.iterate(basis, tracking)? {}
+    Ok(tracking.stats)
+}
+
+/// State of a reduction, which goes on by one iteration of the reduction loop at a time
+///
+/// The reduction is done in two tours of the reduction loop of [`Lattice`], after which the zero vectors are put first.
+struct State<S: Scalar, B: Vectors<S::Integer>> {
+    eta: f64,
+    delta: f64,
+    gso: Orthogonalization<S::Fraction>,
+    /// The initial basis and transformation, restored on a non-finite Gram-Schmidt coefficient
+    initial: Option<(B, Option<Transform<S::Integer>>)>,
+    /// The initial tour, position and work, restored along with the basis
+    start: (usize, usize, usize),
+    /// The state of the current tour, once it started
+    lattice: Option<Lattice<S, B>>,
+    over: bool,
+}
+
+impl<S: Scalar, B: Vectors<S::Integer>> State<S, B> {
+    fn new(
+        basis: &B,
+        eta: f64,
+        delta: f64,
+        gso: Orthogonalization<S::Fraction>,
+        tracking: &Tracking<S::Integer>,
+    ) -> Self {
+        if let Some(transform) = &tracking.transform {
+            assert_eq!(transform.len(), basis.len());
+        }
+        // Only the backends with non-finite numbers can fail, and restore the initial basis
+        let initial =
+            S::NON_FINITE.then(|| (basis.clone(), tracking.transform.as_deref().cloned()));
+        Self {
+            eta,
+            delta,
+            gso,
+            initial,
+            start: (tracking.tour, tracking.kappa, tracking.work),
+            lattice: None,
+            over: false,
+        }
+    }
+
+    /// Runs one iteration of the reduction loop, and returns whether the reduction goes on
+    ///
+    /// Returns an error, leaving the basis and the transformation as they were initially, if a Gram-Schmidt
+    /// coefficient is not finite. If the deadline passes or the maximum number of iterations is reached, the
+    /// reduction stops until the next iteration and `tracking.timeout` or `tracking.incomplete` is set.
+    fn iterate(
+        &mut self,
+        basis: &mut B,
+        tracking: &mut Tracking<S::Integer>,
+    ) -> Result<bool, NonFinite> {
+        match self.advance(basis, tracking) {
+            Err(Failure::NonFinite(index)) => {
+                if let Some((initial_basis, initial_transform)) = &self.initial {
+                    *basis = initial_basis.clone();
+                    if let (Some(transform), Some(initial_transform)) =
+                        (tracking.transform.as_deref_mut(), initial_transform)
+                    {
+                        *transform = initial_transform.clone();
+                    }
+                }
+                (tracking.tour, tracking.kappa, tracking.work) = self.start;
+                self.lattice = None;
+                Err(NonFinite { index })
+            }
+            Err(Failure::Timeout(progress)) => {
+                tracking.timeout = Some(progress);
+                Ok(false)
+            }
+            Err(Failure::IterationLimit(progress)) => {
+                tracking.incomplete = Some(progress);
+                Ok(false)
+            }
+            // Only the checked tours are inconsistent, and they end there
+            Err(Failure::Inconsistent) => Ok(true),
+            Ok(more) => Ok(more),
+        }
+    }
+
+    fn advance(
+        &mut self,
+        basis: &mut B,
+        tracking: &mut Tracking<S::Integer>,
+    ) -> Result<bool, Failure> {
+        if self.over {
+            return Ok(false);
+        }
+        // A resumed reduction starts at its tour, and a complete one is not resumed
+        if tracking.tour >= 2 || basis.len() == 0 {
+            self.over = true;
+            if S::overflowed() {
+                return Ok(false);
+            }
+            zeros_first(basis, tracking.transform.as_deref_mut());
+            tracking.tour = 2;
+            if let Some(tracer) = tracking.tracer.as_deref_mut() {
+                tracer.done(&tracking.stats);
+            }
+            return Ok(false);
+        }
+        let Some(lattice) = &mut self.lattice else {
+            if let Some(tracer) = tracking.tracer.as_deref_mut() {
+                tracer.tour(tracking.tour);
+            }
+            self.lattice = Some(Lattice::new(
+                basis, self.eta, self.delta, false, self.gso, tracking,
+            )?);
+            return Ok(true);
+        };
+        match lattice.iterate(basis, tracking) {
+            // Without checks, the reduction only fails on non-finite numbers
+            Ok(false) | Err(Failure::Inconsistent) => {
+                self.lattice = None;
+                tracking.tour += 1;
+                Ok(true)
+            }
+            result => result,
+        }
+    }
+}
+
+/// The integer relations between the initial vectors of a reduction which gave the zero vectors of the reduced `basis`
+///
+/// `transform` is the transformation of the reduction, starting from the identity (see [`Reduction::transform`]).
+/// Returns one relation `r` per zero vector of the reduced basis: `sum_j r[j] b_j = 0` for the initial vectors
+/// `b_j`. After a complete reduction, the relations form a basis of the integer kernel, which is empty when the
+/// initial vectors are linearly independent.
+pub fn relations<T: Coefficient>(basis: &Matrix<T>, transform: &Transform<T>) -> Vec<Vector<T>> {
     let (d, _) = basis.dimensions();
-    while basis[d - 1].is_zero() {
-        basis.insert(d - 1, 0)
+    (0..d)
+        .filter(|&i| basis[i].is_zero())
+        .map(|i| transform.matrix()[i].clone())
+        .collect()
+}
+
+/// Logarithm (base 2) of the potential `prod_i ||b*_i||^(2 (d - i))` of the `d` non-zero vectors of `basis`
+fn log_potential<S: Scalar>(basis: &Matrix<S::Integer>) -> f64 {
+    let gso = Gso::<S>::compute(basis);
+    let zero = S::Fraction::from(0);
+    let r: Vec<&S::Fraction> = (0..gso.dimension())
+        .map(|i| &gso.r[i])
+        .filter(|&r_i| *r_i > zero)
+        .collect();
+    let d = r.len();
+    r.iter()
+        .enumerate()
+        .map(|(i, r_i)| (d - i) as f64 * S::log2(r_i))
+        .sum()
+}
+
+/// Reports of a reduction run by a [`Reducer`], each one only computed if it was requested from the [`Reduction`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// The numbers of operations of the reduction and the potential of the reduced basis (see [`Reduction::stats`])
+    pub stats: Option<ReductionStats>,
+    /// The logarithms (base 2) of the Gram-Schmidt norms `||b*_i||` of the non-zero reduced vectors, in basis order
+    /// (see [`Reduction::profile`])
+    pub profile: Option<Vec<f64>>,
+    /// The numerical health of the reduction (see [`Reduction::health`])
+    pub health: Option<Health>,
+}
+
+/// Options of a lattice reduction (L² algorithm), which can be combined
+///
+/// A `Reduction` is created from the factors of the reduction, and its options are set in method syntax. It then
+/// gives the [`Reducer`] of a basis with the arithmetic of a backend: [`Reduction::bignum`],
+/// [`Reduction::bigfloat`] or [`Reduction::float`].
+///
+/// ```rust
+/// use lll_rs::{l2::Reduction, prelude::*, Transform};
+///
+/// let mut basis: Matrix<f64> = Matrix::from_matrix(vec![
+///     vec![1., 0., 0., 1345.],
+///     vec![0., 1., 0., 35.],
+///     vec![0., 0., 1., 154.],
+/// ]);
+/// let mut transform = Transform::identity(3);
+/// let report = Reduction::new(LllParams::default())
+///     .transform(&mut transform)
+///     .stats()
+///     .float(&mut basis)
+///     .run()
+///     .unwrap();
+/// assert_eq!(basis[0].norm_sqr(), 119.);
+/// assert!(report.stats.unwrap().swaps > 0);
+/// ```
+pub struct Reduction<'a, T: Coefficient> {
+    params: LllParams,
+    gso: FloatGso,
+    stats: bool,
+    time_limit: Option<Duration>,
+    max_iterations: Option<usize>,
+    tracking: Tracking<'a, T>,
+}
+
+impl<'a, T: Coefficient> Reduction<'a, T> {
+    /// A reduction with the factors `params`, and no option
+    pub fn new(params: LllParams) -> Self {
+        Self {
+            params,
+            gso: FloatGso::default(),
+            stats: false,
+            time_limit: None,
+            max_iterations: None,
+            tracking: Tracking::default(),
+        }
+    }
+
+    /// Apply the operations on the basis vectors to `transform`, e.g. `Transform::identity(n)`
+    ///
+    /// Starting from the identity, the matrix `U` of the transformation is such that the reduced basis is `U * B`.
+    /// If `transform` tracks its inverse (see [`Transform::identity_with_inverse`]), `U^-1` is updated as well,
+    /// which is cheaper than inverting `U` afterwards. The integer relations between linearly dependent vectors
+    /// are then given by [`relations`].
+    ///
+    /// The reduction panics if `transform` does not have one vector per basis vector.
+    pub fn transform(mut self, transform: &'a mut Transform<T>) -> Self {
+        self.tracking.transform = Some(transform);
+        self
+    }
+
+    /// Report the [`Progress`] of the reduction to `callback`, before each iteration of the reduction loop
+    pub fn progress(mut self, callback: &'a mut dyn FnMut(Progress)) -> Self {
+        self.tracking.progress = Some(callback);
+        self
+    }
+
+    /// Pass the operations of the reduction to `tracer`, e.g. an [`EventSink`]
+    pub fn tracer(mut self, tracer: &'a mut dyn Tracer<T>) -> Self {
+        self.tracking.tracer = Some(tracer);
+        self
+    }
+
+    /// Stop each run of the reduction after the wall-clock time `time_limit`, with a [`Timeout`] error reporting how
+    /// far it got
+    ///
+    /// The time limit starts with [`Reducer::run`], or the first [`Reducer::step`]. The basis is then partially
+    /// reduced, and still spans the same lattice.
+    pub fn time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = Some(time_limit);
+        self
+    }
+
+    /// Stop each run of the reduction after `max_iterations` iterations of the reduction loop over all the tours
+    /// (see [`Progress`]), with an [`IterationLimit`] error reporting how far it got
+    ///
+    /// The basis is then partially reduced, and still spans the same lattice. This is a safety net against the
+    /// floating-point reductions which do not terminate.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Report the [`ReductionStats`] of the reduction
+    pub fn stats(mut self) -> Self {
+        self.stats = true;
+        self
+    }
+
+    /// Report the Gram-Schmidt profile of the reduced basis, as computed by the reduction
+    ///
+    /// The profile can be compared with [`estimate::gsa_profile`](crate::estimate::gsa_profile).
+    pub fn profile(mut self) -> Self {
+        self.tracking.profile = Some(Vec::new());
+        self
+    }
+
+    /// Report the [`Health`] of a floating-point reduction, which tells whether it should be run again with more
+    /// precision
+    pub fn health(mut self) -> Self {
+        self.tracking.health = Some(Health::default());
+        self
+    }
+
+    /// Computation of the Gram-Schmidt coefficients of [`Reduction::float`]
+    ///
+    /// The other backends use the Cholesky factorisation of the Gram matrix.
+    pub fn gso(mut self, gso: FloatGso) -> Self {
+        self.gso = gso;
+        self
+    }
+
+    /// Resume the reduction of `checkpoint`, whose basis is then the one passed to the backend
+    ///
+    /// The reduction must have the factors of the one which created the checkpoint (see [`Reducer::checkpoint`]).
+    pub fn resume(mut self, checkpoint: &Checkpoint<T>) -> Self {
+        self.tracking.tour = checkpoint.tour;
+        self.tracking.kappa = checkpoint.kappa;
+        self.tracking.work = checkpoint.work;
+        self
+    }
+
+    fn reducer<S: Scalar<Integer = T>>(
+        self,
+        basis: &'a mut Matrix<T>,
+        gso: Orthogonalization<S::Fraction>,
+    ) -> Reducer<'a, S> {
+        Reducer {
+            basis,
+            options: self,
+            gso,
+            state: None,
+            events: VecDeque::new(),
+            stopped: false,
+            #[cfg(feature = "rug")]
+            precision: None,
+        }
+    }
+}
+
+impl<T: Coefficient> Default for Reduction<'_, T> {
+    fn default() -> Self {
+        Self::new(LllParams::default())
     }
 }
 
-fn reduction<S: Scalar>(basis: &mut Matrix<S::Integer>, eta: f64, delta: f64) {
-    lattice_reduce::<S>(basis, eta, delta);
-    lattice_reduce::<S>(basis, eta, delta);
-    zeros_first::<S>(basis);
+#[cfg(feature = "rug")]
+impl<'a> Reduction<'a, rug::Integer> {
+    /// Reduce `basis` with `rug::Integer` and `rug::Rational` for the underlying arithmetic operations, as
+    /// [`lll_bignum`]
+    pub fn bignum(self, basis: &'a mut Matrix<rug::Integer>) -> Reducer<'a, BigNum> {
+        self.reducer(basis, Orthogonalization::Cholesky)
+    }
+
+    /// Reduce `basis` with `rug::Integer` and `rug::Float` of `precision` bits of mantissa for the underlying
+    /// arithmetic operations, as [`lll_bigfloat`]
+    ///
+    /// # Panics
+    /// if precision is out of the range supported by MPFR
+    pub fn bigfloat(
+        self,
+        basis: &'a mut Matrix<rug::Integer>,
+        precision: u32,
+    ) -> Reducer<'a, BigFloat> {
+        assert!(precision >= rug::float::prec_min() && precision <= rug::float::prec_max());
+        let mut reducer = self.reducer(basis, Orthogonalization::Cholesky);
+        reducer.precision = Some(precision);
+        reducer
+    }
+}
+
+impl<'a> Reduction<'a, f64> {
+    /// Reduce `basis` with platform double floating-point numbers (IEEE 754) for the underlying arithmetic
+    /// operations, as [`lll_float`], and the Gram-Schmidt coefficients of [`Reduction::gso`]
+    pub fn float(self, basis: &'a mut Matrix<f64>) -> Reducer<'a, Float> {
+        let gso = self.gso.with_sqrt::<Float>();
+        self.reducer(basis, gso)
+    }
+}
+
+/// Lattice reduction (L² algorithm) of a basis with the arithmetic `S`, and the options of a [`Reduction`]
+pub struct Reducer<'a, S: Scalar> {
+    basis: &'a mut Matrix<S::Integer>,
+    options: Reduction<'a, S::Integer>,
+    gso: Orthogonalization<S::Fraction>,
+    /// The state of the reduction, once it started
+    state: Option<State<S, Matrix<S::Integer>>>,
+    /// The operations of the last iteration which [`Reducer::step`] did not return yet
+    events: VecDeque<Event>,
+    /// Whether [`Reducer::step`] returned an error, which ends its iterator
+    stopped: bool,
+    /// The precision of the `BigFloat` backend, which is set while it runs
+    #[cfg(feature = "rug")]
+    precision: Option<u32>,
+}
+
+impl<S: Scalar> Reducer<'_, S> {
+    /// Run the reduction
+    ///
+    /// The basis is reduced in-place, as by [`lll_bignum`], and the reports requested from the [`Reduction`] are
+    /// returned. A reduction stopped by its time limit or its maximum number of iterations can be run again,
+    /// from where it stopped, with a new time limit and maximum number of iterations.
+    ///
+    /// Returns an error, and leaves the basis and the transformation unchanged, if a Gram-Schmidt coefficient of a
+    /// floating-point backend overflows or is not a number. Returns an error reporting how far the reduction got if
+    /// it exceeds its time limit or its maximum number of iterations.
+    pub fn run(&mut self) -> Result<Report, ReductionError> {
+        self.start();
+        #[cfg(feature = "rug")]
+        if let Some(precision) = self.precision {
+            return with_precision(precision, || self.reduce());
+        }
+        self.reduce()
+    }
+
+    /// Run the reduction until its next operation, and return it
+    ///
+    /// The operations are the events of [`Verbosity::PerSwap`], from the start of the first tour to the summary,
+    /// and are also passed to the tracer of the [`Reduction`], if any. The reduction goes on lazily: an iteration
+    /// of the reduction loop, which size-reduces a vector and inserts it, is only run once the operations of the
+    /// previous one are returned, and [`Reducer::basis`] is the basis after it.
+    ///
+    /// Returns `None` once the reduction is complete. Returns an error as [`Reducer::run`], after which the
+    /// reduction stops: a Gram-Schmidt coefficient which is not finite restores the initial basis.
+    pub fn step(&mut self) -> Option<Result<Event, ReductionError>> {
+        if self.state.is_none() {
+            self.start();
+        }
+        #[cfg(feature = "rug")]
+        if let Some(precision) = self.precision {
+            return with_precision(precision, || self.next_event());
+        }
+        self.next_event()
+    }
+
+    /// The basis, as reduced so far
+    pub fn basis(&self) -> &Matrix<S::Integer> {
+        self.basis
+    }
+
+    /// The state of the reduction, to resume it later with [`Reduction::resume`]
+    pub fn checkpoint(&self) -> Checkpoint<S::Integer> {
+        let tracking = &self.options.tracking;
+        let lattice = self.state.as_ref().and_then(|state| state.lattice.as_ref());
+        Checkpoint {
+            basis: self.basis.clone(),
+            tour: tracking.tour,
+            kappa: lattice.map_or(tracking.kappa, |lattice| lattice.kappa),
+            work: tracking.work,
+        }
+    }
+
+    /// Starts a run of the reduction, with the whole time limit and maximum number of iterations
+    fn start(&mut self) {
+        let Reduction {
+            time_limit,
+            max_iterations,
+            tracking,
+            ..
+        } = &mut self.options;
+        tracking.deadline = time_limit.and_then(deadline);
+        tracking.max_iterations = max_iterations.map(|max| tracking.work.saturating_add(max));
+    }
+
+    fn next_event(&mut self) -> Option<Result<Event, ReductionError>> {
+        let Reducer {
+            basis,
+            options: Reduction {
+                params, tracking, ..
+            },
+            gso,
+            state,
+            events,
+            stopped,
+            ..
+        } = self;
+        if *stopped {
+            return None;
+        }
+        let state = state.get_or_insert_with(|| {
+            State::new(*basis, params.eta(), params.delta(), *gso, tracking)
+        });
+        loop {
+            if let Some(event) = events.pop_front() {
+                return Some(Ok(event));
+            }
+            match tracking.queued(events, |tracking| state.iterate(*basis, tracking)) {
+                Ok(true) => {}
+                // The last operations are returned before the error, if any
+                Ok(false) => match events.pop_front() {
+                    Some(event) => return Some(Ok(event)),
+                    None => {
+                        *stopped = true;
+                        return tracking.interruption().map(Err);
+                    }
+                },
+                Err(error) => {
+                    // The basis is restored, and the operations undone
+                    events.clear();
+                    *stopped = true;
+                    return Some(Err(error.into()));
+                }
+            }
+        }
+    }
+
+    fn reduce(&mut self) -> Result<Report, ReductionError> {
+        let Reducer {
+            basis,
+            options:
+                Reduction {
+                    params,
+                    stats,
+                    tracking,
+                    ..
+                },
+            gso,
+            state,
+            ..
+        } = self;
+        let state = state.get_or_insert_with(|| {
+            State::new(*basis, params.eta(), params.delta(), *gso, tracking)
+        });
+        while state.iterate(*basis, tracking)? {}
+        if let Some(error) = tracking.interruption() {
+            return Err(error);
+        }
+        Ok(Report {
+            stats: stats.then(|| ReductionStats {
+                log_potential: log_potential::<S>(basis),
+                ..tracking.stats
+            }),
+            profile: tracking.profile.clone(),
+            health: tracking.health,
+        })
+    }
+}
+
+impl<S: Scalar> Iterator for Reducer<'_, S> {
+    type Item = Result<Event, ReductionError>;
+
+    /// The next operation of the reduction, as [`Reducer::step`]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.step()
+    }
 }
 
 /// Lattice reduction (L² algorithm)
 ///
-/// This implementation uses `rug::Integers` and `rug::Rationnal` for the underlying arithmetic operations.
+/// This implementation uses `rug::Integers` and `rug::Rational` for the underlying arithmetic operations.
 ///
 /// Arguments:
 ///  * basis: A generating matrix for the lattice
@@ -198,11 +1848,484 @@ fn reduction<S: Scalar>(basis: &mut Matrix<S::Integer>, eta: f64, delta: f64) {
 /// The basis is reduced in-place. The reduction is performed according to the standard pipeline of the fplll implementation of LLL.
 /// It is done by doing one extra LLL-reduction at the end and putting all the trailing null rows at the beginning
 ///
+/// Returns an error, and leaves the basis unchanged, if it is empty or its vectors do not have the same dimension
+/// (see [`Matrix::check_dimensions`]).
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+#[cfg(feature = "rug")]
+pub fn lll_bignum(
+    basis: &mut Matrix<rug::Integer>,
+    eta: f64,
+    delta: f64,
+) -> Result<(), InvalidBasis> {
+    basis.check_dimensions()?;
+    reduction::<BigNum>(basis, eta, delta);
+    Ok(())
+}
+
+/// Lattice reduction (L² algorithm) of a basis with rational entries
+///
+/// The denominators are cleared by scaling the basis by their least common multiple, which is then reduced with
+/// [`lll_bignum`] and scaled back: the result is a reduced basis of the original lattice, in the original
+/// coordinates.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place. Returns an error, and leaves it unchanged, if it is invalid, as [`lll_bignum`].
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+#[cfg(feature = "rug")]
+pub fn lll_rational(
+    basis: &mut Matrix<rug::Rational>,
+    eta: f64,
+    delta: f64,
+) -> Result<(), InvalidBasis> {
+    basis.check_dimensions()?;
+    let (n, dim) = basis.dimensions();
+    let mut scale = rug::Integer::from(1);
+    for i in 0..n {
+        for j in 0..dim {
+            scale.lcm_mut(basis[i][j].denom());
+        }
+    }
+
+    let mut scaled: Matrix<rug::Integer> = Matrix::init(n, dim);
+    for i in 0..n {
+        for j in 0..dim {
+            let entry = rug::Rational::from(&basis[i][j] * &scale);
+            scaled[i][j] = entry.into_numer_denom().0;
+        }
+    }
+    lll_bignum(&mut scaled, eta, delta)?;
+
+    for i in 0..n {
+        for j in 0..dim {
+            basis[i][j] = rug::Rational::from((scaled[i][j].clone(), scale.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses `rug::Integers` and `rug::Float` for the underlying arithmetic operations,
+/// with `precision` bits of mantissa. This is useful for bases whose entries overflow double floating-point
+/// numbers, or for which their 53 bits of precision are not sufficient, but where exact rationals are too slow.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///  * precision: precision in bits of the floating-point numbers
+///
+/// The basis is reduced in-place. Returns an error, and leaves it unchanged, if it is invalid, as [`lll_bignum`].
+///
+/// Returns an error, and leaves the basis unchanged, if a Gram-Schmidt coefficient overflows or is not a number,
+/// which happens when the entries of the basis are too large for the exponents of MPFR.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)  
+/// if precision is out of the range supported by MPFR
+#[cfg(feature = "rug")]
+pub fn lll_bigfloat(
+    basis: &mut Matrix<rug::Integer>,
+    eta: f64,
+    delta: f64,
+    precision: u32,
+) -> Result<(), ReductionError> {
+    assert!(precision >= rug::float::prec_min() && precision <= rug::float::prec_max());
+    basis.check_dimensions()?;
+    with_precision(precision, || {
+        finite_reduction::<BigFloat, _>(
+            basis,
+            eta,
+            delta,
+            Orthogonalization::Cholesky,
+            &mut Tracking::default(),
+        )
+    })?;
+    Ok(())
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses `rug::Integers` and double-double floating-point numbers (the unevaluated sum of two
+/// `f64`) for the underlying arithmetic operations. Their 106 bits of precision suffice for lattices of larger
+/// dimension than double floating-point numbers, at a fraction of the cost of `rug::Float`.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place. Returns an error, and leaves it unchanged, if it is invalid, as [`lll_bignum`].
+///
 /// # Panics
 /// if delta <= 1/4 or delta >= 1  
 /// if eta <= 1/2 or eta > sqrt(delta)
-pub fn lll_bignum(basis: &mut Matrix<rug::Integer>, eta: f64, delta: f64) {
-    reduction::<BigNum>(basis, eta, delta)
+#[cfg(feature = "rug")]
+pub fn lll_doubledouble(
+    basis: &mut Matrix<rug::Integer>,
+    eta: f64,
+    delta: f64,
+) -> Result<(), InvalidBasis> {
+    basis.check_dimensions()?;
+    reduction::<BigDoubleDouble>(basis, eta, delta);
+    Ok(())
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses `rug::Integers` and intervals of double floating-point numbers for the underlying
+/// arithmetic operations. Whenever an interval is too wide to decide a comparison or a rounding, the exact
+/// value is computed with `rug::Rational`: the result is the same as with [`lll_bignum`], usually at the cost
+/// of floating-point arithmetic.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place. Returns an error, and leaves it unchanged, if it is invalid, as [`lll_bignum`].
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+#[cfg(feature = "rug")]
+pub fn lll_certified(
+    basis: &mut Matrix<rug::Integer>,
+    eta: f64,
+    delta: f64,
+) -> Result<(), InvalidBasis> {
+    basis.check_dimensions()?;
+    reduction::<Certified>(basis, eta, delta);
+    Ok(())
+}
+
+/// Arithmetic of the Gram-Schmidt coefficients
+#[cfg(feature = "rug")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Arithmetic {
+    /// Double floating-point numbers
+    Double,
+    /// Double-double floating-point numbers (about 106 bits)
+    DoubleDouble,
+    /// `rug::Float` with the given precision in bits
+    Mpfr(u32),
+    /// Exact rationals (`rug::Rational`)
+    Rational,
+}
+
+#[cfg(feature = "rug")]
+impl Arithmetic {
+    fn lattice_reduce(
+        self,
+        basis: &mut Matrix<rug::Integer>,
+        eta: f64,
+        delta: f64,
+        check: bool,
+        tracking: &mut Tracking<rug::Integer>,
+    ) -> Result<(), Failure> {
+        match self {
+            Arithmetic::Double => lattice_reduce::<BigDouble, _>(
+                basis,
+                eta,
+                delta,
+                check,
+                Orthogonalization::Cholesky,
+                tracking,
+            ),
+            Arithmetic::DoubleDouble => lattice_reduce::<BigDoubleDouble, _>(
+                basis,
+                eta,
+                delta,
+                check,
+                Orthogonalization::Cholesky,
+                tracking,
+            ),
+            Arithmetic::Mpfr(precision) => {
+                assert!(precision >= rug::float::prec_min() && precision <= rug::float::prec_max());
+                with_precision(precision, || {
+                    lattice_reduce::<BigFloat, _>(
+                        basis,
+                        eta,
+                        delta,
+                        check,
+                        Orthogonalization::Cholesky,
+                        tracking,
+                    )
+                })
+            }
+            Arithmetic::Rational => lattice_reduce::<BigNum, _>(
+                basis,
+                eta,
+                delta,
+                check,
+                Orthogonalization::Cholesky,
+                tracking,
+            ),
+        }
+    }
+}
+
+/// Options of the L² reduction
+#[cfg(feature = "rug")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Options {
+    /// eta factor of the basis reduction
+    pub eta: f64,
+    /// delta factor of the basis reduction
+    pub delta: f64,
+    /// Arithmetics tried in turn: when a stage of the reduction fails numerically, it is restarted from its
+    /// initial basis with the next arithmetic. The last one is trusted.
+    pub escalation: Vec<Arithmetic>,
+    /// Diagnostic events passed to the sink of [`lll_with_options_and_trace`]
+    pub verbosity: Verbosity,
+}
+
+#[cfg(feature = "rug")]
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            eta: 0.51,
+            delta: 0.99,
+            escalation: vec![
+                Arithmetic::Double,
+                Arithmetic::Mpfr(128),
+                Arithmetic::Rational,
+            ],
+            verbosity: Verbosity::Silent,
+        }
+    }
+}
+
+/// Lattice reduction (L² algorithm) with automatic precision escalation
+///
+/// This implementation uses `rug::Integers` for the basis, and the arithmetics of `options.escalation` for the
+/// Gram-Schmidt coefficients. Each stage of the reduction starts with the first arithmetic; when it detects a
+/// numerical failure (a non-positive `r_ii`, or a size-reduction which does not converge), the stage is restarted
+/// with the next one instead of producing an incorrect basis.
+///
+/// The basis is reduced in-place.
+///
+/// Returns the most precise arithmetic which was needed, or an error, leaving the basis unchanged, if the basis is
+/// invalid (as [`lll_bignum`]) or if a Gram-Schmidt coefficient of the last arithmetic is not finite.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)  
+/// if `options.escalation` is empty, or contains a precision out of the range supported by MPFR
+#[cfg(feature = "rug")]
+pub fn lll_with_options(
+    basis: &mut Matrix<rug::Integer>,
+    options: &Options,
+) -> Result<Arithmetic, ReductionError> {
+    lll_with_options_and_trace(basis, options, |_| {})
+}
+
+/// Lattice reduction (L² algorithm) with automatic precision escalation, passing its diagnostic events to a sink
+///
+/// The reduction is the one of [`lll_with_options`]. The events of `options.verbosity` are passed to `trace`,
+/// including the operations of the stages which were restarted with a more precise arithmetic.
+///
+/// Returns the most precise arithmetic which was needed, or an error, leaving the basis unchanged, if the basis is
+/// invalid (as [`lll_bignum`]) or if a Gram-Schmidt coefficient of the last arithmetic is not finite.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)  
+/// if `options.escalation` is empty, or contains a precision out of the range supported by MPFR
+#[cfg(feature = "rug")]
+pub fn lll_with_options_and_trace(
+    basis: &mut Matrix<rug::Integer>,
+    options: &Options,
+    trace: impl FnMut(Event),
+) -> Result<Arithmetic, ReductionError> {
+    basis.check_dimensions()?;
+    let (last, arithmetics) = options
+        .escalation
+        .split_last()
+        .expect("The escalation policy must not be empty");
+    let mut sink = EventSink::new(options.verbosity, trace);
+    let mut tracking = Tracking {
+        tracer: Some(&mut sink),
+        ..Tracking::default()
+    };
+
+    let original = basis.clone();
+    let mut needed = 0;
+    for tour in 0..2 {
+        tracking.tour = tour;
+        if let Some(tracer) = tracking.tracer.as_deref_mut() {
+            tracer.tour(tour);
+        }
+        let initial = basis.clone();
+        let mut stage = arithmetics.len();
+        for (i, arithmetic) in arithmetics.iter().enumerate() {
+            if arithmetic
+                .lattice_reduce(basis, options.eta, options.delta, true, &mut tracking)
+                .is_ok()
+            {
+                stage = i;
+                break;
+            }
+            *basis = initial.clone();
+        }
+        if stage == arithmetics.len() {
+            // Without checks, the reduction only fails on non-finite numbers
+            if let Err(Failure::NonFinite(index)) =
+                last.lattice_reduce(basis, options.eta, options.delta, false, &mut tracking)
+            {
+                *basis = original;
+                return Err(NonFinite { index }.into());
+            }
+        }
+        needed = needed.max(stage);
+    }
+    zeros_first(basis, None);
+    if let Some(tracer) = tracking.tracer.as_deref_mut() {
+        tracer.done(&tracking.stats);
+    }
+    Ok(options.escalation[needed])
+}
+
+/// Floating-point precision (in bits) which provably suffices for the L² algorithm
+///
+/// The L² algorithm is guaranteed to output a `(delta, eta)`-reduced basis of a `dimension`-dimensional lattice
+/// with a precision of `d log2(ρ) + o(d)` bits, where `ρ = (1 + eta)² / (delta - eta²)` [NS09, Theorem 2]. This
+/// returns `d log2(ρ') + 2 log2(d) + log2(100)`, where `ρ'` is `ρ` with `(1 + eta)²` increased by `0.01`,
+/// as in fplll. The precision does not depend on the size of the entries, which only determines the exponent
+/// range of the floating-point numbers.
+///
+/// [NS09]: P. Q. Nguyen and D. Stehlé. An LLL Algorithm with Quadratic Complexity. SIAM J. Comput. 2009.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+pub fn required_precision(dimension: usize, eta: f64, delta: f64) -> u32 {
+    assert!(0.25 < delta && delta < 1.);
+    assert!(0.5 < eta && eta * eta < delta);
+
+    let epsilon = 0.01;
+    let rho = ((1. + eta).powi(2) + epsilon) / (delta - eta * eta);
+    let d = dimension.max(1) as f64;
+    (d * rho.log2() + 2. * d.log2() - epsilon.log2()).ceil() as u32
+}
+
+/// Lattice reduction (L² algorithm) with a provably sufficient precision
+///
+/// This implementation uses `rug::Integers` for the basis, and double floating-point numbers for the Gram-Schmidt
+/// coefficients when their precision and exponent range provably suffice (see [`required_precision`]), or
+/// `rug::Float` with the required precision otherwise.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place.
+///
+/// Returns the arithmetic which was used, or an error, leaving the basis unchanged, if the basis is invalid (as
+/// [`lll_bignum`]) or if a Gram-Schmidt coefficient is not finite.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+#[cfg(feature = "rug")]
+pub fn lll_auto(
+    basis: &mut Matrix<rug::Integer>,
+    eta: f64,
+    delta: f64,
+) -> Result<Arithmetic, ReductionError> {
+    basis.check_dimensions()?;
+    let (n, dim) = basis.dimensions();
+    let bits = basis.max_bits();
+    let precision = required_precision(n, eta, delta);
+
+    // The entries of the Gram matrix have at most 2 bits + log2(dim) bits
+    let gram_bits = 2 * bits + (dim as u64 + 1).ilog2() as u64 + 1;
+    let arithmetic = if precision <= f64::MANTISSA_DIGITS && gram_bits < f64::MAX_EXP as u64 {
+        Arithmetic::Double
+    } else {
+        Arithmetic::Mpfr(precision.max(f64::MANTISSA_DIGITS))
+    };
+    let options = Options {
+        eta,
+        delta,
+        escalation: vec![arithmetic],
+        ..Options::default()
+    };
+    lll_with_options(basis, &options)
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses `num_bigint::BigInt` and `num_rational::BigRational` for the underlying arithmetic operations.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place. Returns an error, and leaves it unchanged, if it is invalid, as [`lll_bignum`].
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+#[cfg(feature = "num")]
+pub fn lll_num_bigint(
+    basis: &mut Matrix<num_bigint::BigInt>,
+    eta: f64,
+    delta: f64,
+) -> Result<(), InvalidBasis> {
+    basis.check_dimensions()?;
+    reduction::<NumBigInt>(basis, eta, delta);
+    Ok(())
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses any integer type implementing the standard `num-traits` traits (see [`NumInteger`]),
+/// and platform double floating-point numbers (IEEE 754) for the underlying arithmetic operations.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place.
+///
+/// Returns an error, and leaves the basis unchanged, if it is invalid (as [`lll_bignum`]), or if an entry or an
+/// intermediate integer (e.g. an entry of the Gram matrix) cannot be represented by a double floating-point number.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+#[cfg(feature = "num-traits")]
+pub fn lll_num_traits<T: NumInteger + Coefficient>(
+    basis: &mut Matrix<T>,
+    eta: f64,
+    delta: f64,
+) -> Result<(), ReductionError> {
+    basis.check_dimensions()?;
+    let mut wrapped = basis.map(|n| Num(n.clone()));
+    finite_reduction::<NumTraits<T>, _>(
+        &mut wrapped,
+        eta,
+        delta,
+        Orthogonalization::Cholesky,
+        &mut Tracking::default(),
+    )?;
+    *basis = wrapped.map(|n| n.0.clone());
+    Ok(())
 }
 
 /// Lattice reduction (L² algorithm)
@@ -218,9 +2341,407 @@ pub fn lll_bignum(basis: &mut Matrix<rug::Integer>, eta: f64, delta: f64) {
 /// The basis is reduced in-place. The reduction is performed according to the standard pipeline of the fplll implementation of LLL.
 /// It is done by doing one extra LLL-reduction at the end and putting all the trailing null rows at the beginning
 ///
+/// Returns an error, and leaves the basis unchanged, if it is empty or its vectors do not have the same dimension
+/// (see [`Matrix::check_dimensions`]).
+///
+/// Returns an error, and leaves the basis unchanged, if a Gram-Schmidt coefficient overflows or is not a number,
+/// which happens when the entries of the basis are too large.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+pub fn lll_float(basis: &mut Matrix<f64>, eta: f64, delta: f64) -> Result<(), ReductionError> {
+    basis.check_dimensions()?;
+    finite_reduction::<Float, _>(
+        basis,
+        eta,
+        delta,
+        Orthogonalization::Cholesky,
+        &mut Tracking::default(),
+    )?;
+    Ok(())
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses platform single floating-point numbers (IEEE 754)
+/// for the underlying arithmetic operations. Their 24 bits of precision only suffice for
+/// small lattices with small entries.
+///
+/// Arguments:
+///  * basis: The basis vectors of the lattice, which must have the same dimension
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place.
+///
+/// The basis vectors are given as a slice since `f32` does not provide the operations of a `Matrix` entry:
+/// they are wrapped into [`Single`] numbers for the reduction.
+///
+/// Returns an error, and leaves the basis unchanged, if it is invalid (as [`lll_float`]), or if a Gram-Schmidt
+/// coefficient overflows or is not a number, which happens when the entries of the basis are too large.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+pub fn lll_float32(basis: &mut [Vec<f32>], eta: f64, delta: f64) -> Result<(), ReductionError> {
+    let mut single = Matrix::from_matrix(
+        basis
+            .iter()
+            .map(|v| v.iter().map(|&f| Single(f)).collect())
+            .collect(),
+    );
+    single.check_dimensions()?;
+    finite_reduction::<Float32, _>(
+        &mut single,
+        eta,
+        delta,
+        Orthogonalization::Cholesky,
+        &mut Tracking::default(),
+    )?;
+    for (i, v) in basis.iter_mut().enumerate() {
+        for (j, f) in v.iter_mut().enumerate() {
+            *f = single[i][j].0;
+        }
+    }
+    Ok(())
+}
+
+/// Runs the reduction on a copy of `basis` with checked arithmetic, and writes it back if no overflow occurred
+fn checked_reduction<S, T>(
+    basis: &mut Matrix<T>,
+    eta: f64,
+    delta: f64,
+) -> Result<(), ReductionError>
+where
+    S: Scalar<Integer = Checked<T>>,
+    T: Coefficient + Copy,
+    Checked<T>: Coefficient,
+{
+    basis.check_dimensions()?;
+    let mut checked = basis.map(|&n| Checked(n));
+    with_overflow_check(|| reduction::<S>(&mut checked, eta, delta))?;
+    *basis = checked.map(|n| n.0);
+    Ok(())
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses `i64` integers with checked arithmetic and platform double floating-point numbers
+/// (IEEE 754) for the underlying arithmetic operations.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place.
+///
+/// Returns an error, and leaves the basis unchanged, if it is invalid (as [`lll_float`]), or if an intermediate
+/// integer (e.g. an entry of the Gram matrix) does not fit in an `i64`.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+pub fn lll_i64(basis: &mut Matrix<i64>, eta: f64, delta: f64) -> Result<(), ReductionError> {
+    checked_reduction::<CheckedI64, i64>(basis, eta, delta)
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses `i128` integers with checked arithmetic and platform double floating-point numbers
+/// (IEEE 754) for the underlying arithmetic operations.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place.
+///
+/// Returns an error, and leaves the basis unchanged, if it is invalid (as [`lll_float`]), or if an intermediate
+/// integer (e.g. an entry of the Gram matrix) does not fit in an `i128`.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+pub fn lll_i128(basis: &mut Matrix<i128>, eta: f64, delta: f64) -> Result<(), ReductionError> {
+    checked_reduction::<CheckedI128, i128>(basis, eta, delta)
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses `i64` integers with checked arithmetic and fixed-point numbers with `FRAC` fractional
+/// bits (stored in an `i128`) for the underlying arithmetic operations. It does not use any floating-point
+/// operation besides the conversion of `eta` and `delta`, so that the result is the same on every platform.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place.
+///
+/// Returns an error, and leaves the basis unchanged, if it is invalid (as [`lll_float`]), if an intermediate integer
+/// does not fit in an `i64`, or if an intermediate fixed-point number does not fit in an `i128`.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)  
+/// if FRAC is not between 1 and 63
+pub fn lll_fixed<const FRAC: u32>(
+    basis: &mut Matrix<i64>,
+    eta: f64,
+    delta: f64,
+) -> Result<(), ReductionError> {
+    assert!(0 < FRAC && FRAC < 64);
+    checked_reduction::<FixedPoint<FRAC>, i64>(basis, eta, delta)
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses fixed-width `crypto_bigint::Int<LIMBS>` integers with checked arithmetic and platform
+/// double floating-point numbers (IEEE 754) for the underlying arithmetic operations. The integers are not
+/// allocated on the heap.
+///
+/// Arguments:
+///  * basis: The basis vectors of the lattice, which must have the same dimension
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place.
+///
+/// The basis vectors are given as a slice since `Int<LIMBS>` does not provide the operations of a `Matrix` entry:
+/// they are wrapped into checked integers for the reduction.
+///
+/// Returns an error, and leaves the basis unchanged, if it is invalid (as [`lll_float`]), or if an intermediate
+/// integer (e.g. an entry of the Gram matrix) does not fit in an `Int<LIMBS>`.
+///
 /// # Panics
 /// if delta <= 1/4 or delta >= 1  
 /// if eta <= 1/2 or eta > sqrt(delta)
-pub fn lll_float(basis: &mut Matrix<f64>, eta: f64, delta: f64) {
-    reduction::<Float>(basis, eta, delta)
+#[cfg(feature = "crypto-bigint")]
+pub fn lll_crypto_bigint<const LIMBS: usize>(
+    basis: &mut [Vec<crypto_bigint::Int<LIMBS>>],
+    eta: f64,
+    delta: f64,
+) -> Result<(), ReductionError> {
+    let mut checked = Matrix::from_matrix(
+        basis
+            .iter()
+            .map(|v| v.iter().map(|&n| Checked(n)).collect())
+            .collect(),
+    );
+    checked.check_dimensions()?;
+    with_overflow_check(|| reduction::<FixedInt<LIMBS>>(&mut checked, eta, delta))?;
+    for (i, v) in basis.iter_mut().enumerate() {
+        for (j, n) in v.iter_mut().enumerate() {
+            *n = checked[i][j].0;
+        }
+    }
+    Ok(())
+}
+
+/// Error returned when the factors of a reduction are out of range
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParamError {
+    /// `delta` is not in `(1/4, 1)`
+    Delta(f64),
+    /// `eta` is not in `(1/2, sqrt(delta))`
+    Eta(f64),
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Delta(delta) => write!(f, "delta = {} is not in (1/4, 1)", delta),
+            ParamError::Eta(eta) => write!(f, "eta = {} is not in (1/2, sqrt(delta))", eta),
+        }
+    }
+}
+
+impl error::Error for ParamError {}
+
+/// Factors `delta` and `eta` of an LLL reduction, checked to satisfy `1/4 < delta < 1` and `1/2 < eta < sqrt(delta)`
+///
+/// The reduced basis satisfies the Lovász condition with `delta`, and is size-reduced with `eta`: the closer
+/// `delta` is to 1 and `eta` to 1/2, the stronger (and slower) the reduction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LllParams {
+    delta: f64,
+    eta: f64,
+}
+
+impl LllParams {
+    /// Check the factors of a reduction
+    pub fn new(delta: f64, eta: f64) -> Result<Self, ParamError> {
+        if !(0.25 < delta && delta < 1.) {
+            return Err(ParamError::Delta(delta));
+        }
+        if !(0.5 < eta && eta * eta < delta) {
+            return Err(ParamError::Eta(eta));
+        }
+        Ok(Self { delta, eta })
+    }
+
+    /// The default factors of fplll, `delta = 0.99` and `eta = 0.51`
+    pub fn fplll_default() -> Self {
+        Self {
+            delta: 0.99,
+            eta: 0.51,
+        }
+    }
+
+    /// The strongest factors which the double floating-point reductions handle reliably, `delta = 0.999` and
+    /// `eta = 0.501`
+    pub fn strongest() -> Self {
+        Self {
+            delta: 0.999,
+            eta: 0.501,
+        }
+    }
+
+    /// Factor of the Lovász condition
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+
+    /// Factor of the size-reduction
+    pub fn eta(&self) -> f64 {
+        self.eta
+    }
+}
+
+impl Default for LllParams {
+    fn default() -> Self {
+        Self::fplll_default()
+    }
+}
+
+/// Lattice reductions in method syntax: `basis.lll()`, `basis.l2(eta, delta)` or `basis.reduce(params)`
+///
+/// The reduction is the L² function of the type of the entries (e.g. [`lll_bignum`] for `Matrix<rug::Integer>`
+/// and [`lll_float`] for `Matrix<f64>`), which reduces the basis in-place, or a copy of it with
+/// [`reduced`](LatticeReduce::reduced). The basis is checked first, as by the L² functions: an empty basis, or
+/// basis vectors of different dimensions, are reported as an error instead of a panic inside the reduction. The
+/// reductions of a [`Basis`], checked once at its construction, fail on the backend errors only.
+pub trait LatticeReduce: Sized {
+    /// Error of a failed reduction, which leaves the basis unchanged
+    type Error: From<InvalidBasis>;
+
+    /// Lattice reduction (L² algorithm) with checked factors
+    fn reduce(&mut self, params: LllParams) -> Result<(), Self::Error>;
+
+    /// Lattice reduction (L² algorithm) with the default factors of fplll (see [`LllParams::fplll_default`])
+    fn lll(&mut self) -> Result<(), Self::Error> {
+        self.reduce(LllParams::fplll_default())
+    }
+
+    /// Lattice reduction (L² algorithm) with the factors `eta` and `delta`
+    ///
+    /// # Panics
+    /// if delta <= 1/4 or delta >= 1  
+    /// if eta <= 1/2 or eta > sqrt(delta)
+    fn l2(&mut self, eta: f64, delta: f64) -> Result<(), Self::Error> {
+        self.reduce(LllParams::new(delta, eta).expect("The factors must be valid"))
+    }
+
+    /// Return the reduced basis, leaving `self` unchanged
+    fn reduced(&self, params: LllParams) -> Result<Self, Self::Error>
+    where
+        Self: Clone,
+    {
+        let mut basis = self.clone();
+        basis.reduce(params)?;
+        Ok(basis)
+    }
+}
+
+/// Lattice reduction (L² algorithm) of many independent bases, in parallel
+///
+/// The bases are reduced in-place with `params` by a pool of threads, one per available CPU, each taking the next
+/// basis to reduce as soon as it is done with the previous one: bases of very different sizes keep all the threads
+/// busy.
+///
+/// Returns the result of the reduction of each basis, in order.
+pub fn reduce_all<M>(bases: &mut [M], params: LllParams) -> Vec<Result<(), M::Error>>
+where
+    M: LatticeReduce + Send,
+    M::Error: Send,
+{
+    let threads = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(bases.len());
+    let mut results: Vec<Option<Result<(), M::Error>>> =
+        iter::repeat_with(|| None).take(bases.len()).collect();
+    let queue = Mutex::new(bases.iter_mut().zip(results.iter_mut()));
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                // The lock is released before the reduction
+                let next = queue.lock().expect("A reduction panicked").next();
+                match next {
+                    Some((basis, result)) => *result = Some(basis.reduce(params)),
+                    None => break,
+                }
+            });
+        }
+    });
+    results
+        .into_iter()
+        .map(|result| result.expect("Every basis is reduced"))
+        .collect()
+}
+
+impl<T: Coefficient> LatticeReduce for Basis<T>
+where
+    Matrix<T>: LatticeReduce,
+{
+    type Error = <Matrix<T> as LatticeReduce>::Error;
+
+    fn reduce(&mut self, params: LllParams) -> Result<(), Self::Error> {
+        self.matrix_mut().reduce(params)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl LatticeReduce for Matrix<rug::Integer> {
+    type Error = InvalidBasis;
+
+    fn reduce(&mut self, params: LllParams) -> Result<(), InvalidBasis> {
+        lll_bignum(self, params.eta(), params.delta())
+    }
+}
+
+#[cfg(feature = "num")]
+impl LatticeReduce for Matrix<num_bigint::BigInt> {
+    type Error = InvalidBasis;
+
+    fn reduce(&mut self, params: LllParams) -> Result<(), InvalidBasis> {
+        lll_num_bigint(self, params.eta(), params.delta())
+    }
+}
+
+impl LatticeReduce for Matrix<f64> {
+    type Error = ReductionError;
+
+    fn reduce(&mut self, params: LllParams) -> Result<(), ReductionError> {
+        lll_float(self, params.eta(), params.delta())
+    }
+}
+
+impl LatticeReduce for Matrix<i64> {
+    type Error = ReductionError;
+
+    fn reduce(&mut self, params: LllParams) -> Result<(), ReductionError> {
+        lll_i64(self, params.eta(), params.delta())
+    }
+}
+
+impl LatticeReduce for Matrix<i128> {
+    type Error = ReductionError;
+
+    fn reduce(&mut self, params: LllParams) -> Result<(), ReductionError> {
+        lll_i128(self, params.eta(), params.delta())
+    }
 }
@@ -0,0 +1,80 @@
+//! Lattice reduction (L² algorithm) of bases whose dimensions are known at compile time
+//!
+//! The basis is a [`SMatrix`], and the Gram matrix and the Gram-Schmidt coefficients are stored on the stack as
+//! well: these reductions do not allocate, which matters when reducing many small lattices.
+
+use super::{finite_reduction, NonFinite, Orthogonalization, Tracking};
+use crate::algebra::{with_overflow_check, Checked, CheckedI64, Float, Overflow, SMatrix, Scalar};
+
+/// Runs the shared reduction on the vectors of a `SMatrix`, which are reduced without heap allocation
+fn reduction<S: Scalar, const R: usize, const C: usize>(
+    basis: &mut [[S::Integer; C]; R],
+    eta: f64,
+    delta: f64,
+) -> Result<(), NonFinite> {
+    finite_reduction::<S, _>(
+        basis,
+        eta,
+        delta,
+        Orthogonalization::Cholesky,
+        &mut Tracking::default(),
+    )
+    .map(|_| ())
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses platform double floating-point numbers (IEEE 754) for the underlying arithmetic
+/// operations, like `l2::lll_float`, without heap allocation.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place.
+///
+/// Returns an error, and leaves the basis unchanged, if a Gram-Schmidt coefficient overflows or is not a number,
+/// which happens when the entries of the basis are too large.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+pub fn lll_float<const R: usize, const C: usize>(
+    basis: &mut SMatrix<f64, R, C>,
+    eta: f64,
+    delta: f64,
+) -> Result<(), NonFinite> {
+    reduction::<Float, R, C>(&mut basis.vectors, eta, delta)
+}
+
+/// Lattice reduction (L² algorithm)
+///
+/// This implementation uses `i64` integers with checked arithmetic and platform double floating-point numbers
+/// (IEEE 754) for the underlying arithmetic operations, like `l2::lll_i64`, without heap allocation.
+///
+/// Arguments:
+///  * basis: A generating matrix for the lattice
+///  * eta: eta factor of the basis reduction
+///  * delta: delta factor of the basis reduction
+///
+/// The basis is reduced in-place.
+///
+/// Returns an error, and leaves the basis unchanged, if an intermediate integer (e.g. an entry of the Gram
+/// matrix) does not fit in an `i64`.
+///
+/// # Panics
+/// if delta <= 1/4 or delta >= 1  
+/// if eta <= 1/2 or eta > sqrt(delta)
+pub fn lll_i64<const R: usize, const C: usize>(
+    basis: &mut SMatrix<i64, R, C>,
+    eta: f64,
+    delta: f64,
+) -> Result<(), Overflow> {
+    let mut checked = basis.map(|&n| Checked(n));
+    // Backends which do not detect non-finite numbers do not fail
+    let _ =
+        with_overflow_check(|| reduction::<CheckedI64, R, C>(&mut checked.vectors, eta, delta))?;
+    *basis = checked.map(|n| n.0);
+    Ok(())
+}
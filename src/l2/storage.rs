@@ -0,0 +1,172 @@
+//! Storage of the vectors of a reduction, on the heap or on the stack
+//!
+//! The L² reduction only reads and writes its vectors and coefficients through [`Vectors`], so that the same
+//! implementation reduces a [`Matrix`], and the arrays of a [`SMatrix`](crate::algebra::SMatrix) without heap
+//! allocation (see [`small`](super::small)).
+
+use crate::algebra::{Coefficient, Matrix, Vector};
+
+use std::{array, borrow::Cow, ops::IndexMut};
+
+/// Vectors of the same dimension: the basis vectors, or the rows of a matrix of coefficients
+pub(crate) trait Vectors<T: Coefficient>: Clone {
+    /// A vector of the same dimension
+    type Vector<U: Coefficient>: IndexMut<usize, Output = U>;
+    /// One number per vector
+    type Line<U: Coefficient>: IndexMut<usize, Output = U> + AsRef<[U]>;
+    /// The same number of vectors of the same dimension, with entries of another type
+    type With<U: Coefficient>: Vectors<U> + IndexMut<usize, Output = Self::Vector<U>>;
+    /// A square matrix, with one row and one column per vector
+    type Square<U: Coefficient>: Vectors<U> + IndexMut<usize, Output = Self::Line<U>>;
+
+    /// Number of vectors
+    fn len(&self) -> usize;
+
+    /// Dimension of the vectors
+    fn dimension(&self) -> usize;
+
+    /// Coordinates of the `i`-th vector
+    fn row(&self, i: usize) -> &[T];
+
+    /// Subtract `factor` times the `source`-th vector from the `target`-th one, with `source < target`
+    fn sub_multiple(&mut self, target: usize, source: usize, factor: &T);
+
+    /// Insert the `i`-th vector before the `j`-th one, as [`Matrix::insert`]
+    fn insert(&mut self, i: usize, j: usize);
+
+    /// Zero vectors of the same number and dimension
+    fn zeros<U: Coefficient>(&self) -> Self::With<U>;
+
+    /// Zero square matrix, with one row and one column per vector
+    fn square<U: Coefficient>(&self) -> Self::Square<U>;
+
+    /// Zero number per vector
+    fn line<U: Coefficient>(&self) -> Self::Line<U>;
+
+    /// The vectors as a `Matrix`, which is only copied if they are stored on the stack
+    fn as_matrix(&self) -> Cow<'_, Matrix<T>>;
+}
+
+impl<T: Coefficient> Vectors<T> for Matrix<T> {
+    type Vector<U: Coefficient> = Vector<U>;
+    type Line<U: Coefficient> = Vector<U>;
+    type With<U: Coefficient> = Matrix<U>;
+    type Square<U: Coefficient> = Matrix<U>;
+
+    fn len(&self) -> usize {
+        self.dimensions().0
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimensions().1
+    }
+
+    fn row(&self, i: usize) -> &[T] {
+        self[i].as_slice()
+    }
+
+    fn sub_multiple(&mut self, target: usize, source: usize, factor: &T) {
+        let (head, tail) = self.columns_mut().split_at_mut(target);
+        sub_multiple(tail[0].as_mut_slice(), head[source].as_slice(), factor);
+    }
+
+    fn insert(&mut self, i: usize, j: usize) {
+        Matrix::insert(self, i, j)
+    }
+
+    fn zeros<U: Coefficient>(&self) -> Matrix<U> {
+        let (d, n) = self.dimensions();
+        Matrix::init(d, n)
+    }
+
+    fn square<U: Coefficient>(&self) -> Matrix<U> {
+        let (d, _) = self.dimensions();
+        Matrix::init(d, d)
+    }
+
+    fn line<U: Coefficient>(&self) -> Vector<U> {
+        Vector::init(self.dimensions().0)
+    }
+
+    fn as_matrix(&self) -> Cow<'_, Matrix<T>> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<T: Coefficient, const R: usize, const C: usize> Vectors<T> for [[T; C]; R] {
+    type Vector<U: Coefficient> = [U; C];
+    type Line<U: Coefficient> = [U; R];
+    type With<U: Coefficient> = [[U; C]; R];
+    type Square<U: Coefficient> = [[U; R]; R];
+
+    fn len(&self) -> usize {
+        R
+    }
+
+    fn dimension(&self) -> usize {
+        C
+    }
+
+    fn row(&self, i: usize) -> &[T] {
+        &self[i]
+    }
+
+    fn sub_multiple(&mut self, target: usize, source: usize, factor: &T) {
+        let (head, tail) = self.split_at_mut(target);
+        sub_multiple(&mut tail[0], &head[source], factor);
+    }
+
+    fn insert(&mut self, i: usize, j: usize) {
+        if j < i {
+            self[j..=i].rotate_right(1);
+        } else {
+            self[i..=j].rotate_left(1);
+        }
+    }
+
+    fn zeros<U: Coefficient>(&self) -> [[U; C]; R] {
+        array::from_fn(|_| array::from_fn(|_| U::default()))
+    }
+
+    fn square<U: Coefficient>(&self) -> [[U; R]; R] {
+        array::from_fn(|_| array::from_fn(|_| U::default()))
+    }
+
+    fn line<U: Coefficient>(&self) -> [U; R] {
+        array::from_fn(|_| U::default())
+    }
+
+    fn as_matrix(&self) -> Cow<'_, Matrix<T>> {
+        Cow::Owned(Matrix::from_columns(
+            self.iter()
+                .map(|vector| Vector::from_vector(vector.to_vec()))
+                .collect(),
+        ))
+    }
+}
+
+fn sub_multiple<T: Coefficient>(target: &mut [T], source: &[T], factor: &T) {
+    for (b, a) in target.iter_mut().zip(source) {
+        *b -= &(a.clone() * factor);
+    }
+}
+
+/// Dot product of two vectors
+pub(crate) fn dot<T: Coefficient>(a: &[T], b: &[T]) -> T {
+    a.iter()
+        .zip(b)
+        .fold(T::from(0), |sum, (x, y)| sum + &(x.clone() * y))
+}
+
+/// The Gram matrix of `vectors`
+pub(crate) fn gram<T: Coefficient, V: Vectors<T>>(vectors: &V) -> V::Square<T> {
+    let mut gram = vectors.square();
+    for i in 0..vectors.len() {
+        for j in 0..=i {
+            let entry = dot(vectors.row(i), vectors.row(j));
+            gram[j][i] = entry.clone();
+            gram[i][j] = entry;
+        }
+    }
+    gram
+}
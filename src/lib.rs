@@ -9,12 +9,8 @@
 //! # Examples
 //!
 //! ```rust
-//! use lll_rs::{
-//!     l2::{bigl2, l2f},
-//!     lll::{biglll, lllf},
-//!     matrix::Matrix,
-//!     vector::{BigVector, VectorF},
-//! };
+//! # #[cfg(feature = "rug")] {
+//! use lll_rs::{l2, lll, matrix::Matrix, vector::BigVector};
 //!
 //! use rug::{Integer,Assign};
 //!
@@ -42,27 +38,1089 @@
 //! ]);
 //!
 //! // Perfom the LLL basis redution
-//! biglll::lattice_reduce(&mut basis);
+//! lll::lll_bignum(&mut basis);
 //!
 //! // OR
-//! // Perfom the LLL basis redution
-//! // Specify the delta and eta coefficient for the reduction
-//! bigl2::lattice_reduce(&mut basis, 0.5005, 0.999);
+//! // Perfom the L² basis redution
+//! // Specify the eta and delta coefficient for the reduction
+//! l2::lll_bignum(&mut basis, 0.5005, 0.999);
+//! # }
+//! ```
+//!
+//! The common imports are gathered in the [`prelude`]:
+//!
+//! ```rust
+//! use lll_rs::prelude::*;
+//!
+//! let mut basis: Matrix<f64> = Matrix::from_matrix(vec![
+//!     vec![1., 0., 0., 1345.],
+//!     vec![0., 1., 0., 35.],
+//!     vec![0., 0., 1., 154.],
+//! ]);
+//! l2::lll_float(&mut basis, 0.501, 0.998).unwrap();
+//! assert_eq!(basis[0].norm_sqr(), 119.);
 //! ```
 //!
+#[cfg(feature = "crypto-bigint")]
+extern crate crypto_bigint;
+#[cfg(feature = "num")]
+extern crate num_bigint;
+#[cfg(feature = "num")]
+extern crate num_rational;
+#[cfg(feature = "num")]
+extern crate num_traits;
+extern crate rand_core;
+#[cfg(feature = "rug")]
 extern crate rug;
 
 mod algebra;
+#[cfg(feature = "rug")]
+pub mod coppersmith;
+pub mod cvp;
+pub mod enumeration;
+pub mod estimate;
+#[cfg(feature = "rug")]
+pub mod generator;
+#[cfg(feature = "rug")]
+pub mod ggh;
+#[cfg(feature = "rug")]
+pub mod hnp;
+#[cfg(feature = "rug")]
+pub mod hssp;
+#[cfg(feature = "rug")]
+pub mod knapsack;
 pub mod l2;
 pub mod lll;
+#[cfg(feature = "rug")]
+pub mod lwe;
+#[cfg(feature = "rug")]
+pub mod ntru;
+#[cfg(feature = "rug")]
+pub mod orthogonal;
+pub mod quality;
+pub mod random;
+#[cfg(feature = "rug")]
+pub mod relation;
+#[cfg(feature = "rug")]
+pub mod rsa;
+#[cfg(feature = "rug")]
+pub mod schnorr;
+
+#[cfg(feature = "crypto-bigint")]
+pub use algebra::FixedInt;
+#[cfg(feature = "num")]
+pub use algebra::NumBigInt;
+pub use algebra::{
+    Alignment, Basis, Checked, CheckedI128, CheckedI64, DimensionMismatch, EntrySizeStats, Fixed,
+    FixedPoint, Float, Float32, FormatOptions, InvalidBasis, Matrix, Overflow, SMatrix, Transform,
+    Vector,
+};
+#[cfg(feature = "rug")]
+pub use algebra::{BigDouble, BigDoubleDouble, BigFloat, BigNum, Certified, DoubleDouble};
+#[cfg(feature = "num-traits")]
+pub use algebra::{Num, NumInteger, NumTraits};
 
-pub use algebra::{BigNum, Float, Matrix};
+/// Matrices, whose columns are the basis vectors of a lattice
+pub mod matrix {
+    pub use crate::algebra::{
+        Alignment, Basis, DimensionMismatch, EntrySizeStats, FormatOptions, InvalidBasis, Matrix,
+        SMatrix, Transform,
+    };
+}
+
+/// Vectors, such as the basis vectors of a lattice
+pub mod vector {
+    #[cfg(feature = "rug")]
+    pub use crate::algebra::BigVector;
+    pub use crate::algebra::{Vector, VectorF};
+}
+
+/// The common imports: `use lll_rs::prelude::*;`
+pub mod prelude {
+    #[cfg(feature = "rug")]
+    pub use crate::vector::BigVector;
+    pub use crate::{
+        l2,
+        l2::{LatticeReduce, LllParams, NonFinite},
+        lll,
+        matrix::{Basis, Matrix, SMatrix},
+        vector::{Vector, VectorF},
+        Overflow,
+    };
+}
+
+/// Compile-time check that the bases and the reduction states can be moved to and shared between threads
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Matrix<f64>>();
+    assert_send_sync::<Basis<f64>>();
+    assert_send_sync::<Matrix<i64>>();
+    assert_send_sync::<Vector<f64>>();
+    assert_send_sync::<SMatrix<f64, 4, 4>>();
+    assert_send_sync::<Transform<f64>>();
+    assert_send_sync::<l2::Checkpoint<f64>>();
+    assert_send_sync::<l2::LllParams>();
+    assert_send_sync::<l2::ReductionError>();
+    assert_send_sync::<l2::Report>();
+    #[cfg(feature = "rug")]
+    assert_send_sync::<Matrix<rug::Integer>>();
+    #[cfg(feature = "rug")]
+    assert_send_sync::<Vector<rug::Integer>>();
+    #[cfg(feature = "rug")]
+    assert_send_sync::<l2::Checkpoint<rug::Integer>>();
+    #[cfg(feature = "num")]
+    assert_send_sync::<Matrix<num_bigint::BigInt>>();
+};
 
 #[cfg(test)]
 mod test {
-    use crate::{l2, lll, Matrix};
+    #[cfg(feature = "rug")]
+    use crate::{
+        coppersmith, cvp, enumeration, generator, ggh, hnp, hssp, knapsack, l2::ReductionStats,
+        lwe, ntru, orthogonal, quality, relation, rsa, schnorr, EntrySizeStats,
+    };
+    use crate::{
+        estimate, l2, lll, random::SeededRng, Alignment, Basis, DimensionMismatch, FormatOptions,
+        InvalidBasis, Matrix, Overflow, SMatrix, Transform, Vector,
+    };
+    use rand_core::{RngCore, SeedableRng};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_seeded_rng() {
+        let mut rng = SeededRng::new(42);
+        let outputs: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+        let mut again = SeededRng::seed_from_u64(42);
+        assert!(outputs.iter().all(|&output| again.next_u64() == output));
+        assert_eq!(
+            SeededRng::from_seed(42_u64.to_le_bytes()),
+            SeededRng::new(42)
+        );
+        assert_ne!(SeededRng::new(43).next_u64(), outputs[0]);
+
+        let random = |seed| Matrix::<f64>::random(4, 6, 20, &mut SeededRng::new(seed));
+        assert_eq!(random(7), random(7));
+        assert_ne!(random(7), random(8));
+    }
+
+    #[test]
+    fn test_matrix_transpose() {
+        let mut matrix: Matrix<f64> = Matrix::from_matrix(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let transposed = matrix.transpose();
+        assert_eq!(transposed.dimensions(), (3, 2));
+        assert_eq!(
+            transposed,
+            Matrix::from_matrix(vec![vec![1., 4.], vec![2., 5.], vec![3., 6.]])
+        );
+        assert!(transposed.transpose() == matrix);
+
+        matrix.transpose_mut();
+        assert!(matrix == transposed);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_constructors() {
+        let identity: Matrix<i64> = Matrix::identity(3);
+        assert_eq!(
+            identity,
+            Matrix::from_matrix(vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]])
+        );
+        assert_eq!(identity, Matrix::diagonal(&[1, 1, 1]));
+
+        let diagonal: Matrix<rug::Integer> =
+            Matrix::diagonal(&[rug::Integer::from(2), rug::Integer::from(-3)]);
+        assert_eq!(diagonal.dimensions(), (2, 2));
+        assert_eq!(diagonal.determinant(), -6);
+        assert_eq!(Matrix::<f64>::identity(0).dimensions(), (0, 0));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_random() {
+        let basis = Matrix::<rug::Integer>::random(4, 5, 100, &mut SeededRng::new(1));
+        assert_eq!(basis.dimensions(), (4, 5));
+        assert!((0..4).all(|i| (0..5).all(|j| basis[i][j].significant_bits() <= 100)));
+        assert!((0..4).any(|i| (0..5).any(|j| basis[i][j] < 0)));
+        assert!(basis == Matrix::<rug::Integer>::random(4, 5, 100, &mut SeededRng::new(1)));
+
+        let basis = Matrix::<i64>::random(3, 3, 10, &mut SeededRng::new(2));
+        assert!((0..3).all(|i| (0..3).all(|j| basis[i][j].abs() < 1 << 10)));
+        let basis = Matrix::<f64>::random(3, 3, 53, &mut SeededRng::new(3));
+        assert!((0..3).all(|i| (0..3).all(|j| basis[i][j].fract() == 0.)));
+    }
+
+    #[test]
+    fn test_matrix_submatrix() {
+        let matrix: Matrix<i64> = Matrix::from_matrix(vec![
+            vec![1, 2, 3, 4],
+            vec![5, 6, 7, 8],
+            vec![9, 10, 11, 12],
+        ]);
+        assert_eq!(
+            matrix.submatrix(1..3, 2..4),
+            Matrix::from_matrix(vec![vec![7, 8], vec![11, 12]])
+        );
+        assert_eq!(
+            matrix.block(0..2),
+            Matrix::from_matrix(vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]])
+        );
+        assert!(matrix.block(0..3) == matrix);
+        assert_eq!(matrix.submatrix(1..1, 0..4).dimensions(), (0, 4));
+    }
+
+    #[test]
+    fn test_matrix_stack() {
+        let a: Matrix<i64> = Matrix::from_matrix(vec![vec![1, 2], vec![3, 4]]);
+        let t: Matrix<i64> = Matrix::from_matrix(vec![vec![5], vec![6]]);
+        let bottom: Matrix<i64> = Matrix::from_matrix(vec![vec![0, 0, 7]]);
+
+        // Embedding [A | t; 0 | M]
+        let embedding = a.hstack(&t).vstack(&bottom);
+        assert_eq!(
+            embedding,
+            Matrix::from_matrix(vec![vec![1, 2, 5], vec![3, 4, 6], vec![0, 0, 7]])
+        );
+        assert_eq!(embedding.dimensions(), (3, 3));
+        assert_eq!(
+            a.augment_identity(),
+            Matrix::from_matrix(vec![vec![1, 2, 1, 0], vec![3, 4, 0, 1]])
+        );
+    }
+
+    #[test]
+    fn test_matrix_entry_index() {
+        let mut matrix: Matrix<i64> = Matrix::from_matrix(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(matrix[(1, 2)], 6);
+        assert_eq!(matrix[(0, 1)], matrix[0][1]);
+
+        matrix[(1, 0)] = -4;
+        assert_eq!(matrix[1][0], -4);
+    }
+
+    #[test]
+    fn test_matrix_columns() {
+        let mut matrix: Matrix<i64> = Matrix::init(0, 0);
+        matrix.push_column(Vector::from_vector(vec![1, 2]));
+        matrix.push_column(Vector::from_vector(vec![5, 6]));
+        matrix.insert_column(1, Vector::from_vector(vec![3, 4]));
+        assert_eq!(matrix.dimensions(), (3, 2));
+        assert_eq!(
+            matrix,
+            Matrix::from_matrix(vec![vec![1, 2], vec![3, 4], vec![5, 6]])
+        );
+
+        assert!(matrix.remove_column(0) == Vector::from_vector(vec![1, 2]));
+        assert_eq!(matrix.dimensions(), (2, 2));
+        assert_eq!(matrix, Matrix::from_matrix(vec![vec![3, 4], vec![5, 6]]));
+    }
+
+    #[test]
+    fn test_matrix_from_rows() {
+        // Row-basis from the usual notation, and the same lattice given by the columns of a matrix
+        let rows = vec![vec![1, 0, 0, 1345], vec![0, 1, 0, 35], vec![0, 0, 1, 154]];
+        let mut basis: Matrix<i64> = Matrix::from_rows(rows.clone());
+        assert_eq!(basis, Matrix::from_matrix(rows));
+        assert_eq!(basis[(0, 3)], 1345);
+
+        let mut column_basis: Matrix<i64> = Matrix::from_column_basis(vec![
+            vec![1, 0, 0],
+            vec![0, 1, 0],
+            vec![0, 0, 1],
+            vec![1345, 35, 154],
+        ]);
+        assert_eq!(column_basis, basis);
+        assert_eq!(
+            Matrix::<i64>::from_column_basis(vec![]).dimensions(),
+            (0, 0)
+        );
+        l2::lll_i64(&mut basis, 0.501, 0.998).unwrap();
+        l2::lll_i64(&mut column_basis, 0.501, 0.998).unwrap();
+        assert_eq!(basis, column_basis);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_map() {
+        type I = rug::Integer;
+        let matrix: Matrix<i64> = Matrix::from_rows(vec![vec![1, -2, 3], vec![4, 5, -6]]);
+        let big = matrix.map(|&x| I::from(x) << 100);
+        assert_eq!(big[(1, 2)], I::from(-6) << 100);
+
+        // 2^100 = 2 mod 7
+        let q = I::from(7);
+        let reduced = big.map(|x: &I| x.clone().modulo(&q));
+        assert_eq!(
+            reduced.map(|x| x.to_i64().unwrap()),
+            matrix.map(|x| (2 * x).rem_euclid(7))
+        );
+
+        let sum = matrix.zip_with(&matrix.map(|&x| x as f64), |&x, &y| x as f64 + y);
+        assert_eq!(
+            sum,
+            Matrix::from_rows(vec![vec![2., -4., 6.], vec![8., 10., -12.]])
+        );
+        assert!(matrix[0].map(|&x| 2 * x) == matrix[0].zip_with(&matrix[0], |&x, &y| x + y));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_vector_norms() {
+        let v = Vector::from_vector(vec![rug::Integer::from(3), (-4).into(), 1.into()]);
+        assert_eq!(v.norm_sqr(), 26);
+        assert_eq!(v.l1_norm(), 8);
+        assert_eq!(v.max_norm(), 4);
+
+        let v = Vector::from_vector(vec![-1.5, 0.5, 1.]);
+        assert_eq!(v.norm_sqr(), 3.5);
+        assert_eq!(v.l1_norm(), 3.);
+        assert_eq!(v.max_norm(), 1.5);
+        assert_eq!(Vector::<f64>::zero(0).max_norm(), 0.);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_vector_dot() {
+        let a = Vector::from_vector(vec![rug::Integer::from(3), (-4).into(), 1.into()]);
+        let b = Vector::from_vector(vec![rug::Integer::from(2), 1.into(), 5.into()]);
+        assert_eq!(a.dot(&b), 7);
+        assert_eq!(a.dot(&b), b.dot(&a));
+        assert_eq!(a.dot(&a), a.norm_sqr());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vector_dot_dimension_mismatch() {
+        let a: Vector<i64> = Vector::from_vector(vec![3, -4, 1]);
+        let short = Vector::from_vector(vec![1, 1]);
+        a.dot(&short);
+    }
+
+    #[test]
+    fn test_vector_checked_operations() {
+        let a: Vector<i64> = Vector::from_vector(vec![3, -4, 1]);
+        let b = Vector::from_vector(vec![2, 1, 5]);
+        let short = Vector::from_vector(vec![1, 1]);
+        assert_eq!(a.try_add(&b), Ok(a.add(&b)));
+        assert_eq!(a.try_sub(&b), Ok(a.sub(&b)));
+        assert_eq!(a.try_dot(&b), Ok(7));
+
+        let mismatch = DimensionMismatch {
+            index: 1,
+            expected: 3,
+            found: 2,
+        };
+        assert_eq!(a.try_add(&short), Err(mismatch));
+        assert_eq!(a.try_sub(&short), Err(mismatch));
+        assert_eq!(a.try_dot(&short), Err(mismatch));
+    }
+
+    #[test]
+    fn test_vector_collect() {
+        let v: Vector<i64> = (1..=3).map(|x| x * x).collect();
+        assert!(v == Vector::from_vector(vec![1, 4, 9]));
+
+        // Knapsack lattice: identity rows followed by the weights
+        let weights = [1345, 35, 154];
+        let basis: Matrix<i64> = (0..3)
+            .map(|i| {
+                (0..4)
+                    .map(|j| if j == 3 { weights[i] } else { (i == j) as i64 })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(basis.dimensions(), (3, 4));
+        assert!(
+            basis
+                == Matrix::from_matrix(vec![
+                    vec![1, 0, 0, 1345],
+                    vec![0, 1, 0, 35],
+                    vec![0, 0, 1, 154]
+                ])
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_vector_division() {
+        let v = |c: &[i32]| Vector::from_vector(c.iter().map(|&x| rug::Integer::from(x)).collect());
+
+        let a = v(&[6, -4, 10]);
+        assert!(a.divf_exact(&rug::Integer::from(-2)) == Some(v(&[-3, 2, -5])));
+        assert!(a.divf_exact(&rug::Integer::from(4)).is_none());
+        assert_eq!(a.content(), 2);
+        assert!(a.primitive() == v(&[3, -2, 5]));
+        assert!(a.neg() == v(&[-6, 4, -10]));
+        assert!(v(&[0, 0]).primitive() == v(&[0, 0]));
+
+        let f = Vector::from_vector(vec![1., -3.]);
+        assert!(f.divf(2.) == Vector::from_vector(vec![0.5, -1.5]));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_vector_projection() {
+        type Q = rug::Rational;
+        let v = |c: &[i32]| Vector::from_vector(c.iter().map(|&x| Q::from(x)).collect());
+
+        let a = v(&[3, 1, 2]);
+        assert!(a.project_onto(&v(&[1, 1, 0])) == v(&[2, 2, 0]));
+        assert!(a.project_onto(&v(&[0, 0, 0])) == v(&[0, 0, 0]));
+
+        // Dependent vectors spanning the plane z = 0
+        let plane = [v(&[1, 1, 0]), v(&[2, 2, 0]), v(&[1, -2, 0])];
+        let orthogonal = a.orthogonalize_against(&plane);
+        assert!(orthogonal == v(&[0, 0, 2]));
+        assert!(plane.iter().all(|b| orthogonal.dot(b) == 0));
+        assert!(a.orthogonalize_against(&[]) == a);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_vector_operators() {
+        type I = rug::Integer;
+
+        let a = Vector::from_vector(vec![I::from(1), I::from(2), I::from(3)]);
+        let b = Vector::from_vector(vec![I::from(4), I::from(5), I::from(6)]);
+        let x = I::from(2);
+        assert!(&a + &b == a.add(&b));
+        assert!(&b - &a == b.sub(&a));
+        assert!(&a * &x == a.mulf(x.clone()));
+        assert!(-&a == Vector::from_vector(vec![I::from(-1), I::from(-2), I::from(-3)]));
+
+        // b_k -= x b_i
+        let mut c = b.clone();
+        c -= &(&a * &x);
+        assert!(c == Vector::from_vector(vec![I::from(2), I::from(1), I::from(0)]));
+        c += &a;
+        c *= &x;
+        assert!(c == (b.clone() - &(a.clone() * &x) + &a) * &x);
+        assert!(-c.clone() + &c == Vector::zero(3));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_gram() {
+        let basis: Matrix<rug::Integer> = Matrix::from_rows(vec![
+            vec![1.into(), 2.into()],
+            vec![3.into(), 4.into()],
+            vec![0.into(), 1.into()],
+        ]);
+        let gram = basis.gram();
+        assert_eq!(gram, &basis * &basis.transpose());
+        assert_eq!(gram[(0, 1)], 11);
+        assert_eq!(gram[(1, 0)], 11);
+        assert_eq!(gram[(2, 2)], basis[2].norm_sqr());
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_ldl() {
+        type Q = rug::Rational;
+        let basis: Matrix<Q> = Matrix::from_rows(vec![
+            vec![1.into(), 2.into(), 0.into()],
+            vec![3.into(), 4.into(), 1.into()],
+            vec![4.into(), 6.into(), 1.into()],
+        ]);
+        let gram = basis.gram();
+        let (l, d) = gram.ldl();
+        assert_eq!(l[(1, 0)], Q::from((11, 5)));
+        // The third vector is the sum of the first two
+        assert_eq!(d[2], 0);
+        assert_eq!(l[(2, 1)], 1);
+
+        let mut scaled = l.transpose();
+        for i in 0..3 {
+            scaled[i] *= &d[i];
+        }
+        assert_eq!(&l * &scaled, gram);
+
+        let gram = basis.map(|x| x.to_f64()).gram();
+        let cholesky = gram.cholesky();
+        let product = &cholesky * &cholesky.transpose();
+        assert!((0..3).all(|i| (0..3).all(|j| (product[(i, j)] - gram[(i, j)]).abs() < 1e-9)));
+    }
+
+    #[test]
+    fn test_matrix_product() {
+        let basis: Matrix<i64> = Matrix::from_matrix(vec![
+            vec![1, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+
+        // The reduced basis is the product of a unimodular matrix and the initial one
+        let unimodular: Matrix<i64> =
+            Matrix::from_matrix(vec![vec![1, 1, -9], vec![0, 9, -2], vec![1, -3, -8]]);
+        let mut reduced: Matrix<i64> = Matrix::from_matrix(vec![
+            vec![1, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        l2::lll_i64(&mut reduced, 0.501, 0.998).unwrap();
+        assert_eq!(&unimodular * &basis, reduced);
+
+        // Combination of the basis vectors, and coordinates in the canonical basis
+        assert!(&unimodular[1] * &basis == reduced[1]);
+        let coordinates = &basis * &Vector::basis_vector(4, 3);
+        assert!((0..3).all(|i| coordinates[i] == basis[i][3]));
+    }
+
+    #[test]
+    fn test_matrix_sort_by_norm() {
+        let mut basis: Matrix<i64> = Matrix::from_matrix(vec![
+            vec![1, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+            vec![0, 0, 0, 0],
+        ]);
+        l2::lll_i64(&mut basis, 0.501, 0.998).unwrap();
+        basis.sort_by_norm();
+
+        let norms: Vec<i64> = (0..4).map(|i| basis[i].norm_sqr()).collect();
+        assert_eq!(norms, vec![0, 119, 134, 138]);
+
+        let mut floats: Matrix<f64> = Matrix::from_matrix(vec![
+            vec![3., 0.],
+            vec![f64::NAN, 1.],
+            vec![1., 1.],
+            vec![f64::INFINITY, 0.],
+            vec![0., 2.],
+        ]);
+        floats.sort_by_norm();
+        assert_eq!(floats[0][0], 1.);
+        assert_eq!(floats[1][1], 2.);
+        assert_eq!(floats[2][0], 3.);
+        assert_eq!(floats[3][0], f64::INFINITY);
+        assert!(floats[4][0].is_nan());
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_from_primitive() {
+        let entries: Vec<Vec<i64>> =
+            vec![vec![1, 0, 0, 1345], vec![0, 1, 0, 35], vec![0, 0, 1, 154]];
+        let rows: Vec<&[i64]> = entries.iter().map(|row| &row[..]).collect();
+
+        let mut basis = Matrix::<rug::Integer>::from(entries.clone());
+        assert!(basis == Matrix::from(&rows[..]));
+        assert_eq!(basis[0][3], 1345);
+        l2::lll_bignum(&mut basis, 0.501, 0.998).unwrap();
+        let reduced = vec![
+            vec![1_i64, 1, -9, -6],
+            vec![0, 9, -2, 7],
+            vec![1, -3, -8, 8],
+        ];
+        assert!(basis == Matrix::from(reduced));
+
+        let floats = Matrix::<f64>::from(entries);
+        assert_eq!(floats.dimensions(), (3, 4));
+        assert_eq!(floats[2][3], 154.);
+    }
+
+    #[test]
+    fn test_matrix_try_from() {
+        let basis = Matrix::try_from(vec![vec![1, 0, 3], vec![0, 1, 5]]).unwrap();
+        assert_eq!(basis.dimensions(), (2, 3));
+        assert!(basis == Matrix::<i64>::from_matrix(vec![vec![1, 0, 3], vec![0, 1, 5]]));
+
+        let ragged = vec![vec![1., 0., 3.], vec![0., 1., 5.], vec![2., 7.]];
+        assert_eq!(
+            Matrix::<f64>::try_from_matrix(ragged),
+            Err(DimensionMismatch {
+                index: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_matrix_check_dimensions() {
+        use crate::l2::LatticeReduce;
+
+        let mut basis: Matrix<f64> = Matrix::from_matrix(vec![vec![1., 0., 3.], vec![0., 1., 5.]]);
+        assert_eq!(basis.check_dimensions(), Ok(()));
+        basis[1] = Vector::from_vector(vec![0., 1.]);
+        let mismatch = InvalidBasis::DimensionMismatch(DimensionMismatch {
+            index: 1,
+            expected: 3,
+            found: 2,
+        });
+        assert_eq!(basis.check_dimensions(), Err(mismatch));
+
+        // The checked reductions report the malformed bases instead of panicking
+        let initial = basis.clone();
+        assert_eq!(basis.lll(), Err(l2::ReductionError::InvalidBasis(mismatch)));
+        assert_eq!(basis, initial);
+        assert_eq!(
+            l2::lll_i64(
+                &mut Matrix::from_matrix(vec![vec![1, 0, 3], vec![0, 1]]),
+                0.501,
+                0.998
+            ),
+            Err(l2::ReductionError::InvalidBasis(mismatch))
+        );
+        #[cfg(feature = "rug")]
+        {
+            let mut integers = Matrix::<rug::Integer>::from(vec![vec![1_i64, 0, 3], vec![0, 1]]);
+            assert_eq!(integers.lll(), Err(mismatch));
+            assert_eq!(l2::lll_bignum(&mut integers, 0.501, 0.998), Err(mismatch));
+        }
+
+        // So are the empty ones
+        let empty = Err(InvalidBasis::Empty);
+        assert_eq!(Matrix::<f64>::init(0, 0).check_dimensions(), empty);
+        assert_eq!(Matrix::<f64>::init(2, 0).check_dimensions(), empty);
+        assert_eq!(
+            Matrix::<f64>::init(0, 0).lll(),
+            Err(l2::ReductionError::InvalidBasis(InvalidBasis::Empty))
+        );
+        assert_eq!(
+            l2::lll_float(&mut Matrix::init(2, 0), 0.501, 0.998),
+            Err(l2::ReductionError::InvalidBasis(InvalidBasis::Empty))
+        );
+    }
+
+    #[test]
+    fn test_basis_try_new() {
+        use crate::l2::LatticeReduce;
+
+        let matrix = Matrix::<f64>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut basis = Basis::try_new(matrix.clone()).unwrap();
+        assert_eq!(basis.matrix(), &matrix);
+        basis.lll().unwrap();
+        let mut expected = matrix;
+        expected.lll().unwrap();
+        assert_eq!(basis.into_matrix(), expected);
+
+        assert_eq!(
+            Basis::try_new(Matrix::<f64>::init(0, 3)),
+            Err(InvalidBasis::Empty)
+        );
+        assert_eq!(
+            Basis::try_new(Matrix::<f64>::init(2, 0)),
+            Err(InvalidBasis::Empty)
+        );
+        let mut matrix: Matrix<f64> = Matrix::from_matrix(vec![vec![1., 0.], vec![0., 1.]]);
+        matrix[1] = Vector::from_vector(vec![0.]);
+        assert_eq!(
+            Basis::try_new(matrix),
+            Err(InvalidBasis::DimensionMismatch(DimensionMismatch {
+                index: 1,
+                expected: 2,
+                found: 1,
+            }))
+        );
+        for entry in &[f64::INFINITY, f64::NAN] {
+            let matrix = Matrix::from_matrix(vec![vec![1., 0.], vec![0., *entry]]);
+            assert_eq!(
+                Basis::try_new(matrix),
+                Err(InvalidBasis::NonFinite {
+                    index: 1,
+                    coordinate: 1,
+                })
+            );
+        }
+
+        #[cfg(feature = "rug")]
+        {
+            let integers = Matrix::<rug::Integer>::from(vec![vec![1_i64, 2], vec![3, 4]]);
+            let mut basis = Basis::try_new(integers).unwrap();
+            assert_eq!(basis.lll(), Ok(()));
+            assert_eq!(basis.matrix().determinant().abs(), 2);
+        }
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_hash() {
+        use std::collections::HashSet;
+
+        let basis = Matrix::<rug::Integer>::from(vec![vec![1_i64, 0, 3], vec![0, 1, 5]]);
+        let mut reduced = basis.clone();
+        l2::lll_bignum(&mut reduced, 0.501, 0.998).unwrap();
+
+        let bases: HashSet<_> = vec![basis.clone(), reduced, basis].into_iter().collect();
+        assert_eq!(bases.len(), 2);
+    }
+
+    #[test]
+    fn test_matrix_display() {
+        let basis: Matrix<i64> = Matrix::from_matrix(vec![
+            vec![1, 1, -9, -6],
+            vec![0, 9, -2, 7],
+            vec![1, -3, -8, 8],
+        ]);
+        assert_eq!(
+            basis.to_string(),
+            "[1  1 -9 -6]\n[0  9 -2  7]\n[1 -3 -8  8]"
+        );
+
+        let floats: Matrix<f64> = Matrix::from_matrix(vec![vec![0.5, 2.], vec![-1.25, 0.]]);
+        assert_eq!(format!("{:.1}", floats), "[ 0.5 2.0]\n[-1.2 0.0]");
+
+        let large: Matrix<i64> = Matrix::from_matrix(vec![vec![1 << 60, 0], vec![0, 1]]);
+        let options = FormatOptions {
+            max_width: Some(7),
+            ..FormatOptions::default()
+        };
+        assert_eq!(basis.to_text_with(&options), basis.to_string());
+        assert_eq!(large.to_text_with(&options), "[115…976 0]\n[      0 1]");
+        let options = FormatOptions {
+            max_width: Some(7),
+            alignment: Alignment::Left,
+        };
+        assert_eq!(large.to_text_with(&options), "[115…976 0]\n[0       1]");
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_latex_markdown() {
+        let basis: Matrix<i64> = Matrix::from_matrix(vec![vec![1, -9], vec![10, 2]]);
+        assert_eq!(
+            basis.to_latex(),
+            "\\left(\\begin{array}{rr}\n1 & -9 \\\\\n10 & 2\n\\end{array}\\right)"
+        );
+        assert_eq!(
+            basis.to_markdown(),
+            "|     |     |\n| --: | --: |\n|   1 |  -9 |\n|  10 |   2 |"
+        );
+
+        let mut large = Matrix::<rug::Integer>::from(vec![vec![1_i64, 0], vec![0, 1]]);
+        large[0][0] = rug::Integer::from(1) << 100;
+        let options = FormatOptions {
+            max_width: Some(7),
+            alignment: Alignment::Left,
+        };
+        assert_eq!(
+            large.to_latex_with(&options),
+            "\\left(\\begin{array}{ll}\n126\\ldots{}376 & 0 \\\\\n0 & 1\n\\end{array}\\right)"
+        );
+        assert_eq!(
+            large.to_markdown_with(&options),
+            "|         |     |\n| :------ | :-- |\n| 126…376 | 0   |\n| 0       | 1   |"
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_hadamard_mod_q() {
+        let a: Matrix<i64> = Matrix::from_matrix(vec![vec![1, -9, 4], vec![10, 2, -7]]);
+        let b: Matrix<i64> = Matrix::from_matrix(vec![vec![3, 2, -1], vec![0, 5, 6]]);
+        let product = a.hadamard(&b);
+        assert_eq!(
+            product,
+            Matrix::from_matrix(vec![vec![3, -18, -4], vec![0, 10, -42]])
+        );
+        assert_eq!(
+            product.mod_q(7),
+            Matrix::from_matrix(vec![vec![3, 3, 3], vec![0, 3, 0]])
+        );
+        assert_eq!(
+            a.mod_q(4),
+            Matrix::from_matrix(vec![vec![1, -1, 0], vec![2, 2, 1]])
+        );
+
+        let big = Matrix::<rug::Integer>::from(vec![vec![-9_i64, 4, 13], vec![2, -2, 7]]);
+        assert_eq!(
+            big.mod_q(&rug::Integer::from(4)),
+            Matrix::from(vec![vec![-1_i64, 0, 1], vec![2, 2, -1]])
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_permutation() {
+        let vector = Vector::from_vector(vec![10_i64, 20, 30]);
+        assert_eq!(
+            vector.permute(&[2, 0, 1]),
+            Vector::from_vector(vec![30, 10, 20])
+        );
+
+        let basis: Matrix<i64> = Matrix::from_matrix(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            basis.permute_rows(&[1, 2, 0]),
+            Matrix::from_matrix(vec![vec![2, 3, 1], vec![5, 6, 4]])
+        );
+
+        let permutation = generator::permutation(20, &mut SeededRng::new(5));
+        let mut sorted = permutation.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+        assert_ne!(permutation, sorted);
+
+        let vector: Vector<i64> = (0..20).collect();
+        assert_eq!(
+            vector.permute(&permutation),
+            permutation.iter().map(|&j| j as i64).collect()
+        );
+    }
+
+    #[test]
+    fn test_matrix_transform() {
+        let basis: Matrix<i64> = Matrix::from_matrix(vec![
+            vec![1, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let reduction = Transform::new(Matrix::from_matrix(vec![
+            vec![1, 1, -9],
+            vec![0, 9, -2],
+            vec![1, -3, -8],
+        ]));
+        let mut reduced = basis.clone();
+        l2::lll_i64(&mut reduced, 0.501, 0.998).unwrap();
+        assert_eq!(basis.apply_transform(&reduction), reduced);
+        assert_eq!(
+            basis.apply_transform(&Transform::identity(3)),
+            basis.clone()
+        );
+
+        // The last coordinates follow the combinations of the basis vectors
+        let last: Matrix<i64> = (0..3)
+            .map(|i| Vector::from_vector(vec![basis[i][3]]))
+            .collect();
+        assert!((0..3).all(|i| last.apply_transform(&reduction)[i][0] == reduced[i][3]));
+
+        // Swap of the first two vectors after the reduction
+        let swap = Transform::new(Matrix::from_matrix(vec![
+            vec![0, 1, 0],
+            vec![1, 0, 0],
+            vec![0, 0, 1],
+        ]));
+        let composed = reduction.compose(&swap);
+        assert_eq!(
+            composed.matrix(),
+            &Matrix::from_matrix(vec![vec![0, 9, -2], vec![1, 1, -9], vec![1, -3, -8]])
+        );
+        assert_eq!(
+            basis.apply_transform(&composed),
+            reduced.apply_transform(&swap)
+        );
+    }
+
+    #[test]
+    fn test_matrix_column_operations() {
+        let initial: Matrix<i64> =
+            Matrix::from_matrix(vec![vec![1, 0, 1345], vec![0, 1, 35], vec![0, 0, 154]]);
+        let mut basis = initial.clone();
+        let mut transform = Transform::identity(3);
+
+        basis.column_add_multiple(0, 2, &-8, Some(&mut transform));
+        basis.column_negate(1, Some(&mut transform));
+        basis.column_swap(0, 2, Some(&mut transform));
+        basis.column_add_multiple(1, 0, &3, None);
+
+        assert_eq!(
+            basis,
+            Matrix::from_matrix(vec![vec![0, 0, 154], vec![0, -1, 427], vec![1, 0, 113]])
+        );
+        assert_eq!(
+            transform.matrix(),
+            &Matrix::from_matrix(vec![vec![0, 0, 1], vec![0, -1, 0], vec![1, 0, -8]])
+        );
+        // The untracked operation desynchronizes the transform from the basis
+        assert_eq!(initial.apply_transform(&transform)[0], basis[0]);
+        assert_ne!(initial.apply_transform(&transform), basis);
+    }
+
+    #[test]
+    fn test_matrix_rref() {
+        // The echelon form of integers would not be over a field: [[2, 1], [4, 2]] has rank 1
+        let floats: Matrix<f64> = Matrix::from_matrix(vec![vec![2., 1.], vec![4., 2.]]);
+        let (rref, pivots) = floats.rref(1e-12);
+        assert_eq!(pivots, vec![0]);
+        assert_eq!(rref, Matrix::from_matrix(vec![vec![1., 0.5], vec![0., 0.]]));
+        let nearly: Matrix<f64> = Matrix::from_matrix(vec![vec![1., 1.], vec![1., 1. + 1e-14]]);
+        assert_eq!(nearly.rref(1e-12).1, vec![0]);
+        assert_eq!(nearly.rref(0.).1, vec![0, 1]);
+
+        #[cfg(feature = "rug")]
+        {
+            let basis = Matrix::<rug::Integer>::from(vec![
+                vec![2_i64, 4, 1, 3],
+                vec![1, 2, 1, 1],
+                vec![3, 6, 2, 4],
+                vec![0, 0, 1, -1],
+            ]);
+            let (rref, pivots) = basis.map(|n| rug::Rational::from(n)).rref();
+            assert_eq!(pivots, vec![0, 2]);
+            assert_eq!(
+                rref,
+                Matrix::<rug::Integer>::from(vec![
+                    vec![1_i64, 2, 0, 2],
+                    vec![0, 0, 1, -1],
+                    vec![0, 0, 0, 0],
+                    vec![0, 0, 0, 0],
+                ])
+                .map(|n| rug::Rational::from(n))
+            );
+            assert_eq!(basis.rank(), 2);
+
+            let (rref, pivots) = Matrix::<rug::Rational>::from_matrix(vec![
+                vec![rug::Rational::from((1, 2)), rug::Rational::from(1)],
+                vec![rug::Rational::from(1), rug::Rational::from((1, 3))],
+            ])
+            .rref();
+            assert_eq!(pivots, vec![0, 1]);
+            assert_eq!(rref, Matrix::identity(2));
+        }
+    }
+
+    #[test]
+    fn test_matrix_pad_columns() {
+        let vector = Vector::from_vector(vec![1_i64, -2]);
+        assert_eq!(
+            vector.extended(3, 7),
+            Vector::from_vector(vec![1, -2, 7, 7, 7])
+        );
+        assert_eq!(vector.extended(0, 7), vector);
+
+        let basis: Matrix<i64> = Matrix::from_matrix(vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+        let padded = basis.pad_columns(1, 0);
+        assert_eq!(padded.dimensions(), (3, 3));
+        assert_eq!(
+            padded,
+            Matrix::from_matrix(vec![vec![1, 2, 0], vec![3, 4, 0], vec![5, 6, 0]])
+        );
+        assert_eq!(
+            Matrix::<i64>::init(0, 2).pad_columns(2, 1).dimensions(),
+            (0, 4)
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_entry_size_stats() {
+        let basis: Matrix<i64> = Matrix::from_matrix(vec![vec![0, -1, 8], vec![255, 3, -256]]);
+        assert_eq!(basis.max_bits(), 9);
+        assert_eq!(
+            basis.entry_size_stats(),
+            EntrySizeStats {
+                max_bits: 9,
+                mean_bits: 4.
+            }
+        );
+        assert_eq!(Matrix::<i64>::init(0, 0).entry_size_stats().mean_bits, 0.);
+
+        let mut large = Matrix::<rug::Integer>::from(vec![vec![0_i64, -1, 8], vec![255, 3, -256]]);
+        assert_eq!(large.entry_size_stats(), basis.entry_size_stats());
+        large[0][0] = rug::Integer::from(1) << 100;
+        assert_eq!(large.max_bits(), 101);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_determinant() {
+        type I = rug::Integer;
+        let entries = [vec![0, 2, 1], vec![1, 1, 1], vec![2, 1, 3]];
+        let matrix: Matrix<I> = Matrix::from_matrix(
+            entries
+                .iter()
+                .map(|v| v.iter().map(|&x| I::from(x)).collect())
+                .collect(),
+        );
+        assert_eq!(matrix.determinant(), -3);
+        let matrix: Matrix<f64> = Matrix::from_matrix(
+            entries
+                .iter()
+                .map(|v| v.iter().map(|&x| f64::from(x)).collect())
+                .collect(),
+        );
+        assert!((matrix.determinant() + 3.).abs() < 1e-12);
+
+        // Unimodular transformation of the reduction of test_l2f
+        let unimodular: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(1), I::from(1), I::from(-9)],
+            vec![I::from(0), I::from(9), I::from(-2)],
+            vec![I::from(1), I::from(-3), I::from(-8)],
+        ]);
+        assert_eq!(unimodular.determinant(), 1);
+
+        let singular: Matrix<f64> =
+            Matrix::from_matrix(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![2., 4., 6.]]);
+        assert_eq!(singular.determinant(), 0.);
+        assert_eq!(Matrix::<I>::init(0, 0).determinant(), 1);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_inverse() {
+        type I = rug::Integer;
+        type Q = rug::Rational;
+        let matrix: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(0), I::from(2), I::from(1)],
+            vec![I::from(1), I::from(1), I::from(1)],
+            vec![I::from(2), I::from(1), I::from(3)],
+        ]);
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(inverse[0][0], Q::from((-2, 3)));
+
+        let rational: Matrix<Q> = Matrix::from_matrix(
+            (0..3)
+                .map(|i| (0..3).map(|j| Q::from(&matrix[i][j])).collect())
+                .collect(),
+        );
+        let identity: Matrix<Q> = Matrix::identity(3);
+        assert_eq!(&rational * &inverse, identity);
+        assert_eq!(&inverse * &rational, identity);
+        assert_eq!(rational.inverse().unwrap(), inverse);
+
+        let singular: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(1), I::from(2)],
+            vec![I::from(2), I::from(4)],
+        ]);
+        assert!(singular.inverse().is_none());
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_matrix_rank() {
+        type I = rug::Integer;
+        let entries = [
+            vec![2, 4, 1, 3],
+            vec![1, 2, 1, 1],
+            vec![3, 6, 2, 4],
+            vec![0, 0, 1, -1],
+        ];
+        let matrix: Matrix<I> = Matrix::from_matrix(
+            entries
+                .iter()
+                .map(|v| v.iter().map(|&x| I::from(x)).collect())
+                .collect(),
+        );
+        assert_eq!(matrix.rank(), 2);
+        let floats: Matrix<f64> = Matrix::from_matrix(
+            entries
+                .iter()
+                .map(|v| v.iter().map(|&x| f64::from(x)).collect())
+                .collect(),
+        );
+        assert_eq!(floats.rank(1e-12), 2);
+
+        // Nearly dependent vectors only count with a small enough tolerance
+        let nearly: Matrix<f64> = Matrix::from_matrix(vec![vec![1., 1.], vec![1., 1. + 1e-9]]);
+        assert_eq!(nearly.rank(1e-12), 2);
+        assert_eq!(nearly.rank(1e-6), 1);
+
+        assert_eq!(Matrix::<I>::init(3, 2).rank(), 0);
+        assert_eq!(Matrix::<f64>::init(0, 0).rank(1e-12), 0);
+        let unimodular: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(1), I::from(1), I::from(-9)],
+            vec![I::from(0), I::from(9), I::from(-2)],
+            vec![I::from(1), I::from(-3), I::from(-8)],
+        ]);
+        assert_eq!(unimodular.rank(), 3);
+    }
 
     #[test]
+    #[allow(deprecated)]
     fn test_lllf() {
         // "Bad" lattice basis
         let mut basis: Matrix<f64> = Matrix::from_matrix(vec![
@@ -80,10 +1138,12 @@ mod test {
             vec![1.0, 348.0, -88.0, -27.0],
         ]);
 
-        assert_eq!(basis, result);
+        assert!(basis.approx_eq(&result, 1e-9));
     }
 
+    #[cfg(feature = "rug")]
     #[test]
+    #[allow(deprecated)]
     fn test_biglll() {
         type I = rug::Integer;
         // "Bad" lattice basis
@@ -110,7 +1170,7 @@ mod test {
         println!("{:?}", basis);
 
         // "Good" lattice basis
-        l2::lll_float(&mut basis, 0.501, 0.998);
+        l2::lll_float(&mut basis, 0.501, 0.998).unwrap();
         println!("{:?}", basis);
 
         let result: Matrix<f64> = Matrix::from_matrix(vec![
@@ -119,38 +1179,1085 @@ mod test {
             vec![1.0, -3.0, -8.0, 8.0],
         ]);
 
+        assert!(basis.approx_eq(&result, 1e-9));
+    }
+
+    #[test]
+    fn test_l2f32() {
+        let mut basis: Vec<Vec<f32>> = vec![
+            vec![1., 0., 0., 1345.],
+            vec![0., 1., 0., 35.],
+            vec![0., 0., 1., 154.],
+        ];
+        l2::lll_float32(&mut basis, 0.501, 0.998).unwrap();
+
+        let result: Vec<Vec<f32>> = vec![
+            vec![1.0, 1.0, -9.0, -6.0],
+            vec![0.0, 9.0, -2.0, 7.0],
+            vec![1.0, -3.0, -8.0, 8.0],
+        ];
+
         assert_eq!(basis, result);
     }
 
     #[test]
-    fn test_bigl2() {
-        type I = rug::Integer;
-        let mut basis: Matrix<I> = Matrix::from_matrix(vec![
-            vec![I::from(1), I::from(2), I::from(3)],
-            vec![I::from(4), I::from(5), I::from(6)],
-            vec![I::from(7), I::from(8), I::from(9)],
+    fn test_l2f_householder() {
+        let mut basis: Matrix<f64> = Matrix::from_matrix(vec![
+            vec![1., 0., 0., 1345.],
+            vec![0., 1., 0., 35.],
+            vec![0., 0., 1., 154.],
         ]);
-        println!("{:?}", basis);
+        l2::Reduction::new(l2::LllParams::new(0.998, 0.501).unwrap())
+            .gso(l2::FloatGso::Householder)
+            .float(&mut basis)
+            .run()
+            .unwrap();
 
-        l2::lll_bignum(&mut basis, 0.6, 0.95);
-        println!("{:?}", basis);
+        let result: Matrix<f64> = Matrix::from_matrix(vec![
+            vec![1.0, 1.0, -9.0, -6.0],
+            vec![0.0, 9.0, -2.0, 7.0],
+            vec![1.0, -3.0, -8.0, 8.0],
+        ]);
+        assert!(basis.approx_eq(&result, 1e-9));
 
-        let result: Matrix<I> = Matrix::from_matrix(vec![
-            vec![I::from(0), I::from(0), I::from(0)],
-            vec![I::from(2), I::from(1), I::from(0)],
-            vec![I::from(-1), I::from(1), I::from(3)],
+        // Linearly dependent vectors are reduced to zero
+        let mut basis: Matrix<f64> =
+            Matrix::from_matrix(vec![vec![2., 4.], vec![1., 2.], vec![3., 1.], vec![5., 7.]]);
+        l2::Reduction::new(l2::LllParams::default())
+            .gso(l2::FloatGso::Householder)
+            .float(&mut basis)
+            .run()
+            .unwrap();
+        assert!(basis[0].is_zero() && basis[1].is_zero());
+    }
+
+    #[test]
+    fn test_l2_leading_zero() {
+        // A zero first vector has no Gram-Schmidt coefficients, and is moved out of the way of the reduction
+        let entries = vec![vec![0, 0], vec![1, 2], vec![3, 1]];
+        let mut reduced: Matrix<i64> = Matrix::from_matrix(entries[1..].to_vec());
+        l2::lll_i64(&mut reduced, 0.501, 0.998).unwrap();
+        let expected = Matrix::from_columns(vec![
+            Vector::init(2),
+            reduced[0].clone(),
+            reduced[1].clone(),
         ]);
-        assert_eq!(basis, result);
+
+        let mut basis: Matrix<i64> = Matrix::from_matrix(entries.clone());
+        l2::lll_i64(&mut basis, 0.501, 0.998).unwrap();
+        assert_eq!(basis, expected);
+
+        let mut basis = Matrix::from_matrix(entries.clone()).map(|&n: &i64| n as f64);
+        let mut transform = Transform::identity(3);
+        l2::Reduction::new(l2::LllParams::new(0.998, 0.501).unwrap())
+            .transform(&mut transform)
+            .float(&mut basis)
+            .run()
+            .unwrap();
+        assert_eq!(basis, expected.map(|&n| n as f64));
+        assert_eq!(
+            l2::relations(&basis, &transform),
+            vec![Vector::from_vector(vec![1., 0., 0.])]
+        );
+
+        #[cfg(feature = "num")]
+        {
+            let mut basis =
+                Matrix::from_matrix(entries.clone()).map(|&n: &i64| num_bigint::BigInt::from(n));
+            l2::lll_num_bigint(&mut basis, 0.501, 0.998).unwrap();
+            assert_eq!(basis, expected.map(|&n| num_bigint::BigInt::from(n)));
+        }
+        #[cfg(feature = "rug")]
+        {
+            let mut basis = Matrix::<rug::Integer>::from(entries);
+            l2::lll_bignum(&mut basis, 0.501, 0.998).unwrap();
+            assert_eq!(basis, expected.map(|&n| rug::Integer::from(n)));
+        }
     }
 
     #[test]
-    fn test_bigl2_ntrulike() {
-        type I = rug::Integer;
-        let mut basis: Matrix<I> = Matrix::from_matrix(vec![
-            vec![
-                I::from(1),
-                I::from(0),
-                I::from(0),
+    fn test_l2f_non_finite() {
+        // The squared norm of the second vector overflows an f64
+        let entries = vec![vec![1., 0., 0., 1345.], vec![0., 1e200, 0., 35.]];
+        let mut basis: Matrix<f64> = Matrix::from_matrix(entries.clone());
+        assert_eq!(
+            l2::lll_float(&mut basis, 0.501, 0.998),
+            Err(l2::NonFinite { index: 1 }.into())
+        );
+        assert_eq!(basis, Matrix::from_matrix(entries.clone()));
+
+        // The steps stop at the error, with the initial basis
+        let mut basis: Matrix<f64> = Matrix::from_matrix(entries.clone());
+        let mut reducer = l2::Reduction::default().float(&mut basis);
+        assert_eq!(reducer.step(), Some(Ok(l2::Event::Tour { tour: 0 })));
+        assert_eq!(reducer.step(), Some(Err(l2::NonFinite { index: 1 }.into())));
+        assert_eq!(reducer.step(), None);
+        assert_eq!(basis, Matrix::from_matrix(entries));
+
+        let mut basis: Vec<Vec<f32>> = vec![vec![f32::NAN, 1.], vec![0., 1.]];
+        assert_eq!(
+            l2::lll_float32(&mut basis, 0.501, 0.998),
+            Err(l2::NonFinite { index: 0 }.into())
+        );
+    }
+
+    #[test]
+    fn test_l2_small() {
+        let entries = [[1, 0, 0, 1345], [0, 1, 0, 35], [0, 0, 1, 154]];
+        let result = [[1, 1, -9, -6], [0, 9, -2, 7], [1, -3, -8, 8]];
+
+        let mut basis = SMatrix::new(entries);
+        l2::small::lll_i64(&mut basis, 0.501, 0.998).unwrap();
+        assert_eq!(basis.into_inner(), result);
+
+        let mut basis = SMatrix::new(entries).map(|&n| n as f64);
+        l2::small::lll_float(&mut basis, 0.501, 0.998).unwrap();
+        assert!(basis == SMatrix::new(result).map(|&n| n as f64));
+
+        let mut basis = SMatrix::new([[1 << 40, 0], [0, 1]]);
+        assert_eq!(l2::small::lll_i64(&mut basis, 0.501, 0.998), Err(Overflow));
+        assert_eq!(basis[0][0], 1 << 40);
+    }
+
+    #[test]
+    fn test_l2_reduction() {
+        // The options of a reduction combine
+        let initial: Matrix<f64> = Matrix::from_matrix(vec![
+            vec![1., 0., 0., 1345.],
+            vec![0., 1., 0., 35.],
+            vec![0., 0., 1., 154.],
+        ]);
+        let mut expected = initial.clone();
+        l2::lll_float(&mut expected, 0.51, 0.99).unwrap();
+
+        let mut basis = initial.clone();
+        let mut transform = Transform::identity(3);
+        let mut events = Vec::new();
+        let mut sink = l2::EventSink::new(l2::Verbosity::Summary, |event| events.push(event));
+        let report = l2::Reduction::new(l2::LllParams::default())
+            .transform(&mut transform)
+            .tracer(&mut sink)
+            .max_iterations(100)
+            .stats()
+            .profile()
+            .health()
+            .float(&mut basis)
+            .run()
+            .unwrap();
+        assert_eq!(basis, expected);
+        assert_eq!(initial.apply_transform(&transform), basis);
+        let stats = report.stats.unwrap();
+        assert_eq!(
+            events,
+            vec![l2::Event::Summary {
+                swaps: stats.swaps,
+                deep_insertions: stats.deep_insertions,
+                size_reductions: stats.size_reductions
+            }]
+        );
+        assert_eq!(report.profile.map(|profile| profile.len()), Some(3));
+        assert_eq!(report.health.unwrap().borderline_decisions, 0);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_transform() {
+        let basis = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut reduced = basis.clone();
+        let mut transform = Transform::identity_with_inverse(3);
+        l2::Reduction::new(l2::LllParams::new(0.998, 0.501).unwrap())
+            .transform(&mut transform)
+            .bignum(&mut reduced)
+            .run()
+            .unwrap();
+
+        let mut expected = basis.clone();
+        l2::lll_bignum(&mut expected, 0.501, 0.998).unwrap();
+        assert_eq!(reduced, expected);
+        assert_eq!(basis.apply_transform(&transform), reduced);
+
+        // The inverse maps the reduced basis back to the initial one
+        let inverse = transform.inverse().unwrap();
+        assert_eq!(inverse * &reduced, basis);
+        assert_eq!(transform.matrix() * inverse, Matrix::identity(3));
+
+        // Without tracking the inverse, on floating-point numbers
+        let initial = basis.map(|x| x.to_f64());
+        let mut reduced = initial.clone();
+        let mut transform = Transform::identity(3);
+        l2::Reduction::new(l2::LllParams::new(0.998, 0.501).unwrap())
+            .transform(&mut transform)
+            .float(&mut reduced)
+            .run()
+            .unwrap();
+        assert_eq!(initial.apply_transform(&transform), reduced);
+        assert_eq!(transform.inverse(), None);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_stats() {
+        let mut basis = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut float = basis.map(|x| x.to_f64());
+        let params = l2::LllParams::new(0.998, 0.501).unwrap();
+        let stats = l2::Reduction::new(params)
+            .stats()
+            .bignum(&mut basis)
+            .run()
+            .unwrap()
+            .stats
+            .unwrap();
+        let float_stats = l2::Reduction::new(params)
+            .stats()
+            .float(&mut float)
+            .run()
+            .unwrap()
+            .stats
+            .unwrap();
+
+        assert_eq!(stats.swaps, 2);
+        assert_eq!(stats.deep_insertions, 2);
+        assert_eq!(stats.size_reductions, 5);
+        // One initial computation per pass, and one per insertion
+        assert_eq!(stats.gram_recomputations, 2 + 4);
+        assert_eq!(
+            ReductionStats {
+                log_potential: stats.log_potential,
+                ..float_stats
+            },
+            stats
+        );
+
+        let norms = quality::basis_quality_bignum(&basis).gso_norms;
+        let log_potential: f64 = (0..3).map(|i| 2. * (3 - i) as f64 * norms[i].log2()).sum();
+        assert!((stats.log_potential - log_potential).abs() < 1e-9);
+        assert!((float_stats.log_potential - log_potential).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_progress() {
+        let mut basis = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut reports = vec![];
+        l2::Reduction::new(l2::LllParams::new(0.998, 0.501).unwrap())
+            .progress(&mut |progress| reports.push(progress))
+            .bignum(&mut basis)
+            .run()
+            .unwrap();
+
+        let mut expected = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        l2::lll_bignum(&mut expected, 0.501, 0.998).unwrap();
+        assert_eq!(basis, expected);
+
+        assert!(reports
+            .iter()
+            .enumerate()
+            .all(|(i, progress)| progress.work == i
+                && progress.dimension == 3
+                && (1..3).contains(&progress.kappa)));
+        assert_eq!(reports.first().map(|progress| progress.tour), Some(0));
+        assert_eq!(reports.last().map(|progress| progress.tour), Some(1));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_time_limit() {
+        use std::time::Duration;
+
+        let initial = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut expected = initial.clone();
+        l2::lll_bignum(&mut expected, 0.501, 0.998).unwrap();
+
+        let params = l2::LllParams::new(0.998, 0.501).unwrap();
+        let time_limit = |basis: &mut Matrix<rug::Integer>, time_limit| {
+            l2::Reduction::new(params)
+                .time_limit(time_limit)
+                .bignum(basis)
+                .run()
+        };
+
+        let mut basis = initial.clone();
+        match time_limit(&mut basis, Duration::ZERO) {
+            Err(l2::ReductionError::Timeout(l2::Timeout { progress })) => {
+                assert_eq!((progress.kappa, progress.tour, progress.work), (1, 0, 0))
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert_eq!(basis, initial);
+
+        let mut basis = initial.clone();
+        time_limit(&mut basis, Duration::from_secs(60)).unwrap();
+        assert_eq!(basis, expected);
+
+        // The time limit starts with the run
+        let mut basis = initial.clone();
+        let mut reducer = l2::Reduction::new(params)
+            .time_limit(Duration::from_millis(100))
+            .bignum(&mut basis);
+        std::thread::sleep(Duration::from_millis(200));
+        reducer.run().unwrap();
+        assert_eq!(basis, expected);
+        let mut basis = initial.clone();
+        time_limit(&mut basis, Duration::MAX).unwrap();
+        assert_eq!(basis, expected);
+
+        let mut float = initial.map(|x| x.to_f64());
+        assert!(matches!(
+            l2::Reduction::new(params)
+                .time_limit(Duration::ZERO)
+                .float(&mut float)
+                .run(),
+            Err(l2::ReductionError::Timeout(_))
+        ));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_checkpoint() {
+        use std::time::Duration;
+
+        let initial = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut expected = initial.clone();
+        l2::lll_bignum(&mut expected, 0.501, 0.998).unwrap();
+
+        let params = l2::LllParams::new(0.998, 0.501).unwrap();
+        let mut basis = initial.clone();
+        let mut reducer = l2::Reduction::new(params)
+            .time_limit(Duration::ZERO)
+            .bignum(&mut basis);
+        assert!(reducer.run().is_err());
+        let checkpoint = reducer.checkpoint();
+        assert!(!checkpoint.is_complete());
+
+        let text = checkpoint.to_string();
+        assert_eq!(
+            text,
+            "tour 0\nkappa 1\nwork 0\n1 0 0 1345\n0 1 0 35\n0 0 1 154\n"
+        );
+        let mut resumed: l2::Checkpoint<rug::Integer> = text.parse().unwrap();
+        assert_eq!(resumed, checkpoint);
+        let mut reducer = l2::Reduction::new(params)
+            .resume(&resumed)
+            .bignum(&mut resumed.basis);
+        reducer.run().unwrap();
+        assert!(reducer.checkpoint().is_complete());
+        assert_eq!(resumed.basis, expected);
+
+        // A reduction stopped in its second tour resumes there, at the vector where it stopped
+        let mut basis = initial.clone();
+        let mut reducer = l2::Reduction::new(params)
+            .max_iterations(9)
+            .bignum(&mut basis);
+        assert!(reducer.run().is_err());
+        let mut checkpoint = reducer.checkpoint();
+        assert_eq!(
+            (checkpoint.tour, checkpoint.kappa, checkpoint.work),
+            (1, 2, 9)
+        );
+        let mut reports = vec![];
+        l2::Reduction::new(params)
+            .resume(&checkpoint)
+            .progress(&mut |progress| reports.push(progress))
+            .bignum(&mut checkpoint.basis)
+            .run()
+            .unwrap();
+        assert_eq!(checkpoint.basis, expected);
+        assert_eq!(
+            reports
+                .first()
+                .map(|progress| (progress.tour, progress.kappa, progress.work)),
+            Some((1, 2, 9))
+        );
+
+        // The lines of the errors count the blank lines
+        assert_eq!(
+            "tour 1\nkappa 1\nwork 2\n1 2\n3\n".parse::<l2::Checkpoint<rug::Integer>>(),
+            Err(l2::ParseCheckpointError { line: 4 })
+        );
+        assert_eq!(
+            "tour 1\nkappa 1\nwork 2\n\n1 2\n\n3\n".parse::<l2::Checkpoint<rug::Integer>>(),
+            Err(l2::ParseCheckpointError { line: 6 })
+        );
+        assert_eq!(
+            "tour 1\nkappa 1\n".parse::<l2::Checkpoint<rug::Integer>>(),
+            Err(l2::ParseCheckpointError { line: 2 })
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_iteration_limit() {
+        let initial = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut expected = initial.clone();
+        l2::lll_bignum(&mut expected, 0.501, 0.998).unwrap();
+
+        let params = l2::LllParams::new(0.998, 0.501).unwrap();
+        let mut basis = initial.clone();
+        let incomplete = l2::Reduction::new(params)
+            .max_iterations(9)
+            .bignum(&mut basis)
+            .run();
+        match incomplete {
+            Err(l2::ReductionError::IterationLimit(l2::IterationLimit { progress })) => {
+                assert_eq!((progress.kappa, progress.tour, progress.work), (2, 1, 9))
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert_ne!(basis, initial);
+
+        // The reduction goes on when it is run again, with as many iterations
+        let mut basis = initial.clone();
+        let mut reducer = l2::Reduction::new(params)
+            .max_iterations(9)
+            .bignum(&mut basis);
+        assert!(reducer.run().is_err());
+        reducer.run().unwrap();
+        assert_eq!(basis, expected);
+
+        let mut basis = initial.clone();
+        l2::Reduction::new(params)
+            .max_iterations(10)
+            .bignum(&mut basis)
+            .run()
+            .unwrap();
+        assert_eq!(basis, expected);
+
+        let mut float = initial.map(|x| x.to_f64());
+        assert!(matches!(
+            l2::Reduction::new(params)
+                .max_iterations(0)
+                .float(&mut float)
+                .run(),
+            Err(l2::ReductionError::IterationLimit(_))
+        ));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_trace() {
+        let mut basis = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut events = Vec::new();
+        let mut sink = l2::EventSink::new(l2::Verbosity::PerSwap, |event| events.push(event));
+        l2::Reduction::new(l2::LllParams::new(0.998, 0.501).unwrap())
+            .tracer(&mut sink)
+            .bignum(&mut basis)
+            .run()
+            .unwrap();
+
+        assert_eq!(events[0], l2::Event::Tour { tour: 0 });
+        assert_eq!(events[1], l2::Event::Insertion { from: 1, to: 0 });
+        assert_eq!(events[1].to_string(), "event=insertion from=1 to=0");
+        let size_reductions = events
+            .iter()
+            .filter(|event| matches!(event, l2::Event::SizeReduction { .. }))
+            .count();
+        assert_eq!(size_reductions, 5);
+        assert_eq!(
+            events.last(),
+            Some(&l2::Event::Summary {
+                swaps: 2,
+                deep_insertions: 2,
+                size_reductions: 5
+            })
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_profile() {
+        let mut basis = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let reduce = |basis: &mut Matrix<rug::Integer>| {
+            l2::Reduction::new(l2::LllParams::new(0.998, 0.501).unwrap())
+                .profile()
+                .bignum(basis)
+                .run()
+                .unwrap()
+                .profile
+                .unwrap()
+        };
+        let profile = reduce(&mut basis);
+        let norms = quality::basis_quality_bignum(&basis).gso_norms;
+        assert_eq!(profile.len(), 3);
+        assert!(profile
+            .iter()
+            .zip(&norms)
+            .all(|(log_norm, norm)| (log_norm - norm.log2()).abs() < 1e-9));
+
+        // Zero vectors are not part of the profile
+        let mut basis = Matrix::<rug::Integer>::from(vec![vec![1_i64, 2], vec![2, 4], vec![1, 1]]);
+        assert_eq!(reduce(&mut basis), vec![0., 0.]);
+    }
+
+    #[test]
+    fn test_l2_health() {
+        // The Lovász condition of the second vector holds with equality, (0.98 + 1) / 2 * 100 = 99, in each tour
+        let mut basis = Matrix::<f64>::from(vec![vec![10_i64, 0, 0], vec![1, 7, 7]]);
+        let params = l2::LllParams::new(0.98, 0.51).unwrap();
+        let health = l2::Reduction::new(params)
+            .health()
+            .float(&mut basis)
+            .run()
+            .unwrap()
+            .health
+            .unwrap();
+        assert_eq!(health.borderline_decisions, 2);
+        assert!((health.min_r - 98.).abs() < 1e-9);
+        assert_eq!(health.precision_loss, 0.);
+
+        #[cfg(feature = "rug")]
+        {
+            let mut basis = Matrix::<rug::Integer>::from(vec![
+                vec![1_i64, 0, 0, 1345],
+                vec![0, 1, 0, 35],
+                vec![0, 0, 1, 154],
+            ]);
+            let health = l2::Reduction::new(l2::LllParams::default())
+                .health()
+                .bigfloat(&mut basis, 128)
+                .run()
+                .unwrap()
+                .health
+                .unwrap();
+            assert_eq!(health.borderline_decisions, 0);
+            assert!(health.min_r >= 1.);
+            // The second vector is size-reduced by 1345 / 35
+            assert!(health.precision_loss > 5.);
+
+            // The factor (0.98 + 1) / 2 rounded to a double is not borderline with 128 bits of precision
+            let mut basis = Matrix::<rug::Integer>::from(vec![vec![10_i64, 0, 0], vec![1, 7, 7]]);
+            let health = l2::Reduction::new(params)
+                .health()
+                .bigfloat(&mut basis, 128)
+                .run()
+                .unwrap()
+                .health
+                .unwrap();
+            assert_eq!(health.borderline_decisions, 0);
+        }
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_relations() {
+        let initial = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 2, 3],
+            vec![2, 4, 6],
+            vec![1, 1, 1],
+            vec![3, 3, 3],
+            vec![0, 1, 0],
+        ]);
+        let kernel = |basis: &Matrix<rug::Integer>| {
+            let mut reduced = basis.clone();
+            let (d, _) = basis.dimensions();
+            let mut transform = Transform::identity(d);
+            l2::Reduction::new(l2::LllParams::new(0.998, 0.501).unwrap())
+                .transform(&mut transform)
+                .bignum(&mut reduced)
+                .run()
+                .unwrap();
+            l2::relations(&reduced, &transform)
+        };
+        let relations = kernel(&initial);
+        assert_eq!(relations.len(), 2);
+        for relation in &relations {
+            assert!(!relation.is_zero());
+            let combination = (0..5).fold(Vector::zero(3), |sum, j| {
+                sum + &(&initial[j] * &relation[j])
+            });
+            assert!(combination.is_zero());
+        }
+
+        let basis = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        assert!(kernel(&basis).is_empty());
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_steps() {
+        let initial = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut expected = initial.clone();
+        l2::lll_bignum(&mut expected, 0.501, 0.998).unwrap();
+
+        let mut basis = initial.clone();
+        let params = l2::LllParams::new(0.998, 0.501).unwrap();
+        let mut reducer = l2::Reduction::new(params).bignum(&mut basis);
+        assert_eq!(reducer.step(), Some(Ok(l2::Event::Tour { tour: 0 })));
+        assert_eq!(reducer.basis(), &initial);
+        assert_eq!(
+            reducer.step(),
+            Some(Ok(l2::Event::Insertion { from: 1, to: 0 }))
+        );
+        assert_eq!(reducer.basis()[0], initial[1]);
+        let events = reducer.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(events.len(), 10);
+        assert!(matches!(events.last(), Some(l2::Event::Summary { .. })));
+        assert_eq!(reducer.step(), None);
+        assert_eq!(basis, expected);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_tracer() {
+        #[derive(Default)]
+        struct Recorder {
+            swaps: Vec<usize>,
+            insertions: Vec<(usize, usize)>,
+            size_reductions: Vec<(usize, Vec<rug::Integer>)>,
+            gram_recomputations: usize,
+        }
+
+        impl l2::Tracer<rug::Integer> for Recorder {
+            fn swap(&mut self, kappa: usize) {
+                self.swaps.push(kappa);
+            }
+
+            fn insertion(&mut self, from: usize, to: usize) {
+                self.insertions.push((from, to));
+            }
+
+            fn size_reduce(&mut self, kappa: usize, factors: &[rug::Integer]) {
+                self.size_reductions.push((kappa, factors.to_vec()));
+            }
+
+            fn gram_recompute(&mut self, gram: &Matrix<rug::Integer>) {
+                assert_eq!(gram.dimensions(), (3, 3));
+                self.gram_recomputations += 1;
+            }
+        }
+
+        let mut basis = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut recorder = Recorder::default();
+        l2::Reduction::new(l2::LllParams::new(0.998, 0.501).unwrap())
+            .tracer(&mut recorder)
+            .bignum(&mut basis)
+            .run()
+            .unwrap();
+
+        assert_eq!(recorder.swaps, vec![1, 1]);
+        assert_eq!(recorder.insertions, vec![(2, 0), (2, 0)]);
+        assert_eq!(recorder.size_reductions.len(), 5);
+        assert_eq!(
+            recorder.size_reductions[0],
+            (1, vec![rug::Integer::from(38)])
+        );
+        assert_eq!(recorder.gram_recomputations, 6);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_verbosity() {
+        let initial = Matrix::<rug::Integer>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut expected = initial.clone();
+        l2::lll_bignum(&mut expected, 0.51, 0.99).unwrap();
+
+        let mut counts = Vec::new();
+        for verbosity in [
+            l2::Verbosity::Silent,
+            l2::Verbosity::Summary,
+            l2::Verbosity::PerTour,
+            l2::Verbosity::PerSwap,
+        ] {
+            let options = l2::Options {
+                verbosity,
+                ..l2::Options::default()
+            };
+            let mut basis = initial.clone();
+            let mut events = Vec::new();
+            l2::lll_with_options_and_trace(&mut basis, &options, |event| events.push(event))
+                .unwrap();
+            assert_eq!(basis, expected);
+            assert!(events.iter().all(|event| event.level() <= verbosity));
+            counts.push(events.len());
+        }
+        assert_eq!(&counts[..3], &[0, 1, 3]);
+        assert!(counts[3] > 3);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_lattice_reduce() {
+        use crate::l2::LatticeReduce;
+
+        let vectors = vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ];
+        let mut expected = Matrix::<rug::Integer>::from(vectors.clone());
+        l2::lll_bignum(&mut expected, 0.51, 0.99).unwrap();
+
+        let mut basis = Matrix::<rug::Integer>::from(vectors.clone());
+        basis.lll().unwrap();
+        assert_eq!(basis, expected);
+
+        let mut basis = Matrix::<rug::Integer>::from(vectors.clone());
+        basis.l2(0.51, 0.99).unwrap();
+        assert_eq!(basis, expected);
+
+        let mut float = Matrix::<f64>::from(vectors.clone());
+        float
+            .reduce(l2::LllParams::new(0.99, 0.51).unwrap())
+            .unwrap();
+        assert_eq!(float, expected.map(|x| x.to_f64()));
+
+        let mut machine: Matrix<i64> = Matrix::from_matrix(vectors);
+        assert_eq!(machine.lll(), Ok(()));
+        assert_eq!(machine[0].norm_sqr(), 119);
+    }
+
+    #[test]
+    fn test_l2_params() {
+        use crate::l2::{LatticeReduce, LllParams, ParamError};
+
+        let params = LllParams::new(0.998, 0.501).unwrap();
+        assert_eq!((params.delta(), params.eta()), (0.998, 0.501));
+        assert_eq!(LllParams::new(1., 0.51), Err(ParamError::Delta(1.)));
+        assert_eq!(LllParams::new(0.2, 0.51), Err(ParamError::Delta(0.2)));
+        assert_eq!(LllParams::new(0.51, 0.99), Err(ParamError::Eta(0.99)));
+        assert_eq!(LllParams::new(0.99, 0.5), Err(ParamError::Eta(0.5)));
+        assert_eq!(LllParams::default(), LllParams::fplll_default());
+        assert!(
+            LllParams::new(LllParams::strongest().delta(), LllParams::strongest().eta()).is_ok()
+        );
+
+        let mut basis = Matrix::<f64>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        let mut expected = basis.clone();
+        l2::lll_float(&mut expected, 0.501, 0.998).unwrap();
+        basis.reduce(params).unwrap();
+        assert_eq!(basis, expected);
+    }
+
+    #[test]
+    fn test_l2_reduced() {
+        use crate::l2::{LatticeReduce, LllParams};
+
+        #[cfg(feature = "rug")]
+        {
+            let basis = Matrix::<rug::Integer>::from(vec![
+                vec![1_i64, 0, 0, 1345],
+                vec![0, 1, 0, 35],
+                vec![0, 0, 1, 154],
+            ]);
+            let initial = basis.clone();
+            let reduced = basis.reduced(LllParams::fplll_default()).unwrap();
+            assert_eq!(basis, initial);
+
+            let mut expected = initial.clone();
+            expected.lll().unwrap();
+            assert_eq!(reduced, expected);
+        }
+
+        let float = Matrix::<f64>::from(vec![vec![1_i64, 0, 0, 1345], vec![0, 1, 0, 35]]);
+        let reduced = float.reduced(LllParams::fplll_default()).unwrap();
+        assert_eq!(float[0][3], 1345.);
+        assert_eq!(reduced[0].norm_sqr(), 1226.);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_l2_reduce_all() {
+        use crate::l2::{LatticeReduce, LllParams};
+
+        let mut bases: Vec<Matrix<rug::Integer>> = (0..20)
+            .map(|k| {
+                let (basis, _) =
+                    generator::usvp_bignum(5 + k % 4, 40, 3, &mut SeededRng::new(k as u64));
+                basis
+            })
+            .collect();
+        let expected: Vec<_> = bases
+            .iter()
+            .map(|basis| basis.reduced(LllParams::fplll_default()).unwrap())
+            .collect();
+        let results = l2::reduce_all(&mut bases, LllParams::fplll_default());
+        assert_eq!(results.len(), 20);
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(bases, expected);
+
+        let mut floats = vec![Matrix::<f64>::from(vec![
+            vec![1_i64, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+        ])];
+        assert_eq!(
+            l2::reduce_all(&mut floats, LllParams::default()),
+            vec![Ok(())]
+        );
+        assert!(l2::reduce_all::<Matrix<f64>>(&mut [], LllParams::default()).is_empty());
+    }
+
+    #[test]
+    fn test_l2_machine_integers() {
+        let mut basis: Matrix<i64> = Matrix::from_matrix(vec![
+            vec![1, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154],
+        ]);
+        l2::lll_i64(&mut basis, 0.501, 0.998).unwrap();
+
+        let result: Matrix<i64> = Matrix::from_matrix(vec![
+            vec![1, 1, -9, -6],
+            vec![0, 9, -2, 7],
+            vec![1, -3, -8, 8],
+        ]);
+        assert_eq!(basis, result);
+
+        // The Gram matrix overflows an i64, but not an i128
+        let entries = vec![
+            vec![1, 0, 0, 1 << 40],
+            vec![0, 1, 0, 35 << 20],
+            vec![0, 0, 1, 154 << 30],
+        ];
+        let mut basis: Matrix<i64> = Matrix::from_matrix(entries.clone());
+        assert_eq!(l2::lll_i64(&mut basis, 0.501, 0.998), Err(Overflow.into()));
+        assert_eq!(basis, Matrix::from_matrix(entries.clone()));
+
+        let mut wide: Matrix<i128> = Matrix::from_matrix(
+            entries
+                .iter()
+                .map(|v| v.iter().map(|&x| i128::from(x)).collect())
+                .collect(),
+        );
+        l2::lll_i128(&mut wide, 0.501, 0.998).unwrap();
+        #[cfg(feature = "rug")]
+        {
+            let mut exact: Matrix<rug::Integer> = Matrix::from_matrix(
+                entries
+                    .iter()
+                    .map(|v| v.iter().map(|&x| rug::Integer::from(x)).collect())
+                    .collect(),
+            );
+            l2::lll_bignum(&mut exact, 0.501, 0.998).unwrap();
+            for i in 0..3 {
+                for j in 0..4 {
+                    assert_eq!(exact[i][j], wide[i][j]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_backend() {
+        let entries = vec![vec![1, 0, 0, 1345], vec![0, 1, 0, 35], vec![0, 0, 1, 154]];
+        let mut basis: Matrix<i64> = Matrix::from_matrix(entries.clone());
+        l2::lll_fixed::<32>(&mut basis, 0.501, 0.998).unwrap();
+
+        let mut reference: Matrix<i64> = Matrix::from_matrix(entries.clone());
+        l2::lll_i64(&mut reference, 0.501, 0.998).unwrap();
+        assert_eq!(basis, reference);
+
+        // The Gram-Schmidt coefficients do not fit with so many fractional bits
+        let mut basis: Matrix<i64> = Matrix::from_matrix(entries.clone());
+        assert_eq!(
+            l2::lll_fixed::<60>(&mut basis, 0.501, 0.998),
+            Err(Overflow.into())
+        );
+        assert_eq!(basis, Matrix::from_matrix(entries));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_certified_backend() {
+        // The entries of the Gram matrix of the second basis overflow double floating-point numbers
+        for &(dimension, bits) in &[(12, 48), (10, 1100)] {
+            let (mut basis, _) = generator::usvp_bignum(dimension, bits, 5, &mut SeededRng::new(3));
+            let (mut exact, _) = generator::usvp_bignum(dimension, bits, 5, &mut SeededRng::new(3));
+
+            l2::lll_certified(&mut basis, 0.51, 0.99).unwrap();
+            l2::lll_bignum(&mut exact, 0.51, 0.99).unwrap();
+            assert!((0..dimension).all(|i| basis[i] == exact[i]));
+        }
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_precision_escalation() {
+        // Double floating-point numbers are enough for small entries
+        let (mut basis, _) = generator::usvp_bignum(12, 48, 5, &mut SeededRng::new(3));
+        let (mut exact, _) = generator::usvp_bignum(12, 48, 5, &mut SeededRng::new(3));
+        let options = l2::Options::default();
+        assert_eq!(
+            l2::lll_with_options(&mut basis, &options),
+            Ok(l2::Arithmetic::Double)
+        );
+        l2::lll_bignum(&mut exact, 0.51, 0.99).unwrap();
+        assert!((0..12).all(|i| basis[i] == exact[i]));
+
+        // The entries of the Gram matrix overflow double floating-point numbers
+        let (mut exact, _) = generator::usvp_bignum(10, 1100, 5, &mut SeededRng::new(3));
+        l2::lll_bignum(&mut exact, 0.51, 0.99).unwrap();
+        for (escalation, needed) in [
+            (options.escalation.clone(), l2::Arithmetic::Mpfr(128)),
+            (
+                vec![l2::Arithmetic::Double, l2::Arithmetic::Rational],
+                l2::Arithmetic::Rational,
+            ),
+        ] {
+            let (mut basis, _) = generator::usvp_bignum(10, 1100, 5, &mut SeededRng::new(3));
+            let options = l2::Options {
+                escalation,
+                ..l2::Options::default()
+            };
+            assert_eq!(l2::lll_with_options(&mut basis, &options), Ok(needed));
+            assert!((0..10).all(|i| basis[i] == exact[i]));
+        }
+
+        // The last arithmetic is trusted, but its non-finite numbers are reported
+        let (mut basis, _) = generator::usvp_bignum(10, 1100, 5, &mut SeededRng::new(3));
+        let initial = basis.clone();
+        let options = l2::Options {
+            escalation: vec![l2::Arithmetic::Double],
+            ..l2::Options::default()
+        };
+        assert!(l2::lll_with_options(&mut basis, &options).is_err());
+        assert_eq!(basis, initial);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_required_precision() {
+        assert_eq!(l2::required_precision(12, 0.51, 0.99), 34);
+        assert!(l2::required_precision(30, 0.51, 0.99) > 53);
+        assert!((1..200)
+            .all(|d| l2::required_precision(d, 0.51, 0.99)
+                < l2::required_precision(d + 1, 0.51, 0.99)));
+        assert!(l2::required_precision(50, 0.51, 0.99) < l2::required_precision(50, 0.51, 0.75));
+
+        for &(dimension, bits, arithmetic) in &[
+            (12, 48, l2::Arithmetic::Double),
+            (10, 1100, l2::Arithmetic::Mpfr(53)),
+        ] {
+            let (mut basis, _) = generator::usvp_bignum(dimension, bits, 5, &mut SeededRng::new(3));
+            let (mut exact, _) = generator::usvp_bignum(dimension, bits, 5, &mut SeededRng::new(3));
+            assert_eq!(l2::lll_auto(&mut basis, 0.51, 0.99), Ok(arithmetic));
+            l2::lll_bignum(&mut exact, 0.51, 0.99).unwrap();
+            assert!((0..dimension).all(|i| basis[i] == exact[i]));
+        }
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_rational_l2() {
+        // The lattice of test_l2f scaled by 1/6, with an extra denominator on the last coordinate
+        let entries = [[1, 0, 0, 1345], [0, 1, 0, 35], [0, 0, 1, 154]];
+        let mut basis: Matrix<rug::Rational> = Matrix::from_matrix(
+            entries
+                .iter()
+                .map(|v| {
+                    let mut column: Vec<rug::Rational> =
+                        v.iter().map(|&x| rug::Rational::from((x, 6))).collect();
+                    column[3] /= 5;
+                    column
+                })
+                .collect(),
+        );
+        l2::lll_rational(&mut basis, 0.51, 0.99).unwrap();
+
+        let mut exact: Matrix<rug::Integer> = Matrix::from_matrix(
+            entries
+                .iter()
+                .map(|v| {
+                    let mut column: Vec<rug::Integer> =
+                        v.iter().map(|&x| rug::Integer::from(5 * x)).collect();
+                    column[3] /= 5;
+                    column
+                })
+                .collect(),
+        );
+        l2::lll_bignum(&mut exact, 0.51, 0.99).unwrap();
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(basis[i][j], rug::Rational::from((exact[i][j].clone(), 30)));
+            }
+        }
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_bigl2() {
+        type I = rug::Integer;
+        let mut basis: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(1), I::from(2), I::from(3)],
+            vec![I::from(4), I::from(5), I::from(6)],
+            vec![I::from(7), I::from(8), I::from(9)],
+        ]);
+        println!("{:?}", basis);
+
+        l2::lll_bignum(&mut basis, 0.6, 0.95).unwrap();
+        println!("{:?}", basis);
+
+        let result: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(0), I::from(0), I::from(0)],
+            vec![I::from(2), I::from(1), I::from(0)],
+            vec![I::from(-1), I::from(1), I::from(3)],
+        ]);
+        assert_eq!(basis, result);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_bigl2_ntrulike() {
+        type I = rug::Integer;
+        let mut basis: Matrix<I> = Matrix::from_matrix(vec![
+            vec![
+                I::from(1),
+                I::from(0),
+                I::from(0),
                 I::from(436),
                 I::from(225),
                 I::from(381),
@@ -198,7 +2305,7 @@ mod test {
         ]);
         println!("{:?}", basis);
 
-        l2::lll_bignum(&mut basis, 0.6, 0.95);
+        l2::lll_bignum(&mut basis, 0.6, 0.95).unwrap();
         println!("{:?}", basis);
 
         let result: Matrix<I> = Matrix::from_matrix(vec![
@@ -253,4 +2360,1163 @@ mod test {
         ]);
         assert_eq!(basis, result);
     }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_basis_quality() {
+        type I = rug::Integer;
+        let basis: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(3), I::from(0), I::from(0)],
+            vec![I::from(1), I::from(2), I::from(0)],
+            vec![I::from(0), I::from(0), I::from(0)],
+        ]);
+
+        let quality = quality::basis_quality_bignum(&basis);
+        assert_eq!(quality.gso_norms, vec![3., 2.]);
+        assert!((quality.log_volume - 6f64.log2()).abs() < 1e-12);
+        assert!((quality.orthogonality_defect - 5f64.sqrt() / 2.).abs() < 1e-12);
+
+        let float_basis: Matrix<f64> =
+            Matrix::from_matrix(vec![vec![3., 0., 0.], vec![1., 2., 0.]]);
+        assert_eq!(quality::basis_quality_float(&float_basis), quality);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_radii() {
+        type I = rug::Integer;
+        let basis: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(0), I::from(0)],
+            vec![I::from(4), I::from(0)],
+            vec![I::from(0), I::from(6)],
+        ]);
+
+        assert_eq!(quality::packing_radius_bignum(&basis), 2.);
+        assert!((quality::covering_radius_bound_bignum(&basis) - 13f64.sqrt()).abs() < 1e-12);
+
+        let float_basis: Matrix<f64> = Matrix::from_matrix(vec![vec![4., 0.], vec![0., 6.]]);
+        assert_eq!(quality::packing_radius_float(&float_basis), 2.);
+        assert!((quality::covering_radius_bound_float(&float_basis) - 13f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_theta_series() {
+        type I = rug::Integer;
+        // Hexagonal lattice A2, embedded in dimension 3
+        let basis: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(1), I::from(-1), I::from(0)],
+            vec![I::from(1), I::from(0), I::from(-1)],
+        ]);
+
+        let series = enumeration::theta_series_bignum(&basis, &I::from(8));
+        let expected = vec![
+            (I::from(0), 1),
+            (I::from(2), 6),
+            (I::from(6), 6),
+            (I::from(8), 6),
+        ];
+        assert_eq!(series, expected);
+
+        let float_basis: Matrix<f64> = Matrix::from_matrix(vec![vec![1., 1.], vec![0., 1.]]);
+        let series = enumeration::theta_series_float(&float_basis, 2.5);
+        assert_eq!(series, vec![(0., 1), (1., 4), (2., 4)]);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_cvp() {
+        type I = rug::Integer;
+        let basis: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(1), I::from(0), I::from(0), I::from(1345)],
+            vec![I::from(0), I::from(1), I::from(0), I::from(35)],
+            vec![I::from(0), I::from(0), I::from(1), I::from(154)],
+        ]);
+        let lattice = cvp::preprocess_bignum(&basis, 0.501, 0.998);
+        assert_eq!(lattice.rank(), 3);
+
+        // 2 b_0 + 3 b_1 - b_2, slightly off
+        let closest = basis[0]
+            .mulf(I::from(2))
+            .add(&basis[1].mulf(I::from(3)))
+            .sub(&basis[2]);
+        let mut target = closest.clone();
+        target[3] += 1;
+
+        assert_eq!(cvp::nearest_plane(&lattice, &target), closest);
+        assert_eq!(cvp::rounding(&lattice, &target), closest);
+
+        let float_basis: Matrix<f64> = Matrix::from_matrix(vec![
+            vec![1., 0., 0., 1345.],
+            vec![0., 1., 0., 35.],
+            vec![0., 0., 1., 154.],
+        ]);
+        let lattice = cvp::preprocess_float(&float_basis, 0.501, 0.998);
+        let closest = float_basis[0]
+            .mulf(2.)
+            .add(&float_basis[1].mulf(3.))
+            .sub(&float_basis[2]);
+        let mut target = closest.clone();
+        target[3] += 1.;
+
+        assert_eq!(cvp::nearest_plane(&lattice, &target), closest);
+        assert_eq!(cvp::rounding(&lattice, &target), closest);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_randomized_nearest_plane() {
+        type I = rug::Integer;
+        let basis: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(7), I::from(1), I::from(0)],
+            vec![I::from(-2), I::from(8), I::from(1)],
+            vec![I::from(1), I::from(-1), I::from(9)],
+        ]);
+        let lattice = cvp::preprocess_bignum(&basis, 0.501, 0.998);
+
+        let mut target = basis[0].sub(&basis[2].mulf(I::from(2)));
+        target[0] += 2;
+        target[2] -= 1;
+
+        let babai = cvp::nearest_plane(&lattice, &target);
+        let mut rng = SeededRng::new(42);
+        let randomized = cvp::randomized_nearest_plane(&lattice, &target, 20, &mut rng);
+
+        let distance = |v: &Vector<I>| {
+            let difference = v.sub(&target);
+            difference.dot(&difference)
+        };
+        assert!(distance(&randomized) <= distance(&babai));
+        assert_eq!(randomized, basis[0].sub(&basis[2].mulf(I::from(2))));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_usvp_generator() {
+        let mut rng = SeededRng::new(7);
+        let (mut basis, secret) = generator::usvp_bignum(12, 48, 5, &mut rng);
+        assert_eq!(secret.dot(&secret), 5);
+
+        l2::lll_bignum(&mut basis, 0.51, 0.99).unwrap();
+        assert!(basis[0] == secret || basis[0] == secret.mulf(rug::Integer::from(-1)));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_coppersmith_univariate() {
+        use coppersmith::{JochemszMay, ModularEquation, Polynomial};
+        type I = rug::Integer;
+
+        // Stereotyped message for RSA with e = 3: (high + x)^3 = c mod N
+        let n = (I::from(1) << 61u32) - 1u32;
+        let n = n * ((I::from(1) << 89u32) - 1u32);
+        let high = I::from(0x1234_5678_9abc_u64) << 64u32;
+        let x = I::from(0x2bad_cafe_u32);
+        let c = I::from(&high + &x).pow_mod(&I::from(3), &n).unwrap();
+
+        let f = Polynomial::univariate(vec![high, I::from(1)]).pow(3);
+        let f = f.sub(&Polynomial::constant(1, c));
+        let equation = ModularEquation::new(f, n, vec![I::from(1) << 30u32]);
+
+        let roots = coppersmith::small_roots(&equation, &JochemszMay::new(2));
+        assert_eq!(roots, vec![vec![x]]);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_coppersmith_bivariate() {
+        use coppersmith::{JochemszMay, ModularEquation, Polynomial};
+        use rug::ops::RemRounding;
+        type I = rug::Integer;
+
+        // x + a y + b = 0 mod N
+        let n = (I::from(1) << 64u32) + 13;
+        let (x, y) = (I::from(12345), I::from(-54321));
+        let a = I::from(987_654_321_987_u64);
+        let b = I::from(-(I::from(&a * &y) + &x)).rem_euc(&n);
+
+        let f = Polynomial::from_terms(
+            2,
+            vec![(vec![1, 0], I::from(1)), (vec![0, 1], a), (vec![0, 0], b)],
+        );
+        let equation = ModularEquation::new(f, n, vec![I::from(1) << 20u32, I::from(1) << 20u32]);
+
+        let roots = coppersmith::small_roots(&equation, &JochemszMay::new(1));
+        assert_eq!(roots, vec![vec![x, y]]);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_partial_key_exposure() {
+        use rsa::KnownBits;
+        type I = rug::Integer;
+
+        let p = (I::from(0xb5e3_7f21_9c4d_0a6b_u64) << 64u32 | I::from(0x3d8e_1f52_a7c9_6e04_u64))
+            .next_prime();
+        let q = (I::from(0xd12a_6b9f_43e7_580c_u64) << 64u32 | I::from(0x9a47_e2b1_0c5d_f836_u64))
+            .next_prime();
+        let n = I::from(&p * &q);
+
+        let msb = KnownBits::Msb {
+            value: I::from(&p >> 40u32),
+            unknown_bits: 40,
+        };
+        assert_eq!(
+            rsa::factor_with_known_bits(&n, &msb, 8),
+            Some((p.clone(), q.clone()))
+        );
+
+        let lsb = KnownBits::Lsb {
+            value: I::from(p.keep_bits_ref(88)),
+            known_bits: 88,
+            unknown_bits: 40,
+        };
+        assert_eq!(
+            rsa::factor_with_known_bits(&n, &lsb, 8),
+            Some((p.clone(), q))
+        );
+
+        // Too few known bits
+        let msb = KnownBits::Msb {
+            value: I::from(&p >> 100u32),
+            unknown_bits: 100,
+        };
+        assert_eq!(rsa::factor_with_known_bits(&n, &msb, 8), None);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_hnp_biased_ecdsa() {
+        use hnp::Signature;
+        use rug::ops::RemRounding;
+        type I = rug::Integer;
+
+        let mut rng = SeededRng::new(2024);
+        let q = (I::from(1) << 127u32) - 1;
+        let key = generator::random_below(&q, &mut rng);
+
+        // Nonces with 24 leading zero bits
+        let signatures: Vec<Signature> = (0..10)
+            .map(|_| {
+                let k = generator::random_bits(127 - 24, &mut rng);
+                let r = generator::random_below(&q, &mut rng);
+                let hash = generator::random_below(&q, &mut rng);
+                let s = (I::from(&r * &key) + &hash) * I::from(k.invert_ref(&q).unwrap());
+                Signature::new(r, s.rem_euc(&q), hash)
+            })
+            .collect();
+        assert_eq!(
+            hnp::recover_ecdsa_key(&q, &signatures, 24),
+            Some(key.clone())
+        );
+
+        // Known leading bits
+        let signatures: Vec<Signature> = (0..10)
+            .map(|_| {
+                let msb = generator::random_bits(24, &mut rng);
+                let k = (I::from(&msb) << 103u32) + generator::random_bits(103, &mut rng);
+                let r = generator::random_below(&q, &mut rng);
+                let hash = generator::random_below(&q, &mut rng);
+                let s = (I::from(&r * &key) + &hash) * I::from(k.invert_ref(&q).unwrap());
+                Signature::with_nonce_msb(r, s.rem_euc(&q), hash, msb)
+            })
+            .collect();
+        assert_eq!(
+            hnp::recover_ecdsa_key(&q, &signatures, 24),
+            Some(key.clone())
+        );
+
+        // Not enough samples: many keys are consistent with the signatures
+        assert_ne!(hnp::recover_ecdsa_key(&q, &signatures[..2], 24), Some(key));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_subset_sum() {
+        use knapsack::{Embedding, SubsetSum};
+        type I = rug::Integer;
+
+        let mut rng = SeededRng::new(5);
+        let instance = |n: usize, bits: u32, rng: &mut SeededRng| {
+            let weights: Vec<I> = (0..n).map(|_| generator::random_bits(bits, rng)).collect();
+            let x: Vec<bool> = (0..n).map(|i| i % 3 != 0).collect();
+            let target = weights
+                .iter()
+                .zip(&x)
+                .filter(|(_, &x_i)| x_i)
+                .map(|(a, _)| a)
+                .sum();
+            (SubsetSum::new(weights, target), x)
+        };
+
+        // Density 0.25
+        let (low, x) = instance(20, 80, &mut rng);
+        assert!(Embedding::LagariasOdlyzko.is_expected_to_work(&low));
+        assert_eq!(
+            knapsack::solve(&low, Embedding::LagariasOdlyzko),
+            Some(x.clone())
+        );
+        assert_eq!(knapsack::solve(&low, Embedding::Cjloss), Some(x));
+
+        // Density 0.75
+        let (medium, x) = instance(24, 32, &mut rng);
+        assert!(!Embedding::LagariasOdlyzko.is_expected_to_work(&medium));
+        assert!(Embedding::Cjloss.is_expected_to_work(&medium));
+        assert_eq!(knapsack::solve(&medium, Embedding::Cjloss), Some(x));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_merkle_hellman() {
+        type I = rug::Integer;
+
+        let mut rng = SeededRng::new(1978);
+        let n = 32;
+
+        // Private key: superincreasing sequence, modulus and multiplier
+        let mut private_key: Vec<I> = vec![];
+        let mut sum = I::new();
+        for _ in 0..n {
+            let w = I::from(&sum + generator::random_bits(n, &mut rng)) + 1;
+            sum += &w;
+            private_key.push(w);
+        }
+        let modulus = sum + 1 + generator::random_bits(n, &mut rng);
+        let multiplier = loop {
+            let r = generator::random_below(&modulus, &mut rng);
+            if r.clone().gcd(&modulus) == 1 {
+                break r;
+            }
+        };
+        let public_key: Vec<I> = private_key
+            .iter()
+            .map(|w| I::from(w * &multiplier) % &modulus)
+            .collect();
+
+        let message: Vec<bool> = (0..n).map(|_| rng.next_u32() & 1 == 1).collect();
+        let ciphertext: I = public_key
+            .iter()
+            .zip(&message)
+            .filter(|(_, &m)| m)
+            .map(|(b, _)| b)
+            .sum();
+
+        assert_eq!(
+            knapsack::merkle_hellman_plaintext(&public_key, &ciphertext),
+            Some(message)
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_ntru_key_recovery() {
+        use rug::ops::RemRounding;
+        type I = rug::Integer;
+
+        let (n, p, q) = (17, I::from(3), I::from(257));
+        let mut rng = SeededRng::new(1996);
+        let mut ternary =
+            || -> Vec<I> { (0..n).map(|_| I::from(rng.next_u32() % 3) - 1).collect() };
+        let (f, g) = (ternary(), ternary());
+
+        // Solve f * h = p g in Z_q[x] / (x^N - 1) by Gauss-Jordan elimination on [F | p g]
+        let mut rows: Vec<Vec<I>> = (0..n)
+            .map(|j| {
+                let mut row: Vec<I> = (0..n).map(|i| f[(j + n - i) % n].clone()).collect();
+                row.push(I::from(&p * &g[j]));
+                row
+            })
+            .collect();
+        for col in 0..n {
+            let pivot = (col..n)
+                .find(|&i| I::from(&rows[i][col] % &q) != 0)
+                .expect("f must be invertible modulo q");
+            rows.swap(col, pivot);
+            let inverse = rows[col][col].clone().invert(&q).unwrap();
+            for entry in rows[col].iter_mut() {
+                *entry = I::from(&*entry * &inverse).rem_euc(&q);
+            }
+            let pivot_row = rows[col].clone();
+            for (i, row) in rows.iter_mut().enumerate() {
+                if i != col {
+                    let factor = row[col].clone();
+                    for (entry, pivot_entry) in row.iter_mut().zip(&pivot_row) {
+                        *entry = I::from(&*entry - &factor * pivot_entry).rem_euc(&q);
+                    }
+                }
+            }
+        }
+        let h: Vec<I> = rows.iter().map(|row| row[n].clone()).collect();
+
+        let key = ntru::PublicKey::new(n, p, q, h);
+        let candidates = ntru::attack(&key);
+        let (f_neg, g_neg): (Vec<I>, Vec<I>) = (
+            f.iter().map(|c| I::from(-c)).collect(),
+            g.iter().map(|c| I::from(-c)).collect(),
+        );
+        let keys: Vec<(Vec<I>, Vec<I>)> = ntru::rotations(&f, &g)
+            .into_iter()
+            .chain(ntru::rotations(&f_neg, &g_neg))
+            .collect();
+        assert!(keys.contains(&candidates[0]));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_algebraic_dependency() {
+        use rug::Float;
+        type I = rug::Integer;
+
+        // sqrt(2) + sqrt(3) is a root of x^4 - 10 x^2 + 1
+        let alpha = Float::with_val(256, 2).sqrt() + Float::with_val(256, 3).sqrt();
+        assert_eq!(
+            relation::algebraic_dependency(&alpha, 4, 200),
+            Some(vec![
+                I::from(1),
+                I::from(0),
+                I::from(-10),
+                I::from(0),
+                I::from(1)
+            ])
+        );
+
+        // A larger degree bound still finds the minimal polynomial of the golden ratio
+        let phi = (Float::with_val(256, 5).sqrt() + 1) / 2;
+        assert_eq!(
+            relation::algebraic_dependency(&phi, 3, 200),
+            Some(vec![I::from(-1), I::from(-1), I::from(1)])
+        );
+
+        // pi is not algebraic of small degree with small coefficients
+        let pi = Float::with_val(256, rug::float::Constant::Pi);
+        assert_eq!(relation::algebraic_dependency(&pi, 3, 200), None);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_simultaneous_approximation() {
+        use rug::Float;
+        type I = rug::Integer;
+
+        let values: Vec<Float> = [2, 3, 5]
+            .iter()
+            .map(|&a| Float::with_val(128, a).sqrt())
+            .collect();
+        let bound = I::from(1) << 30u32;
+        let (q, p) = relation::simultaneous_approximation(&values, &bound).unwrap();
+        assert!(q >= 1 && q <= bound);
+
+        // The errors are within a small factor of Dirichlet's bound 2^-10
+        for (value, p_i) in values.iter().zip(&p) {
+            let error = (Float::with_val(128, value * &q) - p_i).abs();
+            assert!(error < Float::with_val(128, 1) >> 6u32);
+        }
+
+        // Rational values are recovered exactly
+        let values: Vec<Float> = [(1, 7), (3, 14), (5, 21)]
+            .iter()
+            .map(|&(a, b)| Float::with_val(128, a) / b)
+            .collect();
+        let (q, p) = relation::simultaneous_approximation(&values, &I::from(1000)).unwrap();
+        assert_eq!(q, 42);
+        assert_eq!(p, vec![I::from(6), I::from(9), I::from(10)]);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_rational_reconstruction() {
+        use rug::{Float, Rational};
+        type I = rug::Integer;
+
+        // Six decimal digits of 355/113
+        let value = Float::with_val(64, Float::parse("3.141593").unwrap());
+        assert_eq!(
+            relation::rational_reconstruction(&value, &I::from(200)),
+            Some(Rational::from((355, 113)))
+        );
+        assert_eq!(
+            relation::rational_reconstruction(&value, &I::from(10)),
+            Some(Rational::from((22, 7)))
+        );
+        assert_eq!(relation::rational_reconstruction(&value, &I::from(5)), None);
+
+        let values: Vec<Float> = [(-2, 9), (5, 6), (7, 18)]
+            .iter()
+            .map(|&(a, b)| Float::with_val(64, a) / b)
+            .collect();
+        assert_eq!(
+            relation::rational_reconstruction_batch(&values, &I::from(100)),
+            Some(vec![
+                Rational::from((-2, 9)),
+                Rational::from((5, 6)),
+                Rational::from((7, 18))
+            ])
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_orthogonal_lattice() {
+        type I = rug::Integer;
+
+        let vectors: Matrix<I> = Matrix::from_matrix(vec![
+            vec![I::from(1), I::from(2), I::from(3), I::from(4), I::from(5)],
+            vec![I::from(2), I::from(0), I::from(1), I::from(-1), I::from(7)],
+            vec![I::from(3), I::from(2), I::from(4), I::from(3), I::from(12)],
+        ]);
+        let orthogonal = orthogonal::orthogonal_lattice(&vectors);
+
+        // The third vector is the sum of the first two
+        assert_eq!(orthogonal.dimensions(), (3, 5));
+        for i in 0..3 {
+            assert!(!orthogonal[i].is_zero());
+            for j in 0..3 {
+                assert_eq!(orthogonal[i].dot(&vectors[j]), 0);
+            }
+        }
+
+        // The orthogonal lattice of (1, 1, 1) has volume sqrt(3)
+        let orthogonal =
+            orthogonal::orthogonal_lattice(&Matrix::from_matrix(vec![vec![I::from(1); 3]]));
+        assert_eq!(orthogonal.dimensions(), (2, 3));
+        let gram = |i: usize, j: usize| orthogonal[i].dot(&orthogonal[j]);
+        assert_eq!(gram(0, 0) * gram(1, 1) - gram(0, 1) * gram(1, 0), 3);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_integer_kernel() {
+        type I = rug::Integer;
+        let v = |c: &[i64]| c.iter().map(|&x| I::from(x)).collect::<Vec<I>>();
+
+        // v_2 = v_0 + 2 v_1 and v_4 = v_0 - 3 v_3
+        let vectors: Matrix<I> = Matrix::from_matrix(vec![
+            v(&[314, -159, 265, 358]),
+            v(&[979, 323, -846, 264]),
+            v(&[2272, 487, -1427, 886]),
+            v(&[-81, 146, 111, 159]),
+            v(&[557, -597, -68, -119]),
+        ]);
+        let kernel = orthogonal::integer_kernel(&vectors);
+        assert_eq!(kernel.dimensions(), (2, 5));
+
+        let mut dependencies: Vec<Vec<I>> = (0..2)
+            .map(|i| {
+                let sign = if kernel[i][0] < 0 { -1 } else { 1 };
+                (0..5).map(|j| I::from(&kernel[i][j] * sign)).collect()
+            })
+            .collect();
+        dependencies.sort();
+        assert_eq!(
+            dependencies,
+            vec![v(&[1, 0, 0, -3, -1]), v(&[1, 2, -1, 0, 0])]
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_small_modular_solution() {
+        type I = rug::Integer;
+
+        // A planted solution x with entries in [-3, 3]
+        let mut rng = SeededRng::new(31337);
+        let modulus = (I::from(1) << 64u32) + 13;
+        let x: Vec<I> = vec![-3, -2, -1, 0, 1, 2, 3, 1]
+            .into_iter()
+            .map(I::from)
+            .collect();
+        let mut a: Vec<I> = (0..7)
+            .map(|_| generator::random_below(&modulus, &mut rng))
+            .collect();
+        // x_7 = 1 is its own inverse
+        let partial: I = a.iter().zip(&x).map(|(a_j, x_j)| I::from(a_j * x_j)).sum();
+        a.push(I::from(-partial) % &modulus);
+
+        let kernel = orthogonal::modular_kernel(&a, &modulus);
+        assert_eq!(kernel.dimensions(), (8, 8));
+
+        let solution = orthogonal::small_modular_solution(&a, &modulus, &I::from(10)).unwrap();
+        let negated: Vec<I> = x.iter().map(|x_j| I::from(-x_j)).collect();
+        assert!(solution == x || solution == negated);
+
+        assert_eq!(
+            orthogonal::small_modular_solution(&a, &modulus, &I::from(2)),
+            None
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_ggh_embedding() {
+        type I = rug::Integer;
+
+        let n = 12;
+        let mut rng = SeededRng::new(1997);
+        let mut small = |bound: u32| I::from(rng.next_u32() % (2 * bound + 1)) - bound;
+
+        // Private basis: a perturbed multiple of the identity, disguised by column operations
+        let mut basis: Matrix<I> = Matrix::init(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                basis[i][j] = small(4) + if i == j { 40 } else { 0 };
+            }
+        }
+        for step in 0..4 * n {
+            let (i, j) = (step % n, (step * 7 + 3) % n);
+            if i != j {
+                let k = small(2);
+                for l in 0..n {
+                    let v = I::from(&k * &basis[j][l]);
+                    basis[i][l] += v;
+                }
+            }
+        }
+
+        let message: Vec<I> = (0..n).map(|_| small(50)).collect();
+        let error: Vec<I> = (0..n)
+            .map(|_| {
+                if small(1) < 0 {
+                    I::from(-3)
+                } else {
+                    I::from(3)
+                }
+            })
+            .collect();
+        let ciphertext: Vec<I> = (0..n)
+            .map(|j| {
+                (0..n)
+                    .map(|i| I::from(&message[i] * &basis[i][j]))
+                    .sum::<I>()
+                    + &error[j]
+            })
+            .collect();
+
+        assert_eq!(
+            ggh::decrypt(&basis, &ciphertext, &I::from(3)),
+            Some((message, error))
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_knapsack_generator() {
+        use knapsack::Embedding;
+
+        let mut rng = SeededRng::new(11);
+        let (instance, solution) = generator::knapsack_with_density_bignum(30, 0.5, 15, &mut rng);
+        assert_eq!(instance.weights.len(), 30);
+        assert!(instance.weights.iter().all(|a| a.significant_bits() == 60));
+        assert_eq!(solution.iter().filter(|&&x_i| x_i).count(), 15);
+        assert!(instance.is_solution(&solution));
+        assert!((instance.density() - 0.5).abs() < 0.01);
+
+        // Low-density instances are easy
+        assert_eq!(
+            knapsack::solve(&instance, Embedding::Cjloss),
+            Some(solution)
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_q_ary_generator() {
+        use rug::ops::RemRounding;
+        type I = rug::Integer;
+
+        let (n, m, q) = (3, 7, I::from(257));
+        let mut rng = SeededRng::new(257);
+        let (a, primal, dual) = generator::q_ary_bignum(n, m, &q, &mut rng);
+        assert_eq!(a.dimensions(), (m, n));
+        assert_eq!(primal.dimensions(), (m, m));
+        assert_eq!(dual.dimensions(), (m, m));
+
+        // A y = 0 mod q on the dual lattice
+        for k in 0..m {
+            for i in 0..n {
+                let row: I = (0..m).map(|j| I::from(&a[j][i] * &dual[k][j])).sum();
+                assert_eq!(row.rem_euc(&q), 0);
+            }
+        }
+
+        // The two lattices are orthogonal modulo q
+        for k in 0..m {
+            for l in 0..m {
+                assert_eq!(primal[k].dot(&dual[l]).rem_euc(&q), 0);
+            }
+        }
+
+        // det Λ_q(A) = q^(m-n) and det Λ_q^⊥(A) = q^n
+        let volume = |basis: &Matrix<I>| quality::basis_quality_bignum(basis).log_volume;
+        assert!((volume(&primal) - 4. * 257f64.log2()).abs() < 1e-6);
+        assert!((volume(&dual) - 3. * 257f64.log2()).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_lwe_primal_attack() {
+        use lwe::{Embedding, Instance};
+        type I = rug::Integer;
+
+        let (n, m, q) = (10, 24, I::from(257));
+        let mut rng = SeededRng::new(2005);
+        let (a, _, _) = generator::q_ary_bignum(n, m, &q, &mut rng);
+        let mut ternary =
+            |k: usize| -> Vec<I> { (0..k).map(|_| I::from(rng.next_u32() % 3) - 1).collect() };
+        let (secret, error) = (ternary(n), ternary(m));
+        let b: Vec<I> = (0..m)
+            .map(|j| {
+                let product: I = (0..n).map(|i| I::from(&a[j][i] * &secret[i])).sum();
+                (product + &error[j]) % &q
+            })
+            .collect();
+        let instance = Instance::new(a, b, q.clone());
+        let secret_mod_q: Vec<I> = secret.iter().map(|s_i| (s_i.clone() + &q) % &q).collect();
+
+        for &embedding in &[Embedding::Kannan, Embedding::BaiGalbraith] {
+            assert_eq!(
+                lwe::primal_attack(&instance, embedding, &I::from(1), &I::from(6)),
+                Some((secret_mod_q.clone(), error.clone()))
+            );
+        }
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_coppersmith_integer() {
+        use coppersmith::{IntegerEquation, JochemszMay, Polynomial};
+        type I = rug::Integer;
+
+        // Factoring with known high bits: (P0 + x)(Q0 + y) - N = 0 over the integers
+        let p = I::from(0xb5e3_7f21_9c4d_0a6b_u64).next_prime();
+        let q = I::from(0xd12a_6b9f_43e7_580c_u64).next_prime();
+        let n = I::from(&p * &q);
+        let (p0, q0) = (I::from(&p >> 20u32) << 20u32, I::from(&q >> 20u32) << 20u32);
+        let (x, y) = (I::from(&p - &p0), I::from(&q - &q0));
+
+        // The constant coefficient P0 Q0 - N is odd, hence coprime to the bounds
+        let f = Polynomial::from_terms(
+            2,
+            vec![
+                (vec![1, 1], I::from(1)),
+                (vec![1, 0], q0.clone()),
+                (vec![0, 1], p0.clone()),
+                (vec![0, 0], I::from(&p0 * &q0) - &n),
+            ],
+        );
+        let equation = IntegerEquation::new(f, vec![I::from(1) << 20u32, I::from(1) << 20u32]);
+        assert!(equation.is_root(&[x.clone(), y.clone()]));
+
+        let roots = coppersmith::integer_small_roots(
+            &equation,
+            &JochemszMay::with_extra_shifts(1, vec![1, 1]),
+        );
+        assert_eq!(roots, vec![vec![x, y]]);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_small_crt_exponents() {
+        use coppersmith::JochemszMay;
+        use rug::ops::Pow;
+        type I = rug::Integer;
+
+        let p = I::from(0xb5e3_7f21_u64).next_prime();
+        let q = I::from(0xd12a_6b9f_u64).next_prime();
+        let n = I::from(&p * &q);
+        let (p1, q1) = (I::from(&p - 1), I::from(&q - 1));
+
+        // Small CRT exponents whose inverses agree modulo gcd(p - 1, q - 1), and e by CRT
+        let (dp, dq, e) = (3u32..100)
+            .flat_map(|dp| (3u32..100).map(move |dq| (I::from(dp), I::from(dq))))
+            .find_map(|(dp, dq)| {
+                let ep = dp.clone().invert(&p1).ok()?;
+                let eq = dq.clone().invert(&q1).ok()?;
+                let g = p1.clone().gcd(&q1);
+                if I::from(&ep - &eq).is_divisible(&g) {
+                    let mut e = ep;
+                    while !I::from(&e - &eq).is_divisible(&q1) {
+                        e += &p1;
+                    }
+                    Some((dp, dq, e))
+                } else {
+                    None
+                }
+            })
+            .unwrap();
+        let k = (I::from(&e * &dp) - 1u32) / &p1;
+        let l = (I::from(&e * &dq) - 1u32) / &q1;
+        let root = [dp, dq, k, l];
+
+        let equation = rsa::small_crt_equation(&n, &e, 8);
+        assert!(equation.is_root(&root));
+
+        // All the shifts vanish at the root modulo R
+        let strategy = JochemszMay::with_extra_shifts(1, vec![1, 1, 0, 0]);
+        let (basis, monomials, modulus) = coppersmith::integer_lattice(&equation, &strategy);
+        let (d, dimension) = basis.dimensions();
+        assert_eq!(dimension, monomials.len());
+        for i in 0..d {
+            let value: I = monomials
+                .iter()
+                .enumerate()
+                .map(|(j, monomial)| {
+                    let (scale, power) = monomial.iter().enumerate().fold(
+                        (I::from(1), I::from(1)),
+                        |(scale, power), (v, &exponent)| {
+                            (
+                                scale * I::from((&equation.root_bounds[v]).pow(exponent)),
+                                power * I::from((&root[v]).pow(exponent)),
+                            )
+                        },
+                    );
+                    basis[i][j].clone().div_exact(&scale) * power
+                })
+                .sum();
+            assert!(value.is_divisible(&modulus));
+        }
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_hidden_subset_sum() {
+        type I = rug::Integer;
+
+        let mut rng = SeededRng::new(7);
+        let (n, m) = (10, 30);
+        let q = (I::from(1) << 100u32).next_prime();
+        let (instance, vectors, weights) = generator::hidden_subset_sum_bignum(n, m, &q, &mut rng);
+        assert!(instance.is_solution(&vectors, &weights));
+        assert_eq!(hssp::hidden_lattice(&instance).dimensions(), (n, m));
+
+        // The hidden vectors and weights are recovered up to their order
+        let pairs = |vectors: &Matrix<I>, weights: &[I]| {
+            let mut pairs: Vec<(Vec<I>, I)> = (0..n)
+                .map(|i| {
+                    (
+                        (0..m).map(|j| vectors[i][j].clone()).collect(),
+                        weights[i].clone(),
+                    )
+                })
+                .collect();
+            pairs.sort();
+            pairs
+        };
+        let (found, found_weights) = hssp::solve(&instance).unwrap();
+        assert!(instance.is_solution(&found, &found_weights));
+        assert_eq!(pairs(&found, &found_weights), pairs(&vectors, &weights));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_integer_recurrence() {
+        type I = rug::Integer;
+
+        // s_n = 2^n + 3^n satisfies s_(n+2) = 5 s_(n+1) - 6 s_n
+        let sequence: Vec<I> = (0..12u32)
+            .map(|n| (I::from(1) << n) + I::from(I::u_pow_u(3, n)))
+            .collect();
+        let c = relation::integer_recurrence(&sequence, 4).unwrap();
+        assert_eq!(c, vec![I::from(6), I::from(-5), I::from(1)]);
+        assert_eq!(
+            relation::next_term(&c, &sequence),
+            Some((I::from(1) << 12u32) + I::from(I::u_pow_u(3, 12)))
+        );
+
+        // A truncated linear congruential generator has no short integer recurrence
+        let mut state = 12345u64;
+        let outputs: Vec<I> = (0..20)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                I::from(state >> 48)
+            })
+            .collect();
+        assert_eq!(relation::integer_recurrence(&outputs, 6), None);
+
+        // Periodic sequences satisfy s_(n+3) = s_n
+        let periodic: Vec<I> = (0..9).map(|n| I::from([1, 2, 3][n % 3])).collect();
+        assert_eq!(
+            relation::integer_recurrence(&periodic, 4),
+            Some(vec![I::from(-1), I::from(0), I::from(0), I::from(1)])
+        );
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_real_recurrence() {
+        use rug::{ops::Pow, Float};
+        type I = rug::Integer;
+
+        // s_n = (1 + sqrt(2))^n satisfies s_(n+2) = 2 s_(n+1) + s_n
+        let alpha: Float = Float::with_val(256, 2).sqrt() + 1;
+        let sequence: Vec<Float> = (0..8)
+            .map(|n| Float::with_val(256, alpha.clone().pow(n)))
+            .collect();
+        assert_eq!(
+            relation::recurrence(&sequence, 3, 200),
+            Some(vec![I::from(-1), I::from(-2), I::from(1)])
+        );
+
+        // π^n satisfies no short recurrence
+        let pi = Float::with_val(256, rug::float::Constant::Pi);
+        let powers: Vec<Float> = (0..8)
+            .map(|n| Float::with_val(256, pi.clone().pow(n)))
+            .collect();
+        assert_eq!(relation::recurrence(&powers, 3, 200), None);
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_schnorr_relations() {
+        use rug::ops::Pow;
+        type I = rug::Integer;
+
+        let n = I::from(1_000_003u64 * 1_000_033u64);
+        let primes = schnorr::primes(24);
+        assert_eq!(primes[..5], [2, 3, 5, 7, 11]);
+        let diagonal = schnorr::diagonal(24, &mut SeededRng::new(2));
+        let mut sorted = diagonal.clone();
+        sorted.sort();
+        assert_eq!(
+            sorted,
+            (1..=24).map(|i| I::from((i + 1) / 2)).collect::<Vec<_>>()
+        );
+
+        // u = 7 * 37 * 41 * 47^3 * 59 * 61, v = 3: u - 3 N = -2^2 * 11 * 41^2
+        let mut exponents = vec![0; 24];
+        for (i, e) in [(1, -1), (3, 1), (10, 1), (11, 1), (14, 3), (16, 1), (17, 1)] {
+            exponents[i] = e;
+        }
+        let relation = schnorr::relation(&n, &primes, &exponents).unwrap();
+        assert_eq!(
+            (relation.u.clone(), relation.v.clone()),
+            (I::from(3_000_107_926_333u64), I::from(3))
+        );
+        assert_eq!(relation.remainder, -73964);
+        exponents[0] = 1;
+        assert_eq!(schnorr::relation(&n, &primes, &exponents), None);
+
+        let mut basis = schnorr::lattice(&n, &primes, &diagonal, 16);
+        l2::lll_bignum(&mut basis, 0.51, 0.99).unwrap();
+        let shortest = (0..=24).map(|i| basis[i].dot(&basis[i])).min().unwrap();
+        let relations = schnorr::smooth_relations(&n, &primes, &diagonal, 16, &(shortest * 2u32));
+        assert!(!relations.is_empty());
+        for relation in relations {
+            assert_eq!(
+                relation.remainder,
+                I::from(&relation.u - I::from(&relation.v * &n))
+            );
+            let smooth: I = primes
+                .iter()
+                .zip(&relation.remainder_exponents)
+                .map(|(p, &e)| I::from(p.pow(e)))
+                .product();
+            assert_eq!(smooth, relation.remainder.clone().abs());
+        }
+    }
+
+    #[test]
+    fn test_estimate() {
+        assert_eq!(estimate::root_hermite_factor(2), 1.0219);
+        assert!((estimate::root_hermite_factor(100) - 1.0093).abs() < 1e-4);
+        assert!((2..400)
+            .all(|beta| estimate::root_hermite_factor(beta + 1)
+                < estimate::root_hermite_factor(beta)));
+
+        // The GSA profile preserves the volume
+        let profile = estimate::gsa_profile(100, 1000., 60);
+        assert!((profile.iter().sum::<f64>() - 1000.).abs() < 1e-6);
+        assert!(profile.windows(2).all(|w| w[1] < w[0]));
+
+        // LWE with n = 256, m = 256, q = 3329 and a ternary secret and error (Bai-Galbraith embedding)
+        let (n, m, q) = (256., 256., 3329_f64);
+        let dimension = 513;
+        let log_volume = m * q.log2();
+        let norm = ((n + m) * 2. / 3. + 1.).sqrt();
+        let lwe = estimate::usvp(dimension, log_volume, norm).unwrap();
+        assert!(lwe.block_size > 100 && lwe.block_size < 500);
+        assert_eq!(lwe, estimate::Estimate::new(lwe.block_size));
+        assert!((lwe.classical_cost - 0.292 * lwe.block_size as f64).abs() < 1e-9);
+
+        // Shorter vectors are easier to find
+        let easy = estimate::usvp(dimension, log_volume, norm / 4.).unwrap();
+        assert!(easy.block_size < lwe.block_size);
+        assert!(estimate::usvp(dimension, log_volume, norm * 1e3).is_none());
+
+        // Approximate SVP is easy for large targets and impossible below the Gaussian heuristic
+        let gh = estimate::gaussian_heuristic(dimension, log_volume).exp2();
+        assert_eq!(
+            estimate::approx_svp(dimension, log_volume, gh * 1e6).map(|e| e.block_size),
+            Some(2)
+        );
+        assert!(estimate::approx_svp(dimension, log_volume, gh / 2.).is_none());
+        assert!(estimate::approx_svp(dimension, log_volume, gh * 2.).is_some());
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_bigfloat_backend() {
+        // The entries of the basis overflow double floating-point numbers
+        let (mut basis, secret) = generator::usvp_bignum(10, 1100, 5, &mut SeededRng::new(7));
+        let (mut exact, _) = generator::usvp_bignum(10, 1100, 5, &mut SeededRng::new(7));
+
+        l2::lll_bigfloat(&mut basis, 0.51, 0.99, 128).unwrap();
+        assert!(basis[0] == secret || basis[0] == secret.mulf(rug::Integer::from(-1)));
+
+        // With enough precision, the result matches the exact reduction
+        l2::lll_bignum(&mut exact, 0.51, 0.99).unwrap();
+        assert!((0..10).all(|i| basis[i] == exact[i]));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_bigfloat_non_finite() {
+        // The squared norm of the first vector overflows the exponents of MPFR
+        let big = rug::Integer::from(1) << (rug::float::exp_max() / 2 + 1) as u32;
+        let entries = vec![vec![big, 0.into()], vec![0.into(), 1.into()]];
+        let mut basis: Matrix<rug::Integer> = Matrix::from_matrix(entries.clone());
+        assert_eq!(
+            l2::lll_bigfloat(&mut basis, 0.51, 0.99, 53),
+            Err(l2::NonFinite { index: 0 }.into())
+        );
+        assert!(basis == Matrix::from_matrix(entries));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn test_doubledouble_backend() {
+        use crate::{algebra::FromExt, DoubleDouble};
+
+        // Integers of 106 bits are represented exactly
+        let n = (rug::Integer::from(1) << 105) + 1;
+        assert_eq!(DoubleDouble::from_ext(&n).parts(), (2f64.powi(105), 1.));
+        assert!(DoubleDouble::from_ext(&n) > rug::Integer::from(1) << 105);
+
+        let (mut basis, secret) = generator::usvp_bignum(20, 200, 5, &mut SeededRng::new(7));
+        let (mut exact, _) = generator::usvp_bignum(20, 200, 5, &mut SeededRng::new(7));
+        l2::lll_doubledouble(&mut basis, 0.51, 0.99).unwrap();
+        assert!(basis[0] == secret || basis[0] == secret.mulf(rug::Integer::from(-1)));
+
+        l2::lll_bignum(&mut exact, 0.51, 0.99).unwrap();
+        assert!((0..20).all(|i| basis[i] == exact[i]));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_num_bigint_backend() {
+        use num_bigint::BigInt;
+
+        let entries = vec![vec![1, 0, 0, 1345], vec![0, 1, 0, 35], vec![0, 0, 1, 154]];
+        let mut num_basis: Matrix<BigInt> = Matrix::from_matrix(
+            entries
+                .iter()
+                .map(|v| v.iter().map(|&x| BigInt::from(x)).collect())
+                .collect(),
+        );
+        let mut machine: Matrix<i64> = Matrix::from_matrix(entries);
+        l2::lll_num_bigint(&mut num_basis, 0.51, 0.99).unwrap();
+        l2::lll_i64(&mut machine, 0.51, 0.99).unwrap();
+        assert!((0..3).all(|i| (0..4).all(|j| num_basis[i][j] == BigInt::from(machine[i][j]))));
+
+        #[cfg(feature = "rug")]
+        {
+            let entries = [
+                ["1267650600228229401496703205376", "0", "0", "1345"],
+                ["0", "1", "0", "35"],
+                ["0", "0", "1", "154"],
+            ];
+            let mut rug_basis: Matrix<rug::Integer> = Matrix::from_matrix(
+                entries
+                    .iter()
+                    .map(|v| v.iter().map(|x| x.parse().unwrap()).collect())
+                    .collect(),
+            );
+            let mut num_basis: Matrix<BigInt> = Matrix::from_matrix(
+                entries
+                    .iter()
+                    .map(|v| v.iter().map(|x| x.parse().unwrap()).collect())
+                    .collect(),
+            );
+
+            // Both backends perform the same exact computations
+            l2::lll_bignum(&mut rug_basis, 0.51, 0.99).unwrap();
+            l2::lll_num_bigint(&mut num_basis, 0.51, 0.99).unwrap();
+            for i in 0..3 {
+                for j in 0..4 {
+                    assert_eq!(rug_basis[i][j].to_string(), num_basis[i][j].to_string());
+                }
+            }
+            assert_eq!(
+                quality::basis_quality_bignum(&rug_basis),
+                quality::basis_quality_num_bigint(&num_basis)
+            );
+        }
+    }
+
+    #[cfg(feature = "crypto-bigint")]
+    #[test]
+    fn test_crypto_bigint_backend() {
+        use crypto_bigint::Int;
+
+        let entries: Vec<Vec<i64>> = vec![
+            vec![1, 0, 0, 1 << 40],
+            vec![0, 1, 0, 35 << 20],
+            vec![0, 0, 1, 154 << 30],
+        ];
+        let mut exact: Matrix<i128> = Matrix::from_matrix(
+            entries
+                .iter()
+                .map(|v| v.iter().map(|&x| i128::from(x)).collect())
+                .collect(),
+        );
+        l2::lll_i128(&mut exact, 0.501, 0.998).unwrap();
+
+        // The Gram matrix overflows 64-bit integers, but not 128-bit ones
+        let mut narrow: Vec<Vec<Int<1>>> = entries
+            .iter()
+            .map(|v| v.iter().map(|&x| Int::from_i64(x)).collect())
+            .collect();
+        assert_eq!(
+            l2::lll_crypto_bigint(&mut narrow, 0.501, 0.998),
+            Err(Overflow.into())
+        );
+        assert_eq!(narrow[0][3], Int::from_i64(1 << 40));
+
+        let mut wide: Vec<Vec<Int<2>>> = entries
+            .iter()
+            .map(|v| v.iter().map(|&x| Int::from_i64(x)).collect())
+            .collect();
+        l2::lll_crypto_bigint(&mut wide, 0.501, 0.998).unwrap();
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(wide[i][j], Int::from_i64(exact[i][j] as i64));
+            }
+        }
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits_backend() {
+        let entries: Vec<Vec<i64>> =
+            vec![vec![1, 0, 0, 1345], vec![0, 1, 0, 35], vec![0, 0, 1, 154]];
+        let mut basis: Matrix<i64> = Matrix::from_matrix(entries);
+        let mut exact = basis.clone();
+
+        l2::lll_num_traits(&mut basis, 0.501, 0.998).unwrap();
+        l2::lll_i64(&mut exact, 0.501, 0.998).unwrap();
+        assert_eq!(basis, exact);
+
+        // Double floating-point numbers cannot represent the entries
+        #[cfg(feature = "num")]
+        {
+            use num_bigint::BigInt;
+
+            let mut basis: Matrix<BigInt> = Matrix::from_matrix(vec![
+                vec![BigInt::from(1) << 1100, BigInt::from(0)],
+                vec![BigInt::from(0), BigInt::from(1)],
+            ]);
+            let initial = basis.clone();
+            assert!(l2::lll_num_traits(&mut basis, 0.501, 0.998).is_err());
+            assert_eq!(basis, initial);
+        }
+    }
 }
@@ -1,6 +1,9 @@
 //! The Lenstra-Lenstra-Lovasz algorithm [LLL82]
 
-use crate::algebra::{BigNum, Float, FromExt, Matrix, Scalar};
+use crate::algebra::{Float, FromExt, Matrix, Scalar};
+
+#[cfg(feature = "rug")]
+use crate::algebra::BigNum;
 
 /// Lattice reduction using the original Lenstra-Lenstra-Lovasz algorithm
 ///
@@ -40,7 +43,7 @@ fn lattice_reduce<S: Scalar>(basis: &mut Matrix<S::Integer>) {
 
             let alpha = S::round_div(b_ip1.dot(b_i), b_i.dot(b_i));
             let vec_rhs = b_ip1.add(&b_i.mulf(alpha));
-            let rhs = vec_rhs.dot(&vec_rhs);
+            let rhs = vec_rhs.norm_sqr();
 
             if lhs > rhs {
                 basis.swap(i, i + 1);
@@ -59,6 +62,7 @@ fn lattice_reduce<S: Scalar>(basis: &mut Matrix<S::Integer>) {
 ///   - `basis`: A generating matrix for the lattice
 ///
 /// The basis is reduced in-place.
+#[cfg(feature = "rug")]
 #[deprecated(
     note = "Current implementation might yield incorrect results. Use l2.lll_bignum() instead"
 )]
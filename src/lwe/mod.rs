@@ -0,0 +1,220 @@
+//! Primal attack on Learning With Errors
+//!
+//! An LWE instance is a matrix `A` of size `n x m` and a vector `b = A^T s + e mod q`, for a secret
+//! `s` and a small error `e`. Recovering `e` is a bounded distance decoding problem in the q-ary
+//! lattice `Λ_q(A)`, which is turned into a unique-SVP instance by embedding:
+//!
+//! - Kannan's embedding: `(e, M)` is a short vector of the lattice spanned by `Λ_q(A) x {0}` and `(b, M)`
+//! - Bai and Galbraith's embedding [BG14]: `(s, e, M)` is a short vector of the lattice
+//!   `{ (x, y, M z) : A^T x + y = z b mod q }`, which is better suited to small secrets
+//!
+//! [BG14]: S. Bai and S. D. Galbraith. Lattice Decoding Attacks on Binary LWE. ACISP 2014.
+
+use crate::{
+    algebra::{centre, Matrix},
+    generator, l2,
+};
+
+use rug::{ops::RemRounding, Integer};
+
+/// An LWE instance `b = A^T s + e mod q`
+#[derive(Debug, PartialEq)]
+pub struct Instance {
+    /// The matrix `A`, whose `m` columns `a_j` have dimension `n`
+    pub a: Matrix<Integer>,
+
+    /// The `m` values `b_j = <a_j, s> + e_j mod q`
+    pub b: Vec<Integer>,
+
+    /// The modulus `q`
+    pub q: Integer,
+}
+
+impl Instance {
+    /// # Panics
+    /// if the number of columns of `a` and of entries of `b` differ
+    pub fn new(a: Matrix<Integer>, b: Vec<Integer>, q: Integer) -> Self {
+        assert_eq!(a.dimensions().0, b.len());
+        Self { a, b, q }
+    }
+
+    /// The error `b - A^T s`, centred in `(-q/2, q/2]`
+    pub fn error(&self, secret: &[Integer]) -> Vec<Integer> {
+        let (m, n) = self.a.dimensions();
+        (0..m)
+            .map(|j| {
+                let product: Integer = (0..n)
+                    .map(|i| Integer::from(&self.a[j][i] * &secret[i]))
+                    .sum();
+                centre(&self.b[j] - product, &self.q)
+            })
+            .collect()
+    }
+}
+
+/// Embedding used to turn the instance into a unique-SVP instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Embedding {
+    /// Kannan's embedding, of dimension `m + 1`
+    Kannan,
+
+    /// Bai and Galbraith's embedding, of dimension `n + m + 1`
+    BaiGalbraith,
+}
+
+/// Embedding lattice of an LWE instance, with embedding factor `M`
+///
+/// Returns `None` for Kannan's embedding if the first `n` columns of `A` are not invertible
+/// modulo `q` (see [`generator::q_ary_bases`]).
+pub fn embedding_lattice(
+    instance: &Instance,
+    embedding: Embedding,
+    embedding_factor: &Integer,
+) -> Option<Matrix<Integer>> {
+    let (m, n) = instance.a.dimensions();
+    let q = &instance.q;
+
+    match embedding {
+        Embedding::Kannan => {
+            let (primal, _) = generator::q_ary_bases(&instance.a, q)?;
+            let mut basis: Matrix<Integer> = Matrix::init(m + 1, m + 1);
+            for i in 0..m {
+                for j in 0..m {
+                    basis[i][j] = primal[i][j].clone();
+                }
+            }
+            for j in 0..m {
+                basis[m][j] = instance.b[j].clone().rem_euc(q);
+            }
+            basis[m][m] = embedding_factor.clone();
+            Some(basis)
+        }
+        Embedding::BaiGalbraith => {
+            // (e_i, -A^T e_i mod q, 0), (0, q e_j, 0) and (0, b, M)
+            let mut basis: Matrix<Integer> = Matrix::init(n + m + 1, n + m + 1);
+            for i in 0..n {
+                basis[i][i] = Integer::from(1);
+                for j in 0..m {
+                    basis[i][n + j] = Integer::from(-&instance.a[j][i]).rem_euc(q);
+                }
+            }
+            for j in 0..m {
+                basis[n + j][n + j] = q.clone();
+                basis[n + m][n + j] = instance.b[j].clone().rem_euc(q);
+            }
+            basis[n + m][n + m] = embedding_factor.clone();
+            Some(basis)
+        }
+    }
+}
+
+/// Solves `A^T s = v mod q` with Gauss-Jordan elimination, if the pivots are invertible modulo `q`
+pub(crate) fn solve_secret(
+    a: &Matrix<Integer>,
+    v: &[Integer],
+    q: &Integer,
+) -> Option<Vec<Integer>> {
+    let (m, n) = a.dimensions();
+    let mut rows: Vec<Vec<Integer>> = (0..m)
+        .map(|j| {
+            let mut row: Vec<Integer> = (0..n).map(|i| a[j][i].clone().rem_euc(q)).collect();
+            row.push(v[j].clone().rem_euc(q));
+            row
+        })
+        .collect();
+
+    for col in 0..n {
+        let pivot = (col..m).find(|&j| rows[j][col].clone().gcd(q) == 1)?;
+        rows.swap(col, pivot);
+        let inverse = rows[col][col].clone().invert(q).ok()?;
+        for entry in rows[col].iter_mut() {
+            *entry = Integer::from(&*entry * &inverse).rem_euc(q);
+        }
+        let pivot_row = rows[col].clone();
+        for (j, row) in rows.iter_mut().enumerate() {
+            if j != col && row[col] != 0 {
+                let factor = row[col].clone();
+                for (entry, pivot_entry) in row.iter_mut().zip(&pivot_row) {
+                    *entry = Integer::from(&*entry - &factor * pivot_entry).rem_euc(q);
+                }
+            }
+        }
+    }
+    Some(rows[..n].iter().map(|row| row[n].clone()).collect())
+}
+
+/// Runs the primal attack with a custom reduction pipeline
+///
+/// Builds the lattice of [`embedding_lattice`], reduces it with `reduce`, and looks for reduced
+/// vectors of the form `±(e, M)` (Kannan) or `±(s, e, M)` (Bai-Galbraith). Returns the secret
+/// (in `[0, q)`) and the error (centred) of the first such vector whose error has a norm at most
+/// `error_bound`, or `None` if there is none.
+pub fn primal_attack_with<F>(
+    instance: &Instance,
+    embedding: Embedding,
+    embedding_factor: &Integer,
+    error_bound: &Integer,
+    mut reduce: F,
+) -> Option<(Vec<Integer>, Vec<Integer>)>
+where
+    F: FnMut(&mut Matrix<Integer>),
+{
+    let (m, n) = instance.a.dimensions();
+    let q = &instance.q;
+    let mut basis = embedding_lattice(instance, embedding, embedding_factor)?;
+    reduce(&mut basis);
+
+    let (d, dimension) = basis.dimensions();
+    let bound_squared = Integer::from(error_bound * error_bound);
+    (0..d).find_map(|k| {
+        let v = &basis[k];
+        let last = &v[dimension - 1];
+        let sign = if last == embedding_factor {
+            1
+        } else if Integer::from(-last) == *embedding_factor {
+            -1
+        } else {
+            return None;
+        };
+        let v: Vec<Integer> = (0..dimension - 1)
+            .map(|j| Integer::from(&v[j] * sign))
+            .collect();
+
+        let secret = match embedding {
+            Embedding::Kannan => {
+                // v = b - A^T s mod q = e, up to multiples of q
+                let target: Vec<Integer> = (0..m)
+                    .map(|j| Integer::from(&instance.b[j] - &v[j]))
+                    .collect();
+                solve_secret(&instance.a, &target, q)?
+            }
+            Embedding::BaiGalbraith => v[..n].iter().map(|s_i| s_i.clone().rem_euc(q)).collect(),
+        };
+
+        let error = instance.error(&secret);
+        let norm: Integer = error.iter().map(|e_j| Integer::from(e_j * e_j)).sum();
+        if norm <= bound_squared {
+            Some((secret, error))
+        } else {
+            None
+        }
+    })
+}
+
+/// Runs the primal attack with the L² algorithm (`eta = 0.51`, `delta = 0.99`)
+///
+/// See [`primal_attack_with`].
+pub fn primal_attack(
+    instance: &Instance,
+    embedding: Embedding,
+    embedding_factor: &Integer,
+    error_bound: &Integer,
+) -> Option<(Vec<Integer>, Vec<Integer>)> {
+    primal_attack_with(
+        instance,
+        embedding,
+        embedding_factor,
+        error_bound,
+        |basis| l2::lll_bignum(basis, 0.51, 0.99).expect("The lattice basis is valid"),
+    )
+}
@@ -0,0 +1,126 @@
+//! Key recovery attack on NTRU
+//!
+//! An NTRU public key is `h = p g / f mod q` in `Z_q[x] / (x^N - 1)`, for private polynomials `f`
+//! and `g` with small coefficients. With `h' = h / p mod q`, the vector `(f, g)` belongs to the
+//! `2N`-dimensional lattice of Coppersmith and Shamir [CS97], spanned by the rows of
+//!
+//! ```text
+//! [ I  H' ]
+//! [ 0  qI ]
+//! ```
+//!
+//! where the `i`-th row of the circulant matrix `H'` is `x^i h'`. All the rotations `(x^i f, x^i g)`
+//! are short vectors of this lattice, and any of them is a valid private key.
+//!
+//! [CS97]: D. Coppersmith and A. Shamir. Lattice Attacks on NTRU. EUROCRYPT 1997.
+
+use crate::{algebra::Matrix, l2};
+
+use rug::{ops::RemRounding, Integer};
+
+/// Public parameters and public key `h` (of degree less than `N`) of an NTRU instance
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicKey {
+    /// Degree `N` of the ring `Z[x] / (x^N - 1)`
+    pub n: usize,
+
+    /// Small modulus `p`
+    pub p: Integer,
+
+    /// Large modulus `q`
+    pub q: Integer,
+
+    /// Coefficients of `h`, constant first
+    pub h: Vec<Integer>,
+}
+
+impl PublicKey {
+    pub fn new(n: usize, p: Integer, q: Integer, h: Vec<Integer>) -> Self {
+        Self { n, p, q, h }
+    }
+}
+
+/// Multiplication by `x^k` in `Z[x] / (x^N - 1)`, for `N = polynomial.len()`
+pub fn rotate(polynomial: &[Integer], k: usize) -> Vec<Integer> {
+    let n = polynomial.len();
+    (0..n)
+        .map(|j| polynomial[(j + n - k % n) % n].clone())
+        .collect()
+}
+
+/// All the rotations `(x^i f, x^i g)` of a key pair, for `0 <= i < N`
+pub fn rotations(f: &[Integer], g: &[Integer]) -> Vec<(Vec<Integer>, Vec<Integer>)> {
+    (0..f.len()).map(|i| (rotate(f, i), rotate(g, i))).collect()
+}
+
+/// The `2N`-dimensional Coppersmith-Shamir lattice of a public key
+///
+/// # Panics
+/// if `p` is not invertible modulo `q` or if `h` has more than `N` coefficients
+pub fn lattice(key: &PublicKey) -> Matrix<Integer> {
+    let n = key.n;
+    assert!(key.h.len() <= n);
+
+    let p_inverse = Integer::from(
+        key.p
+            .invert_ref(&key.q)
+            .expect("p must be invertible modulo q"),
+    );
+    let mut h: Vec<Integer> = key
+        .h
+        .iter()
+        .map(|c| Integer::from(c * &p_inverse).rem_euc(&key.q))
+        .collect();
+    h.resize(n, Integer::new());
+
+    let mut basis: Matrix<Integer> = Matrix::init(2 * n, 2 * n);
+    for i in 0..n {
+        basis[i][i] = Integer::from(1);
+        for (j, c) in rotate(&h, i).into_iter().enumerate() {
+            basis[i][n + j] = c;
+        }
+        basis[n + i][n + i] = key.q.clone();
+    }
+    basis
+}
+
+/// Runs the attack with a custom reduction pipeline
+///
+/// Builds the lattice of [`lattice`], reduces it with `reduce` (e.g. several L² passes with
+/// increasing `delta`), and returns the candidate keys `(f, g)` given by the reduced basis vectors
+/// shorter than the Gaussian heuristic `sqrt(N q / (πe))`, sorted by increasing norm.
+/// Vectors with `f = 0` or `g = 0` (such as `(1, ..., 1, 0, ..., 0)` when `h(1) = 0 mod q`) are
+/// not keys and are skipped.
+pub fn attack_with<F>(key: &PublicKey, mut reduce: F) -> Vec<(Vec<Integer>, Vec<Integer>)>
+where
+    F: FnMut(&mut Matrix<Integer>),
+{
+    let n = key.n;
+    let mut basis = lattice(key);
+    reduce(&mut basis);
+
+    let mut candidates: Vec<(Integer, Vec<Integer>, Vec<Integer>)> = (0..2 * n)
+        .map(|i| {
+            let v = &basis[i];
+            let f: Vec<Integer> = (0..n).map(|j| v[j].clone()).collect();
+            let g: Vec<Integer> = (n..2 * n).map(|j| v[j].clone()).collect();
+            (v.dot(v), f, g)
+        })
+        .filter(|(_, f, g)| f.iter().any(|c| *c != 0) && g.iter().any(|c| *c != 0))
+        .filter(|(norm, _, _)| {
+            // ||v||^2 < N q / (πe)
+            norm.to_f64() * std::f64::consts::PI * std::f64::consts::E < n as f64 * key.q.to_f64()
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.0.cmp(&b.0));
+    candidates.into_iter().map(|(_, f, g)| (f, g)).collect()
+}
+
+/// Runs the attack with the L² algorithm (`eta = 0.51`, `delta = 0.99`)
+///
+/// See [`attack_with`].
+pub fn attack(key: &PublicKey) -> Vec<(Vec<Integer>, Vec<Integer>)> {
+    attack_with(key, |basis| {
+        l2::lll_bignum(basis, 0.51, 0.99).expect("The lattice basis is valid")
+    })
+}
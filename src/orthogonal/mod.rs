@@ -0,0 +1,152 @@
+//! Orthogonal lattices and integer kernels
+//!
+//! The orthogonal lattice of vectors `b_1, ..., b_d` of `Z^n` is the lattice
+//! `{ x in Z^n : <x, b_i> = 0 for all i }`, of rank `n - r` where `r` is the rank of the `b_i`.
+//! Following Nguyen and Stern [NS97], it is computed by reducing the lattice spanned by the
+//! vectors `(c b_1[j], ..., c b_d[j], e_j)` for a large constant `c`: the reduced vectors whose
+//! first `d` coordinates vanish form a basis of the orthogonal lattice. The same technique gives
+//! the small solutions of modular linear equations.
+//!
+//! [NS97]: P. Q. Nguyen and J. Stern. Merkle-Hellman Revisited: A Cryptanalysis of the
+//! Qu-Vanstone Cryptosystem Based on Group Factorizations. CRYPTO 1997.
+
+use crate::{algebra::Matrix, l2};
+
+use rug::Integer;
+
+/// Sublattice of the vectors `(c p_k, t_k)` spanned by `generators = [(p_k, t_k)]` whose prefix vanishes
+///
+/// The generators must be linearly independent, and `expected` is the rank of the sublattice.
+/// The constant `c` is increased until the L²-reduced basis contains `expected` vectors with a
+/// zero prefix, which then form a basis of the sublattice. Returns their tails.
+fn zero_prefix_sublattice(
+    generators: &[(Vec<Integer>, Vec<Integer>)],
+    expected: usize,
+) -> Matrix<Integer> {
+    let k = generators.len();
+    let (d, n) = match generators.first() {
+        Some((prefix, tail)) => (prefix.len(), tail.len()),
+        None => return Matrix::init(0, 0),
+    };
+
+    let mut c = Integer::from(1) << (k as u32 + 1);
+    loop {
+        let mut basis: Matrix<Integer> = Matrix::init(k, d + n);
+        for (j, (prefix, tail)) in generators.iter().enumerate() {
+            for i in 0..d {
+                basis[j][i] = Integer::from(&c * &prefix[i]);
+            }
+            for i in 0..n {
+                basis[j][d + i] = tail[i].clone();
+            }
+        }
+        l2::lll_bignum(&mut basis, 0.51, 0.99).expect("The lattice basis is valid");
+
+        let zero_prefix: Vec<usize> = (0..k)
+            .filter(|&j| (0..d).all(|i| basis[j][i] == 0))
+            .collect();
+        if zero_prefix.len() == expected {
+            let mut result: Matrix<Integer> = Matrix::init(expected, n);
+            for (l, &j) in zero_prefix.iter().enumerate() {
+                for i in 0..n {
+                    result[l][i] = basis[j][d + i].clone();
+                }
+            }
+            return result;
+        }
+
+        c <<= k as u32 + 1;
+    }
+}
+
+/// Reduced basis of the orthogonal lattice of `vectors`
+///
+/// Returns the `n - r` basis vectors of the lattice of the integer vectors orthogonal to all the
+/// given vectors of `Z^n`, where `r` is their rank. The basis vectors are taken from an L²-reduced
+/// basis, so that they are short. The result is exact: the constant `c` is increased until the
+/// reduced basis contains the expected number of vectors orthogonal to the input.
+///
+/// An empty family yields an empty basis, as the dimension `n` is unknown.
+pub fn orthogonal_lattice(vectors: &Matrix<Integer>) -> Matrix<Integer> {
+    let (d, n) = vectors.dimensions();
+    if d == 0 {
+        return Matrix::init(0, 0);
+    }
+
+    // (c b_1[j], ..., c b_d[j], e_j)
+    let generators: Vec<(Vec<Integer>, Vec<Integer>)> = (0..n)
+        .map(|j| {
+            let mut e_j = vec![Integer::new(); n];
+            e_j[j] = Integer::from(1);
+            ((0..d).map(|i| vectors[i][j].clone()).collect(), e_j)
+        })
+        .collect();
+    zero_prefix_sublattice(&generators, n - vectors.rank())
+}
+
+/// Reduced basis of the integer kernel of a family of vectors
+///
+/// Returns a basis of the lattice of integer dependencies `x` with `sum x_j v_j = 0` between the
+/// vectors `v_j` of `vectors`, that is, of the integer null space of the matrix whose columns
+/// are the `v_j`. This is the orthogonal lattice of the rows of that matrix, and its basis is
+/// L²-reduced so that the dependencies have small coefficients.
+pub fn integer_kernel(vectors: &Matrix<Integer>) -> Matrix<Integer> {
+    let (n, m) = vectors.dimensions();
+    if m == 0 {
+        return Matrix::identity(n);
+    }
+
+    let mut rows: Matrix<Integer> = Matrix::init(m, n);
+    for i in 0..m {
+        for j in 0..n {
+            rows[i][j] = vectors[j][i].clone();
+        }
+    }
+    orthogonal_lattice(&rows)
+}
+
+/// Reduced basis of the lattice of the solutions of `a · x = 0 mod modulus`
+///
+/// The lattice has full rank `n`. It is computed as the sublattice with a zero first coordinate
+/// of the lattice spanned by `(c a_j, e_j)` and `(c modulus, 0)`, and its basis is L²-reduced.
+///
+/// # Panics
+/// if `modulus` is zero
+pub fn modular_kernel(a: &[Integer], modulus: &Integer) -> Matrix<Integer> {
+    assert!(*modulus != 0);
+    let n = a.len();
+    let mut generators: Vec<(Vec<Integer>, Vec<Integer>)> = a
+        .iter()
+        .enumerate()
+        .map(|(j, a_j)| {
+            let mut e_j = vec![Integer::new(); n];
+            e_j[j] = Integer::from(1);
+            (vec![a_j.clone()], e_j)
+        })
+        .collect();
+    generators.push((vec![modulus.clone()], vec![Integer::new(); n]));
+    zero_prefix_sublattice(&generators, n)
+}
+
+/// Finds a non-zero solution `x` of `a · x = 0 mod modulus` with `||x|| <= bound`
+///
+/// Returns the shortest vector of the reduced basis of [`modular_kernel`] if it satisfies the bound.
+/// As lattice reduction only approximates the shortest vector, a solution may exist even if
+/// `None` is returned, although this is unlikely when it is much shorter than the other solutions.
+///
+/// # Panics
+/// if `modulus` is zero
+pub fn small_modular_solution(
+    a: &[Integer],
+    modulus: &Integer,
+    bound: &Integer,
+) -> Option<Vec<Integer>> {
+    let kernel = modular_kernel(a, modulus);
+    let (d, n) = kernel.dimensions();
+    let bound_squared = Integer::from(bound * bound);
+    (0..d)
+        .map(|i| (kernel[i].norm_sqr(), i))
+        .filter(|(norm, _)| *norm != 0 && *norm <= bound_squared)
+        .min()
+        .map(|(_, i)| (0..n).map(|j| kernel[i][j].clone()).collect())
+}
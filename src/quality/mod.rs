@@ -0,0 +1,201 @@
+//! Quality metrics for lattice bases
+//!
+//! These helpers summarise how "good" a basis is, e.g. after a call to one of the
+//! reduction algorithms of this crate.
+
+use crate::algebra::{Float, FromExt, Gso, Matrix, Scalar};
+
+#[cfg(feature = "rug")]
+use crate::algebra::BigNum;
+#[cfg(feature = "num")]
+use crate::algebra::NumBigInt;
+
+/// Summary of the quality of a lattice basis
+///
+/// Zero vectors in the basis (such as the ones produced by L² on linearly dependent
+/// inputs) are ignored by every metric.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BasisQuality {
+    /// Orthogonality defect `(prod ||b_i||) / vol(L)`
+    ///
+    /// It is equal to 1 for an orthogonal basis and grows as the basis gets worse.
+    /// It is infinite when the non-zero basis vectors are linearly dependent.
+    pub orthogonality_defect: f64,
+
+    /// Norms `||b*_i||` of the Gram-Schmidt vectors, in basis order
+    pub gso_norms: Vec<f64>,
+
+    /// Logarithm (base 2) of the volume of the lattice
+    pub log_volume: f64,
+}
+
+/// Computes the quality metrics of `basis` using generic Scalars
+fn basis_quality<S: Scalar>(basis: &Matrix<S::Integer>) -> BasisQuality {
+    let gso = Gso::<S>::compute(basis);
+    let zero = S::Fraction::from(0);
+
+    let mut log_volume = 0.;
+    let mut log_norms = 0.;
+    let mut dependent = false;
+    let mut gso_norms = Vec::with_capacity(gso.dimension());
+
+    for i in 0..gso.dimension() {
+        if basis[i].is_zero() {
+            continue;
+        }
+
+        let r_i = &gso.r[i];
+        if r_i > &zero {
+            let log_r = S::log2(r_i) / 2.;
+            log_volume += log_r;
+            gso_norms.push(log_r.exp2());
+        } else {
+            dependent = true;
+            gso_norms.push(0.);
+        }
+
+        log_norms += S::log2(&S::Fraction::from_ext(&basis[i].norm_sqr())) / 2.;
+    }
+
+    let orthogonality_defect = if dependent {
+        f64::INFINITY
+    } else {
+        (log_norms - log_volume).exp2()
+    };
+
+    BasisQuality {
+        orthogonality_defect,
+        gso_norms,
+        log_volume,
+    }
+}
+
+/// Estimates the packing radius `lambda_1 / 2` using generic Scalars
+fn packing_radius<S: Scalar>(basis: &Matrix<S::Integer>) -> f64 {
+    let (d, _) = basis.dimensions();
+
+    let log_lambda_1 = (0..d)
+        .filter(|&i| !basis[i].is_zero())
+        .map(|i| S::log2(&S::Fraction::from_ext(&basis[i].norm_sqr())) / 2.)
+        .fold(f64::INFINITY, f64::min);
+
+    (log_lambda_1 - 1.).exp2()
+}
+
+/// Upper bound on the covering radius using generic Scalars
+fn covering_radius_bound<S: Scalar>(basis: &Matrix<S::Integer>) -> f64 {
+    let gso = Gso::<S>::compute(basis);
+
+    let sum_r = (0..gso.dimension())
+        .map(|i| gso.r[i].clone())
+        .fold(S::Fraction::from(0), |acc, r_i| acc + &r_i);
+
+    if sum_r == S::Fraction::from(0) {
+        return 0.;
+    }
+    (S::log2(&sum_r) / 2. - 1.).exp2()
+}
+
+/// Quality metrics of a basis
+///
+/// This implementation uses `rug::Integer` and `rug::Rational` for the underlying arithmetic operations,
+/// so that the Gram-Schmidt orthogonalisation is exact.
+#[cfg(feature = "rug")]
+pub fn basis_quality_bignum(basis: &Matrix<rug::Integer>) -> BasisQuality {
+    basis_quality::<BigNum>(basis)
+}
+
+/// Quality metrics of a basis
+///
+/// This implementation uses `num_bigint::BigInt` and `num_rational::BigRational` for the underlying arithmetic operations,
+/// so that the Gram-Schmidt orthogonalisation is exact.
+#[cfg(feature = "num")]
+pub fn basis_quality_num_bigint(basis: &Matrix<num_bigint::BigInt>) -> BasisQuality {
+    basis_quality::<NumBigInt>(basis)
+}
+
+/// Quality metrics of a basis
+///
+/// This implementation uses platform double floating-point numbers (IEEE 754)
+/// for the underlying arithmetic operations.
+pub fn basis_quality_float(basis: &Matrix<f64>) -> BasisQuality {
+    basis_quality::<Float>(basis)
+}
+
+/// Estimate of the packing radius `lambda_1 / 2` of the lattice
+///
+/// `lambda_1` is estimated by the norm of the shortest non-zero basis vector. This is an upper bound
+/// on the actual packing radius, which is tight when the basis contains a shortest vector of the lattice.
+/// For an LLL-reduced basis it is off by at most a factor `2^((d-1)/2)`.
+///
+/// This implementation uses `rug::Integer` and `rug::Rational` for the underlying arithmetic operations.
+///
+/// Returns infinity if the basis has no non-zero vector.
+#[cfg(feature = "rug")]
+pub fn packing_radius_bignum(basis: &Matrix<rug::Integer>) -> f64 {
+    packing_radius::<BigNum>(basis)
+}
+
+/// Estimate of the packing radius `lambda_1 / 2` of the lattice
+///
+/// `lambda_1` is estimated by the norm of the shortest non-zero basis vector. This is an upper bound
+/// on the actual packing radius, which is tight when the basis contains a shortest vector of the lattice.
+/// For an LLL-reduced basis it is off by at most a factor `2^((d-1)/2)`.
+///
+/// This implementation uses `num_bigint::BigInt` and `num_rational::BigRational` for the underlying arithmetic operations.
+///
+/// Returns infinity if the basis has no non-zero vector.
+#[cfg(feature = "num")]
+pub fn packing_radius_num_bigint(basis: &Matrix<num_bigint::BigInt>) -> f64 {
+    packing_radius::<NumBigInt>(basis)
+}
+
+/// Estimate of the packing radius `lambda_1 / 2` of the lattice
+///
+/// `lambda_1` is estimated by the norm of the shortest non-zero basis vector. This is an upper bound
+/// on the actual packing radius, which is tight when the basis contains a shortest vector of the lattice.
+/// For an LLL-reduced basis it is off by at most a factor `2^((d-1)/2)`.
+///
+/// This implementation uses platform double floating-point numbers (IEEE 754)
+/// for the underlying arithmetic operations.
+///
+/// Returns infinity if the basis has no non-zero vector.
+pub fn packing_radius_float(basis: &Matrix<f64>) -> f64 {
+    packing_radius::<Float>(basis)
+}
+
+/// Upper bound on the covering radius of the lattice
+///
+/// The bound is `sqrt(sum ||b*_i||²) / 2`: Babai's nearest plane algorithm always finds a lattice
+/// vector within this distance of any target.
+/// The covering radius is taken in the span of the lattice.
+///
+/// This implementation uses `rug::Integer` and `rug::Rational` for the underlying arithmetic operations.
+#[cfg(feature = "rug")]
+pub fn covering_radius_bound_bignum(basis: &Matrix<rug::Integer>) -> f64 {
+    covering_radius_bound::<BigNum>(basis)
+}
+
+/// Upper bound on the covering radius of the lattice
+///
+/// The bound is `sqrt(sum ||b*_i||²) / 2`: Babai's nearest plane algorithm always finds a lattice
+/// vector within this distance of any target.
+/// The covering radius is taken in the span of the lattice.
+///
+/// This implementation uses `num_bigint::BigInt` and `num_rational::BigRational` for the underlying arithmetic operations.
+#[cfg(feature = "num")]
+pub fn covering_radius_bound_num_bigint(basis: &Matrix<num_bigint::BigInt>) -> f64 {
+    covering_radius_bound::<NumBigInt>(basis)
+}
+
+/// Upper bound on the covering radius of the lattice
+///
+/// The bound is `sqrt(sum ||b*_i||²) / 2`: Babai's nearest plane algorithm always finds a lattice
+/// vector within this distance of any target.
+/// The covering radius is taken in the span of the lattice.
+///
+/// This implementation uses platform double floating-point numbers (IEEE 754)
+/// for the underlying arithmetic operations.
+pub fn covering_radius_bound_float(basis: &Matrix<f64>) -> f64 {
+    covering_radius_bound::<Float>(basis)
+}
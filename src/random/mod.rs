@@ -0,0 +1,59 @@
+//! Deterministic randomness for reproducible experiments
+//!
+//! The randomized functions of this crate (the instance generators, `Matrix::random`, the randomized nearest
+//! plane, ...) never draw from a global source: they take any `rand_core::RngCore` as an argument. A
+//! [`SeededRng`] makes a run reproducible from a single `u64` seed, without any other dependency.
+
+use rand_core::{impls, Error, RngCore, SeedableRng};
+
+/// Small deterministic generator (SplitMix64), whose outputs only depend on its seed
+///
+/// It passes the usual statistical tests and is fast, but it is not cryptographically secure: the seed is
+/// recovered from a single output. Use it to reproduce experiments, not to generate keys.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    /// Create the generator with the given seed
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for SeededRng {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: [u8; 8]) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    /// Same as [`SeededRng::new`]
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
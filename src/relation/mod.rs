@@ -0,0 +1,391 @@
+//! Integer relations between real numbers
+//!
+//! An integer relation between reals `α_1, ..., α_n` is a non-zero integer vector `r` with
+//! `sum r_i α_i = 0`. Given approximations of the `α_i`, a small relation is found as a short
+//! vector of the lattice spanned by the rows of `[I | round(C α)]` for a large scaling factor `C`:
+//! the last coordinate of `(r, sum r_i round(C α_i))` is small only when `r` is a relation up to the
+//! working precision.
+//!
+//! The values are given as `rug::Float` numbers, so that the precision is only limited by the
+//! precision of the inputs. A relation found at precision `C = 2^precision` is only a candidate:
+//! it holds up to `2^-precision`, which is meaningful when its coefficients are small compared to
+//! `C^(1/n)`.
+//!
+//! Similar lattices give simultaneous rational approximations of real numbers, and the linear
+//! recurrences satisfied by sequences of integers or reals.
+
+use crate::{algebra::Matrix, enumeration, l2, orthogonal};
+
+use rug::{ops::Pow, Float, Integer, Rational};
+
+/// Lattice basis `(e_i, round(2^precision α_i))` of the integer relations between `values`
+///
+/// # Panics
+/// if some value is not finite
+pub fn relation_lattice(values: &[Float], precision: u32) -> Matrix<Integer> {
+    let n = values.len();
+    let mut basis: Matrix<Integer> = Matrix::init(n, n + 1);
+    for (i, value) in values.iter().enumerate() {
+        basis[i][i] = Integer::from(1);
+        basis[i][n] = Float::with_val(value.prec(), value << precision)
+            .to_integer()
+            .expect("The values must be finite");
+    }
+    basis
+}
+
+/// Normalises `v` so that its last non-zero entry is positive
+fn normalise_sign(v: &mut [Integer]) {
+    if v.iter()
+        .rev()
+        .find(|v_i| **v_i != 0)
+        .is_some_and(|v_i| *v_i < 0)
+    {
+        for v_i in v.iter_mut() {
+            *v_i = Integer::from(-&*v_i);
+        }
+    }
+}
+
+/// Finds a small integer relation `r` between `values`, with `|sum r_i α_i| < 2^-precision`
+///
+/// Reduces the lattice of [`relation_lattice`] with L² and returns the first reduced vector,
+/// normalised so that its last non-zero coefficient is positive. Returns `None` if this vector is
+/// not a relation at the given precision, evaluated with the full precision of the values.
+///
+/// `precision` should be somewhat lower than the precision of the values, so that their own
+/// approximation errors do not hide the relation.
+///
+/// # Panics
+/// if some value is not finite
+pub fn integer_relation(values: &[Float], precision: u32) -> Option<Vec<Integer>> {
+    let n = values.len();
+    if n == 0 {
+        return None;
+    }
+    let mut basis = relation_lattice(values, precision);
+    l2::lll_bignum(&mut basis, 0.51, 0.99).expect("The lattice basis is valid");
+
+    let shortest = &basis[0];
+    let mut relation: Vec<Integer> = (0..n).map(|i| shortest[i].clone()).collect();
+
+    // The relation must hold at the full precision of the values, not only up to the rounding errors
+    if relation.iter().all(|r| *r == 0) {
+        return None;
+    }
+    let working_precision = values.iter().map(|v| v.prec()).max().unwrap_or(0) + 64;
+    let residual = values
+        .iter()
+        .zip(&relation)
+        .fold(Float::new(working_precision), |acc, (value, r)| {
+            acc + Float::with_val(working_precision, value * r)
+        });
+    if (residual.abs() << precision) >= 1 {
+        return None;
+    }
+
+    normalise_sign(&mut relation);
+    Some(relation)
+}
+
+/// Finds a polynomial with small integer coefficients and degree at most `degree` vanishing at `alpha`
+///
+/// This is an integer relation between `1, α, ..., α^degree`: if `alpha` is an approximation of an
+/// algebraic number of degree at most `degree` with a small minimal polynomial, the polynomial is
+/// its minimal polynomial (up to sign). Returns the coefficients, constant first and without
+/// leading zeros, or `None` if no relation is found at the given precision.
+///
+/// # Panics
+/// if `alpha` is not finite
+pub fn algebraic_dependency(alpha: &Float, degree: u32, precision: u32) -> Option<Vec<Integer>> {
+    let powers: Vec<Float> = (0..=degree)
+        .map(|i| Float::with_val(alpha.prec(), alpha.pow(i)))
+        .collect();
+
+    let mut polynomial = integer_relation(&powers, precision)?;
+    while polynomial.last().is_some_and(|c| *c == 0) {
+        polynomial.pop();
+    }
+    Some(polynomial)
+}
+
+/// Simultaneous Diophantine approximation of `values` with a denominator at most `bound`
+///
+/// Finds `q` in `[1, bound]` and integers `p_i` such that all the errors `|q α_i - p_i|` are small,
+/// which by Dirichlet's theorem can be achieved with errors at most `bound^(-1/n)`. Lattice reduction
+/// finds a solution within a factor about `2^(n/2)` of the optimum, using the lattice of Lagarias
+/// spanned by `(1, C α_1, ..., C α_n)` and `C e_i`, with `C = bound^(1 + 1/n)` so that both
+/// the denominator and the errors are balanced.
+///
+/// Returns `(q, p)` for the reduced vector with the smallest maximal error, or `None` if no reduced
+/// vector has a denominator in `[1, bound]`. The values should have a precision of at least
+/// `log2(bound) (1 + 1/n) + 16` bits.
+///
+/// # Panics
+/// if some value is not finite or if `bound < 1`
+pub fn simultaneous_approximation(
+    values: &[Float],
+    bound: &Integer,
+) -> Option<(Integer, Vec<Integer>)> {
+    assert!(*bound >= 1);
+    let n = values.len();
+    if n == 0 {
+        return Some((Integer::from(1), vec![]));
+    }
+
+    // Scaling C = 2^k, and an extra factor 2^16 absorbing the rounding errors on C α_i
+    let bound_bits = bound.significant_bits();
+    let k = bound_bits + bound_bits.div_ceil(n as u32);
+    let extra = 16;
+
+    let mut basis: Matrix<Integer> = Matrix::init(n + 1, n + 1);
+    basis[0][0] = Integer::from(1) << extra;
+    for (i, value) in values.iter().enumerate() {
+        basis[0][i + 1] = Float::with_val(value.prec(), value << (k + extra))
+            .to_integer()
+            .expect("The values must be finite");
+        basis[i + 1][i + 1] = Integer::from(1) << (k + extra);
+    }
+    l2::lll_bignum(&mut basis, 0.51, 0.99).expect("The lattice basis is valid");
+
+    let working_precision = values.iter().map(|v| v.prec()).max().unwrap_or(0) + 64;
+    (0..=n)
+        .filter_map(|j| {
+            let v = &basis[j];
+            let q = Integer::from(&v[0] >> extra).abs();
+            if q == 0 || q > *bound {
+                return None;
+            }
+
+            // For a given denominator, the best numerators are the roundings of q α_i
+            let numerators: Vec<Integer> = values
+                .iter()
+                .map(|value| {
+                    Float::with_val(working_precision, value * &q)
+                        .to_integer()
+                        .expect("The values must be finite")
+                })
+                .collect();
+            let error = values
+                .iter()
+                .zip(&numerators)
+                .map(|(value, p)| (Float::with_val(working_precision, value * &q) - p).abs())
+                .fold(Float::new(working_precision), |acc, e| acc.max(&e));
+            Some((error, q, numerators))
+        })
+        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(_, q, numerators)| (q, numerators))
+}
+
+/// Recovers a rational `p / q` with `1 <= q <= bound` from an approximation `value`
+///
+/// Reduces the 2-dimensional lattice spanned by `(1, round(C α))` and `(0, C)`, with `C = bound^2`,
+/// whose short vectors are `(q, C (q α - p))`. Returns the fraction if `|q α - p| < 1 / (2 bound)`,
+/// in which case it is the only fraction with denominator at most `bound` this close to `value`,
+/// and `None` otherwise. Decimal or `f64` approximations can be converted with `Float::with_val`.
+///
+/// # Panics
+/// if `value` is not finite or if `bound < 1`
+pub fn rational_reconstruction(value: &Float, bound: &Integer) -> Option<Rational> {
+    assert!(*bound >= 1);
+    let k = 2 * bound.significant_bits();
+    let working_precision = value.prec().max(k) + 64;
+
+    let mut basis: Matrix<Integer> = Matrix::init(2, 2);
+    basis[0][0] = Integer::from(1);
+    basis[0][1] = Float::with_val(working_precision, value << k)
+        .to_integer()
+        .expect("The value must be finite");
+    basis[1][1] = Integer::from(1) << k;
+    l2::lll_bignum(&mut basis, 0.51, 0.99).expect("The lattice basis is valid");
+
+    (0..2)
+        .map(|i| basis[i][0].clone().abs())
+        .filter(|q| *q != 0 && q <= bound)
+        .map(|q| {
+            let p = Float::with_val(working_precision, value * &q)
+                .to_integer()
+                .expect("The value must be finite");
+            (p, q)
+        })
+        .find(|(p, q)| {
+            let error = (Float::with_val(working_precision, value * q) - p).abs();
+            error * bound * 2 < 1
+        })
+        .map(|(p, q)| Rational::from((p, q)))
+}
+
+/// Recovers rationals `p_i / q` with a common denominator `1 <= q <= bound` from approximations `values`
+///
+/// The common denominator is found with [`simultaneous_approximation`]. Returns the fractions if
+/// all the errors `|q α_i - p_i|` are below `1 / (2 bound)`, and `None` otherwise.
+///
+/// # Panics
+/// if some value is not finite or if `bound < 1`
+pub fn rational_reconstruction_batch(values: &[Float], bound: &Integer) -> Option<Vec<Rational>> {
+    let (q, numerators) = simultaneous_approximation(values, bound)?;
+    let working_precision = values.iter().map(|v| v.prec()).max().unwrap_or(0) + 64;
+
+    let close = values.iter().zip(&numerators).all(|(value, p)| {
+        let error = (Float::with_val(working_precision, value * &q) - p).abs();
+        error * bound * 2 < 1
+    });
+    if !close {
+        return None;
+    }
+    Some(
+        numerators
+            .into_iter()
+            .map(|p| Rational::from((p, q.clone())))
+            .collect(),
+    )
+}
+
+/// Finds the shortest linear recurrence of smallest order satisfied by an integer sequence
+///
+/// A recurrence of order `d` is a vector `c = (c_0, ..., c_d)` with `sum c_k s_(i+k) = 0` for all
+/// the windows of `d + 1` consecutive terms of the sequence. For each order up to `max_order`,
+/// the lattice of the recurrences is computed exactly as an integer kernel (see
+/// [`orthogonal::integer_kernel`]), and its shortest vector is found by enumeration. Returns this
+/// vector, normalised so that its last non-zero coefficient is positive, for the smallest order
+/// that admits a recurrence.
+///
+/// The result is exact: `None` proves that the given terms satisfy no linear recurrence of order
+/// at most `max_order`, and a returned recurrence of Euclidean norm `B` proves that none of the
+/// same order has a smaller norm.
+///
+/// # Panics
+/// if the sequence has at most `2 max_order` terms, as a recurrence of order `d` always exists
+/// for fewer than `2 d + 1` terms
+pub fn integer_recurrence(sequence: &[Integer], max_order: usize) -> Option<Vec<Integer>> {
+    assert!(sequence.len() > 2 * max_order);
+    let n = sequence.len();
+
+    (1..=max_order).find_map(|d| {
+        // The columns (s_k, ..., s_(k + n - d - 1)) for k <= d
+        let mut windows: Matrix<Integer> = Matrix::init(d + 1, n - d);
+        for k in 0..=d {
+            for i in 0..n - d {
+                windows[k][i] = sequence[k + i].clone();
+            }
+        }
+        let kernel = orthogonal::integer_kernel(&windows);
+        let (rank, _) = kernel.dimensions();
+        if rank == 0 {
+            return None;
+        }
+
+        // The reduced basis gives a bound on the norm of the shortest recurrence
+        let bound = (0..rank)
+            .map(|i| kernel[i].norm_sqr())
+            .min()
+            .expect("The kernel is not empty");
+        let mut shortest: Option<(Integer, Vec<Integer>)> = None;
+        enumeration::enumerate_bignum(&kernel, &bound, |_, v| {
+            let norm = v.dot(v);
+            if norm == 0 {
+                return;
+            }
+            let mut c: Vec<Integer> = (0..=d).map(|k| v[k].clone()).collect();
+            normalise_sign(&mut c);
+            let candidate = (norm, c);
+            if shortest.as_ref().is_none_or(|best| candidate < *best) {
+                shortest = Some(candidate);
+            }
+        });
+        shortest.map(|(_, c)| c)
+    })
+}
+
+/// Lattice basis of the linear recurrences of order `order` of a sequence of reals
+///
+/// The basis vectors are `(e_k, round(2^precision α_k), ..., round(2^precision α_(k + n - d - 1)))`
+/// for `k <= d`, where `d = order`: a recurrence `c` gives the short vector of the lattice whose
+/// last coordinates are the scaled residuals `sum c_k α_(i+k)` of all the windows.
+///
+/// # Panics
+/// if the sequence has at most `order` terms, or if some term is not finite
+pub fn recurrence_lattice(sequence: &[Float], order: usize, precision: u32) -> Matrix<Integer> {
+    let n = sequence.len();
+    assert!(n > order);
+    let scaled: Vec<Integer> = sequence
+        .iter()
+        .map(|value| {
+            Float::with_val(value.prec(), value << precision)
+                .to_integer()
+                .expect("The values must be finite")
+        })
+        .collect();
+
+    let d = order;
+    let mut basis: Matrix<Integer> = Matrix::init(d + 1, d + 1 + n - d);
+    for k in 0..=d {
+        basis[k][k] = Integer::from(1);
+        for i in 0..n - d {
+            basis[k][d + 1 + i] = scaled[k + i].clone();
+        }
+    }
+    basis
+}
+
+/// Finds a short linear recurrence of smallest order satisfied by a sequence of reals
+///
+/// For each order `d` up to `max_order`, reduces the lattice of [`recurrence_lattice`] with L² and
+/// returns the first reduced vector `c` if `|sum c_k α_(i+k)| < 2^-precision` for all the windows,
+/// evaluated with the full precision of the terms. The recurrence is normalised so that its last
+/// non-zero coefficient is positive. As for [`integer_relation`], a recurrence is only a candidate
+/// when its coefficients are not small compared to `2^precision`, and `None` does not prove that
+/// no recurrence exists. Integer sequences should use the exact [`integer_recurrence`].
+///
+/// # Panics
+/// if the sequence has at most `2 max_order` terms, or if some term is not finite
+pub fn recurrence(sequence: &[Float], max_order: usize, precision: u32) -> Option<Vec<Integer>> {
+    assert!(sequence.len() > 2 * max_order);
+    let n = sequence.len();
+    let working_precision = sequence.iter().map(|v| v.prec()).max().unwrap_or(0) + 64;
+
+    (1..=max_order).find_map(|d| {
+        let mut basis = recurrence_lattice(sequence, d, precision);
+        l2::lll_bignum(&mut basis, 0.51, 0.99).expect("The lattice basis is valid");
+
+        let mut c: Vec<Integer> = (0..=d).map(|k| basis[0][k].clone()).collect();
+        if c.iter().all(|c_k| *c_k == 0) {
+            return None;
+        }
+        let holds = (0..n - d).all(|i| {
+            let residual = c
+                .iter()
+                .zip(&sequence[i..])
+                .fold(Float::new(working_precision), |acc, (c_k, value)| {
+                    acc + Float::with_val(working_precision, value * c_k)
+                });
+            (residual.abs() << precision) < 1
+        });
+        if !holds {
+            return None;
+        }
+        normalise_sign(&mut c);
+        Some(c)
+    })
+}
+
+/// Next term of an integer sequence satisfying the recurrence `c`
+///
+/// Returns `s_n = -(sum_(k < d) c_k s_(n - d + k)) / c_d`, or `None` if the leading coefficient
+/// `c_d` is zero, if the sequence has fewer than `d` terms or if the division is not exact.
+pub fn next_term(recurrence: &[Integer], sequence: &[Integer]) -> Option<Integer> {
+    let (leading, coefficients) = recurrence.split_last()?;
+    let d = coefficients.len();
+    if *leading == 0 || sequence.len() < d {
+        return None;
+    }
+    let sum: Integer = coefficients
+        .iter()
+        .zip(&sequence[sequence.len() - d..])
+        .map(|(c_k, s)| Integer::from(c_k * s))
+        .sum();
+    if !sum.is_divisible(leading) {
+        return None;
+    }
+    Some(-(sum / leading))
+}
@@ -0,0 +1,177 @@
+//! Lattice attacks on RSA
+//!
+//! These helpers set up the Coppersmith instances of classical attacks on RSA and choose
+//! the lattice parameters automatically.
+
+use crate::coppersmith::{self, IntegerEquation, JochemszMay, ModularEquation, Polynomial};
+
+use rug::Integer;
+
+/// Known bits of a prime factor `p` of `N`
+#[derive(Debug, Clone, PartialEq)]
+pub enum KnownBits {
+    /// Most significant bits: `p = value * 2^unknown_bits + x` with `0 <= x < 2^unknown_bits`
+    Msb { value: Integer, unknown_bits: u32 },
+
+    /// Least significant bits: `p = x * 2^known_bits + value` with `0 <= x < 2^unknown_bits`
+    ///
+    /// `p` is assumed to have exactly `known_bits + unknown_bits` bits.
+    Lsb {
+        value: Integer,
+        known_bits: u32,
+        unknown_bits: u32,
+    },
+}
+
+/// Logarithm (base 2) of a positive integer
+fn log2(n: &Integer) -> f64 {
+    let (mantissa, exponent) = n.to_f64_exp();
+    mantissa.log2() + f64::from(exponent)
+}
+
+/// Parameters `(m, t)` of Howgrave-Graham's lattice for a linear polynomial, if any up to `max_m`
+///
+/// The lattice is spanned by `N^(m-i) f^i` for `i <= m` and `x^j f^m` for `1 <= j <= t`, and is
+/// expected to reveal the root when `det^(1/w) * sqrt(w) < p^m`, where `w = m + t + 1`.
+fn linear_parameters(log_n: f64, log_p: f64, log_x: f64, max_m: u32) -> Option<(u32, u32)> {
+    for m in 1..=max_m {
+        for t in 0..=(2 * m * log_n.ceil() as u32 / log_p.floor().max(1.) as u32) {
+            let (mf, w) = (f64::from(m), f64::from(m + t + 1));
+            let log_det = mf * (mf + 1.) / 2. * log_n + w * (w - 1.) / 2. * log_x;
+            if log_det / w + w.log2() / 2. < mf * log_p {
+                return Some((m, t));
+            }
+        }
+    }
+    None
+}
+
+/// Factors `N = p * q` given some known bits of `p`
+///
+/// Builds the univariate Coppersmith instance `f(x) = 0 mod p` for the unknown part `x` of `p`,
+/// choosing the smallest lattice that satisfies Howgrave-Graham's bound (up to `m = max_m`).
+/// Asymptotically, the attack works when `x < N^(β²)` with `p = N^β`: for balanced primes,
+/// half of the bits of `p` must be known.
+///
+/// Returns `(p, N / p)` or `None` if the known bits are not enough or the factorisation fails.
+pub fn factor_with_known_bits(
+    n: &Integer,
+    known: &KnownBits,
+    max_m: u32,
+) -> Option<(Integer, Integer)> {
+    let (polynomial, divisor_bound, unknown_bits) = match known {
+        KnownBits::Msb {
+            value,
+            unknown_bits,
+        } => (
+            Polynomial::univariate(vec![
+                Integer::from(value << *unknown_bits),
+                Integer::from(1),
+            ]),
+            Integer::from(value << *unknown_bits),
+            *unknown_bits,
+        ),
+        KnownBits::Lsb {
+            value,
+            known_bits,
+            unknown_bits,
+        } => (
+            Polynomial::univariate(vec![value.clone(), Integer::from(1) << *known_bits]),
+            Integer::from(1) << (known_bits + unknown_bits - 1),
+            *unknown_bits,
+        ),
+    };
+    if divisor_bound <= 1 {
+        return None;
+    }
+
+    let root_bound = Integer::from(1) << unknown_bits;
+    let (m, t) = linear_parameters(
+        log2(n),
+        log2(&divisor_bound),
+        f64::from(unknown_bits),
+        max_m,
+    )?;
+
+    let equation = ModularEquation::with_unknown_divisor(
+        polynomial.clone(),
+        n.clone(),
+        divisor_bound,
+        vec![root_bound],
+    );
+    let strategy = JochemszMay::with_extra_shifts(m, vec![t]);
+
+    coppersmith::small_roots(&equation, &strategy)
+        .into_iter()
+        .map(|x| polynomial.evaluate(&x))
+        .find(|p| *p > 1 && p < n && n.is_divisible(p))
+        .map(|p| {
+            let q = Integer::from(n / &p);
+            (p, q)
+        })
+}
+
+/// Integer equation of RSA-CRT with small CRT exponents `d_p, d_q < 2^exponent_bits`
+///
+/// From `e d_p = 1 + k (p - 1)` and `e d_q = 1 + l (q - 1)`, the product
+/// `(e d_p - 1 + k) (e d_q - 1 + l) = k l N` gives the polynomial
+/// `f(x_p, x_q, y_p, y_q) = e² x_p x_q + e x_p (y_q - 1) + e x_q (y_p - 1) - (N - 1) y_p y_q - (y_p + y_q - 1)`
+/// with the root `(d_p, d_q, k, l)`, following Jochemsz and May [JM07]. The bounds on `k` and `l`
+/// assume balanced primes (`p, q > sqrt(N / 2)`).
+///
+/// [JM07]: E. Jochemsz and A. May. A Polynomial Time Attack on RSA with Private CRT-Exponents
+/// Smaller Than N^0.073. CRYPTO 2007.
+pub fn small_crt_equation(n: &Integer, e: &Integer, exponent_bits: u32) -> IntegerEquation {
+    let term = |exponents: [u32; 4], c: Integer| (exponents.to_vec(), c);
+    let polynomial = Polynomial::from_terms(
+        4,
+        vec![
+            term([1, 1, 0, 0], Integer::from(e * e)),
+            term([1, 0, 0, 1], e.clone()),
+            term([1, 0, 0, 0], Integer::from(-e)),
+            term([0, 1, 1, 0], e.clone()),
+            term([0, 1, 0, 0], Integer::from(-e)),
+            term([0, 0, 1, 1], Integer::from(1) - n),
+            term([0, 0, 1, 0], Integer::from(-1)),
+            term([0, 0, 0, 1], Integer::from(-1)),
+            term([0, 0, 0, 0], Integer::from(1)),
+        ],
+    );
+
+    // k = (e d_p - 1) / (p - 1) < e X / (sqrt(N / 2) - 1)
+    let x = Integer::from(1) << exponent_bits;
+    let p_bound = Integer::from(n >> 1).sqrt() - 1u32;
+    let y = Integer::from(e * &x) / p_bound + 1u32;
+
+    IntegerEquation::new(polynomial, vec![x.clone(), x, y.clone(), y])
+}
+
+/// Factors `N = p * q` given an RSA public exponent `e` with small CRT exponents `d_p, d_q < 2^exponent_bits`
+///
+/// Looks for the root `(d_p, d_q, k, l)` of the equation of [`small_crt_equation`] with the
+/// integer strategy of Jochemsz and May, with parameters `strategy`. The attack is asymptotic:
+/// it works up to `d_p, d_q < N^0.073` for full-size `e` with large parameters only, and small
+/// lattices only recover very small exponents.
+///
+/// Returns `(p, q)` or `None` if no root is found.
+pub fn factor_with_small_crt_exponents(
+    n: &Integer,
+    e: &Integer,
+    exponent_bits: u32,
+    strategy: &JochemszMay,
+) -> Option<(Integer, Integer)> {
+    let equation = small_crt_equation(n, e, exponent_bits);
+    coppersmith::integer_small_roots(&equation, strategy)
+        .into_iter()
+        .filter(|root| root[2] > 0)
+        .map(|root| {
+            // k p = e d_p - 1 + k
+            Integer::from(e * &root[0]) - 1u32 + &root[2]
+        })
+        .map(|kp| kp.gcd(n))
+        .find(|p| *p > 1 && p < n)
+        .map(|p| {
+            let q = Integer::from(n / &p);
+            (p, q)
+        })
+}
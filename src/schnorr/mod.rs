@@ -0,0 +1,229 @@
+//! Schnorr's factoring lattices (experimental)
+//!
+//! **This module is research-grade.** It reproduces the lattice construction of Schnorr's factoring
+//! method [Sch21] so that published experiments can be replicated, but it is not a practical
+//! factoring algorithm: the experiments of Ducas [Duc21] found far fewer relations than claimed,
+//! and the method is not known to outperform classical sieving.
+//!
+//! For a factor base of the first `n` primes `p_i`, a diagonal `f` and a scaling `C = 2^precision`,
+//! the lattice is spanned by the vectors `(f_i e_i, round(C ln p_i))` and `(0, round(C ln N))`.
+//! A short vector with coefficients `(e_1, ..., e_n, ∓1)` gives integers
+//! `u = prod_(e_i > 0) p_i^e_i` and `v = prod_(e_i < 0) p_i^-e_i` with `u / v ≈ N`, so that
+//! `u - v N` is small. When it is `p_n`-smooth, the pair is a smooth relation modulo `N`: enough
+//! such relations factor `N` by linear algebra over `GF(2)`, which is left to the caller.
+//!
+//! [Sch21]: C. P. Schnorr. Fast Factoring Integers by SVP Algorithms, corrected. IACR ePrint 2021/933.
+//!
+//! [Duc21]: L. Ducas. Testing Schnorr's Factoring Claim in SageMath. 2021.
+
+use crate::{algebra::Matrix, enumeration, generator, l2};
+
+use rand_core::RngCore;
+use rug::{ops::Pow, Float, Integer};
+
+use std::collections::BTreeSet;
+
+/// A smooth relation `u - v N`, with `u` and `v` products of primes of the factor base
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Relation {
+    /// Exponents `e_i` of the primes, positive for `u` and negative for `v`
+    pub exponents: Vec<i32>,
+
+    /// `u = prod_(e_i > 0) p_i^e_i`
+    pub u: Integer,
+
+    /// `v = prod_(e_i < 0) p_i^-e_i`
+    pub v: Integer,
+
+    /// The smooth remainder `u - v N`
+    pub remainder: Integer,
+
+    /// Exponents of the primes in the factorisation of `|u - v N|`
+    pub remainder_exponents: Vec<u32>,
+}
+
+/// The first `count` primes
+pub fn primes(count: usize) -> Vec<Integer> {
+    let mut primes = Vec::with_capacity(count);
+    let mut p = Integer::from(2);
+    while primes.len() < count {
+        primes.push(p.clone());
+        p = p.next_prime();
+    }
+    primes
+}
+
+/// Schnorr's diagonal: a random permutation of `(ceil(i / 2))_(1 <= i <= count)`
+pub fn diagonal<R: RngCore>(count: usize, rng: &mut R) -> Vec<Integer> {
+    let mut diagonal: Vec<Integer> = (1..=count).map(|i| Integer::from(i.div_ceil(2))).collect();
+    for i in (1..count).rev() {
+        let j = generator::uniform_usize(i + 1, rng);
+        diagonal.swap(i, j);
+    }
+    diagonal
+}
+
+/// `round(2^precision ln x)`
+fn scaled_log(x: &Integer, precision: u32) -> Integer {
+    let working_precision = precision + x.significant_bits() + 64;
+    let log = Float::with_val(working_precision, x).ln();
+    (log << precision)
+        .to_integer()
+        .expect("The logarithm of a positive integer is finite")
+}
+
+/// Schnorr's lattice for `N`, the factor base `primes` and the diagonal `diagonal`
+///
+/// Returns the `n + 1` vectors `(f_i e_i, round(2^precision ln p_i))` and
+/// `(0, round(2^precision ln N))`, of dimension `n + 1`.
+///
+/// # Panics
+/// if `N < 2`, if some prime is not positive, or if `primes` and `diagonal` have different lengths
+pub fn lattice(
+    n: &Integer,
+    primes: &[Integer],
+    diagonal: &[Integer],
+    precision: u32,
+) -> Matrix<Integer> {
+    assert!(*n >= 2);
+    assert_eq!(primes.len(), diagonal.len());
+    assert!(primes.iter().all(|p| *p > 0));
+    let count = primes.len();
+
+    let mut basis: Matrix<Integer> = Matrix::init(count + 1, count + 1);
+    for (i, (p, f)) in primes.iter().zip(diagonal).enumerate() {
+        basis[i][i] = f.clone();
+        basis[i][count] = scaled_log(p, precision);
+    }
+    basis[count][count] = scaled_log(n, precision);
+    basis
+}
+
+/// Exponents of the factorisation of `|x|` over `primes`, if `x` is non-zero and smooth
+pub fn smooth_exponents(x: &Integer, primes: &[Integer]) -> Option<Vec<u32>> {
+    if *x == 0 {
+        return None;
+    }
+    let mut x = x.clone().abs();
+    let exponents = primes.iter().map(|p| x.remove_factor_mut(p)).collect();
+    if x == 1 {
+        Some(exponents)
+    } else {
+        None
+    }
+}
+
+/// The relation given by the exponents `exponents`, if `u - v N` is smooth over `primes`
+///
+/// # Panics
+/// if `primes` and `exponents` have different lengths
+pub fn relation(n: &Integer, primes: &[Integer], exponents: &[i32]) -> Option<Relation> {
+    assert_eq!(primes.len(), exponents.len());
+    let mut u = Integer::from(1);
+    let mut v = Integer::from(1);
+    for (p, &e) in primes.iter().zip(exponents) {
+        let power = Integer::from(p.pow(e.unsigned_abs()));
+        if e > 0 {
+            u *= power;
+        } else {
+            v *= power;
+        }
+    }
+    let remainder = &u - Integer::from(&v * n);
+    let remainder_exponents = smooth_exponents(&remainder, primes)?;
+    Some(Relation {
+        exponents: exponents.to_vec(),
+        u,
+        v,
+        remainder,
+        remainder_exponents,
+    })
+}
+
+/// Exponents `e_i` of a lattice vector with coefficient `∓1` on the vector of `ln N`
+fn exponents(
+    vector: &[Integer],
+    diagonal: &[Integer],
+    logs: &[Integer],
+    log_n: &Integer,
+) -> Option<Vec<i32>> {
+    let count = diagonal.len();
+    let mut exponents: Vec<Integer> = Vec::with_capacity(count);
+    let mut rest = vector[count].clone();
+    for i in 0..count {
+        if !vector[i].is_divisible(&diagonal[i]) {
+            return None;
+        }
+        let e = Integer::from(vector[i].div_exact_ref(&diagonal[i]));
+        rest -= Integer::from(&e * &logs[i]);
+        exponents.push(e);
+    }
+
+    // The coefficient of (0, ln N) must be -1, up to the sign of the whole vector
+    let sign: i32 = if rest == Integer::from(-log_n) {
+        1
+    } else if rest == *log_n {
+        -1
+    } else {
+        return None;
+    };
+    exponents.into_iter().map(|e| (e * sign).to_i32()).collect()
+}
+
+/// Searches for smooth relations with a custom reduction of Schnorr's lattice
+///
+/// Builds the lattice of [`lattice`], reduces it with `reduce`, and enumerates the lattice vectors of
+/// squared norm at most `bound` (see [`enumeration::enumerate_bignum`]). Every vector with a
+/// coefficient `±1` on the vector of `ln N` gives a pair `(u, v)`, which is kept if `u - v N` is
+/// smooth over the factor base. Returns the distinct relations, sorted.
+///
+/// The enumeration is exponential in the number of primes: `bound` should be close to the squared
+/// norm of the shortest reduced vectors.
+///
+/// # Panics
+/// if `N < 2`, if some prime is not positive, or if `primes` and `diagonal` have different lengths
+pub fn smooth_relations_with<F>(
+    n: &Integer,
+    primes: &[Integer],
+    diagonal: &[Integer],
+    precision: u32,
+    bound: &Integer,
+    mut reduce: F,
+) -> Vec<Relation>
+where
+    F: FnMut(&mut Matrix<Integer>),
+{
+    let mut basis = lattice(n, primes, diagonal, precision);
+    let count = primes.len();
+    let logs: Vec<Integer> = (0..count).map(|i| basis[i][count].clone()).collect();
+    let log_n = basis[count][count].clone();
+    reduce(&mut basis);
+
+    let mut candidates: BTreeSet<Vec<i32>> = BTreeSet::new();
+    enumeration::enumerate_bignum(&basis, bound, |_, v| {
+        let vector: Vec<Integer> = (0..=count).map(|i| v[i].clone()).collect();
+        if let Some(e) = exponents(&vector, diagonal, &logs, &log_n) {
+            candidates.insert(e);
+        }
+    });
+
+    candidates
+        .into_iter()
+        .filter_map(|e| relation(n, primes, &e))
+        .collect()
+}
+
+/// Searches for smooth relations in Schnorr's lattice reduced with L² (`eta = 0.51`, `delta = 0.99`)
+///
+/// See [`smooth_relations_with`].
+pub fn smooth_relations(
+    n: &Integer,
+    primes: &[Integer],
+    diagonal: &[Integer],
+    precision: u32,
+    bound: &Integer,
+) -> Vec<Relation> {
+    smooth_relations_with(n, primes, diagonal, precision, bound, |basis| {
+        l2::lll_bignum(basis, 0.51, 0.99).expect("The lattice basis is valid")
+    })
+}